@@ -1,8 +1,10 @@
 use criterion::{
-    black_box, criterion_group, criterion_main, measurement::WallTime, BenchmarkGroup, Criterion,
+    black_box, criterion_group, criterion_main, measurement::WallTime, BatchSize, BenchmarkGroup,
+    Criterion,
 };
 use std::collections::hash_map::DefaultHasher;
 use std::hash::{Hash, Hasher};
+use std::net::Ipv4Addr;
 use std::str::FromStr;
 
 use bench::*;
@@ -51,6 +53,179 @@ fn bench_construction(c: &mut Criterion) {
     group.finish();
 }
 
+/// Converting owned `String`s, as a deserializer produces them, vs borrowing each as a `&str`.
+fn bench_construction_from_string(c: &mut Criterion) {
+    let mut group = c.benchmark_group("construction_from_string");
+    for len in LENGTHS {
+        let strings: Vec<String> = (0..1000).map(|_| random_string(*len, *len)).collect();
+        group.bench_function(format!("new-len={}", len), |b| {
+            b.iter_batched(
+                || strings.clone(),
+                |strings| {
+                    for x in strings {
+                        black_box(ColdString::new(black_box(x.as_str())));
+                    }
+                },
+                BatchSize::SmallInput,
+            )
+        });
+        group.bench_function(format!("from-len={}", len), |b| {
+            b.iter_batched(
+                || strings.clone(),
+                |strings| {
+                    for x in strings {
+                        black_box(ColdString::from(black_box(x)));
+                    }
+                },
+                BatchSize::SmallInput,
+            )
+        });
+    }
+    group.finish();
+}
+
+/// Allocating every heap block in an arena that is reset per batch, vs the global allocator.
+fn bench_construction_bump(c: &mut Criterion) {
+    let mut group = c.benchmark_group("construction_bump");
+    for len in LENGTHS {
+        let strings: Vec<String> = (0..1000).map(|_| random_string(*len, *len)).collect();
+        group.bench_function(format!("global-len={}", len), |b| {
+            b.iter(|| {
+                for x in strings.iter() {
+                    black_box(ColdString::new(black_box(x.as_str())));
+                }
+            })
+        });
+        let mut bump = bumpalo::Bump::new();
+        group.bench_function(format!("bump-len={}", len), |b| {
+            b.iter(|| {
+                for x in strings.iter() {
+                    black_box(ColdString::new_in(black_box(x.as_str()), &bump));
+                }
+                bump.reset();
+            })
+        });
+    }
+    group.finish();
+}
+
+fn bench_collect_chars(c: &mut Criterion) {
+    let mut group = c.benchmark_group("collect_chars");
+    for len in LENGTHS {
+        let strings: Vec<String> = (0..1000).map(|_| random_string(*len, *len)).collect();
+        group.bench_function(format!("std-len={}", len), |b| {
+            b.iter(|| {
+                for x in strings.iter() {
+                    black_box(black_box(x.as_str()).chars().collect::<String>());
+                }
+            })
+        });
+        group.bench_function(format!("cold-string-len={}", len), |b| {
+            b.iter(|| {
+                for x in strings.iter() {
+                    black_box(black_box(x.as_str()).chars().collect::<ColdString>());
+                }
+            })
+        });
+    }
+    group.finish();
+}
+
+fn bench_from_display(c: &mut Criterion) {
+    let numbers: Vec<u32> = (0..1000).map(|_| fastrand::u32(..)).collect();
+    let addrs: Vec<Ipv4Addr> = (0..1000)
+        .map(|_| Ipv4Addr::from(fastrand::u32(..)))
+        .collect();
+    let mut group = c.benchmark_group("from_display");
+    group.bench_function("u32-to_string", |b| {
+        b.iter(|| {
+            for x in numbers.iter() {
+                black_box(ColdString::new(black_box(x).to_string()));
+            }
+        })
+    });
+    group.bench_function("u32-from_display", |b| {
+        b.iter(|| {
+            for x in numbers.iter() {
+                black_box(ColdString::from_display(black_box(x)));
+            }
+        })
+    });
+    group.bench_function("ipv4-to_string", |b| {
+        b.iter(|| {
+            for x in addrs.iter() {
+                black_box(ColdString::new(black_box(x).to_string()));
+            }
+        })
+    });
+    group.bench_function("ipv4-from_display", |b| {
+        b.iter(|| {
+            for x in addrs.iter() {
+                black_box(ColdString::from_display(black_box(x)));
+            }
+        })
+    });
+    group.finish();
+}
+
+fn bench_from_ascii(c: &mut Criterion) {
+    let mut group = c.benchmark_group("from_ascii");
+    for len in [16, 256, 4096] {
+        let bytes: Vec<u8> = (0..len).map(|_| fastrand::alphanumeric() as u8).collect();
+        group.bench_function(format!("from_utf8-len={}", len), |b| {
+            b.iter(|| black_box(ColdString::from_utf8(black_box(&bytes))))
+        });
+        group.bench_function(format!("from_ascii-len={}", len), |b| {
+            b.iter(|| black_box(ColdString::from_ascii(black_box(&bytes))))
+        });
+    }
+    group.finish();
+}
+
+fn bench_join(c: &mut Criterion) {
+    let mut group = c.benchmark_group("join");
+    for count in [2, 8, 64] {
+        let parts: Vec<String> = (0..count)
+            .map(|_| {
+                (0..fastrand::usize(1..12))
+                    .map(|_| fastrand::alphanumeric())
+                    .collect()
+            })
+            .collect();
+        group.bench_function(format!("String::join-count={}", count), |b| {
+            b.iter(|| black_box(ColdString::new(black_box(&parts).join(", "))))
+        });
+        group.bench_function(format!("ColdString::join-count={}", count), |b| {
+            b.iter(|| black_box(ColdString::join(", ", black_box(&parts))))
+        });
+    }
+    group.finish();
+}
+
+fn bench_eq_ignore_ascii_case(c: &mut Criterion) {
+    let mut group = c.benchmark_group("eq_ignore_ascii_case");
+    for (a, b) in [
+        ("Accept", "ACCEPT"),
+        ("Content-Security-Policy", "content-security-policy"),
+    ] {
+        let (a, b) = (ColdString::new(a), ColdString::new(b));
+        group.bench_function(format!("to_ascii_lowercase-len={}", a.len()), |bench| {
+            bench.iter(|| {
+                black_box(&a).as_str().to_ascii_lowercase()
+                    == black_box(&b).as_str().to_ascii_lowercase()
+            })
+        });
+        group.bench_function(format!("eq_ignore_ascii_case-len={}", a.len()), |bench| {
+            bench.iter(|| black_box(&a).eq_ignore_ascii_case(black_box(&b)))
+        });
+        group.bench_function(
+            format!("eq_ignore_ascii_case_cold-len={}", a.len()),
+            |bench| bench.iter(|| black_box(&a).eq_ignore_ascii_case_cold(black_box(&b))),
+        );
+    }
+    group.finish();
+}
+
 fn bench_len(c: &mut Criterion) {
     let cold = ColdString::from(LONG);
     let string = String::from(LONG);
@@ -60,6 +235,57 @@ fn bench_len(c: &mut Criterion) {
     group.finish();
 }
 
+/// Run with and without `--features fixed-header` to compare the heap length headers.
+fn bench_len_header(c: &mut Criterion) {
+    let header = if cfg!(feature = "fixed-header") {
+        "fixed"
+    } else {
+        "varint"
+    };
+    let mut group = c.benchmark_group("len_header");
+    for len in [100, 1000, 4000] {
+        let strings: Vec<ColdString> = (0..1000)
+            .map(|i| ColdString::from("a".repeat(len + i % 100).as_str()))
+            .collect();
+        group.bench_function(format!("{}-len={}", header, len), |b| {
+            b.iter(|| {
+                black_box(&strings)
+                    .iter()
+                    .map(ColdString::len)
+                    .sum::<usize>()
+            })
+        });
+    }
+    group.finish();
+}
+
+/// Reading heap strings, which first checks whether the block is a `StaticStr`.
+fn bench_heap_read(c: &mut Criterion) {
+    let mut group = c.benchmark_group("heap_read");
+    for len in [16, 100, 1000, 4000] {
+        let strings: Vec<ColdString> = (0..1000)
+            .map(|i| ColdString::from("a".repeat(len + i % 100).as_str()))
+            .collect();
+        group.bench_function(format!("len-len={}", len), |b| {
+            b.iter(|| {
+                black_box(&strings)
+                    .iter()
+                    .map(ColdString::len)
+                    .sum::<usize>()
+            })
+        });
+        group.bench_function(format!("as_str-len={}", len), |b| {
+            b.iter(|| {
+                black_box(&strings)
+                    .iter()
+                    .map(|s| s.as_str().as_bytes()[0] as usize)
+                    .sum::<usize>()
+            })
+        });
+    }
+    group.finish();
+}
+
 fn bench_as_str_inner<T: FromStr + AsRef<str>>(
     g: &mut BenchmarkGroup<'_, WallTime>,
     name: &'static str,
@@ -172,16 +398,99 @@ fn bench_clone(c: &mut Criterion) {
     let mut group = c.benchmark_group("clone");
     group.bench_function("ColdString clone", |b| b.iter(|| black_box(cold.clone())));
     group.bench_function("String clone", |b| b.iter(|| black_box(string.clone())));
+    for len in [16, 64, 1024] {
+        let s = "a".repeat(len);
+        let cold = ColdString::from(s.as_str());
+        group.bench_function(format!("ColdString clone-len={}", len), |b| {
+            b.iter(|| black_box(cold.clone()))
+        });
+        group.bench_function(format!("String clone-len={}", len), |b| {
+            b.iter(|| black_box(s.clone()))
+        });
+    }
+
+    group.finish();
+}
+
+fn bench_sort_inner<T: FromStr + Ord + Clone>(
+    g: &mut BenchmarkGroup<'_, WallTime>,
+    name: &'static str,
+    strings: &[String],
+) {
+    let values: Vec<T> = strings
+        .iter()
+        .map(|s| T::from_str(s).map_err(|_| ()).unwrap())
+        .collect();
+    g.bench_function(name, |b| {
+        b.iter_batched(
+            || values.clone(),
+            |mut values| {
+                values.sort_unstable();
+                values
+            },
+            BatchSize::LargeInput,
+        )
+    });
+}
 
+/// Sorts 1M strings of mixed lengths, most of them inline.
+fn bench_sort(c: &mut Criterion) {
+    let strings: Vec<String> = (0..1_000_000).map(|_| random_string(0, 32)).collect();
+    let mut group = c.benchmark_group("sort");
+    group.sample_size(10);
+    bench_sort_inner::<ColdString>(&mut group, "ColdString", &strings);
+    bench_sort_inner::<String>(&mut group, "String", &strings);
+    bench_sort_inner::<smol_str::SmolStr>(&mut group, "smol_str", &strings);
+    group.finish();
+}
+
+/// Compares `sort_by_prefix` with `sort_unstable`, with strings long enough to be on the heap.
+fn bench_sort_by_prefix(c: &mut Criterion) {
+    let mut group = c.benchmark_group("sort_by_prefix");
+    group.sample_size(10);
+    for (min, max) in [(0, 32), (16, 64)] {
+        let values: Vec<ColdString> = (0..1_000_000).map(|_| random_string(min, max)).collect();
+        group.bench_function(format!("sort_unstable-len={}-{}", min, max), |b| {
+            b.iter_batched(
+                || values.clone(),
+                |mut values| {
+                    values.sort_unstable();
+                    values
+                },
+                BatchSize::LargeInput,
+            )
+        });
+        group.bench_function(format!("sort_by_prefix-len={}-{}", min, max), |b| {
+            b.iter_batched(
+                || values.clone(),
+                |mut values| {
+                    cold_string::sort_by_prefix(&mut values);
+                    values
+                },
+                BatchSize::LargeInput,
+            )
+        });
+    }
     group.finish();
 }
 
 criterion_group!(
     benches,
     bench_construction,
+    bench_construction_bump,
+    bench_construction_from_string,
+    bench_collect_chars,
+    bench_from_display,
+    bench_from_ascii,
+    bench_join,
+    bench_eq_ignore_ascii_case,
     bench_len,
+    bench_len_header,
+    bench_heap_read,
     bench_as_str,
     bench_hash,
-    bench_clone
+    bench_clone,
+    bench_sort,
+    bench_sort_by_prefix
 );
 criterion_main!(benches);
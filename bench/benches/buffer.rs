@@ -0,0 +1,40 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+
+use bench::*;
+use cold_string::ColdString;
+
+const COUNT: usize = 100_000;
+
+fn bench_buffer(c: &mut Criterion) {
+    let mut buf = String::new();
+    let mut spans = Vec::with_capacity(COUNT);
+    for _ in 0..COUNT {
+        let s: String = random_string(0, 32);
+        spans.push(buf.len()..buf.len() + s.len());
+        buf.push_str(&s);
+    }
+    let bytes = buf.as_bytes();
+
+    let mut group = c.benchmark_group("validated_buffer");
+    group.bench_function("from_utf8_per_span", |b| {
+        b.iter(|| {
+            let strings: Vec<ColdString> = spans
+                .iter()
+                .map(|span| ColdString::from_utf8(&bytes[span.clone()]).unwrap())
+                .collect();
+            black_box(strings)
+        })
+    });
+    group.bench_function("from_validated_buffer", |b| {
+        b.iter(|| {
+            black_box(cold_string::from_validated_buffer(
+                &buf,
+                spans.iter().cloned(),
+            ))
+        })
+    });
+    group.finish();
+}
+
+criterion_group!(benches, bench_buffer);
+criterion_main!(benches);
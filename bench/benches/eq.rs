@@ -64,5 +64,46 @@ fn bench_eq(c: &mut Criterion) {
     bench_eq_type::<String>(c, "String_eq");
 }
 
-criterion_group!(benches, bench_eq);
+/// Compares strings of `len` bytes with strings of `other_len` bytes, which are never equal.
+fn bench_eq_unequal_len(c: &mut Criterion) {
+    let mut group = c.benchmark_group("eq_unequal_len");
+
+    for (len, other_len) in [(4, 64), (64, 4), (64, 128)] {
+        let left: Vec<String> = (0..COUNT).map(|_| random_string(len, len)).collect();
+        let right: Vec<String> = (0..COUNT)
+            .map(|_| random_string(other_len, other_len))
+            .collect();
+        let cold_left: Vec<ColdString> =
+            left.iter().map(|s| ColdString::from(s.as_str())).collect();
+        let cold_right: Vec<ColdString> =
+            right.iter().map(|s| ColdString::from(s.as_str())).collect();
+        let label = format!("len={}_other={}", len, other_len);
+
+        group.bench_function(format!("ColdString-{}", label), |b| {
+            b.iter(|| {
+                for (l, r) in cold_left.iter().zip(cold_right.iter()) {
+                    black_box(l == r);
+                }
+            })
+        });
+        group.bench_function(format!("ColdString_str-{}", label), |b| {
+            b.iter(|| {
+                for (l, r) in cold_left.iter().zip(right.iter()) {
+                    black_box(l == r.as_str());
+                }
+            })
+        });
+        group.bench_function(format!("String-{}", label), |b| {
+            b.iter(|| {
+                for (l, r) in left.iter().zip(right.iter()) {
+                    black_box(l == r);
+                }
+            })
+        });
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, bench_eq, bench_eq_unequal_len);
 criterion_main!(benches);
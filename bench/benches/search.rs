@@ -0,0 +1,97 @@
+use criterion::{
+    black_box, criterion_group, criterion_main, measurement::WallTime, BenchmarkGroup, Criterion,
+};
+
+use bench::*;
+use cold_string::ColdString;
+
+const COUNT: usize = 1_000_000;
+const LEN: usize = 32;
+const LOOKUPS: usize = 1000;
+
+fn bench_search(c: &mut Criterion) {
+    let mut strings: Vec<String> = (0..COUNT).map(|_| random_string(LEN, LEN)).collect();
+    strings.sort();
+    strings.dedup();
+    let cold: Vec<ColdString> = strings.iter().map(ColdString::new).collect();
+
+    let needles = random_needles(&strings, LEN, LEN);
+    let prefixes: Vec<&str> = needles.iter().map(|s| &s[..3]).collect();
+
+    let mut group = c.benchmark_group("sorted_search");
+    group.bench_function("String_binary_search", |b| {
+        b.iter(|| {
+            for needle in needles.iter() {
+                let _ = black_box(strings.binary_search_by(|s| s.as_str().cmp(needle)));
+            }
+        })
+    });
+    bench_cold_search(&mut group, &cold, &needles);
+    group.bench_function("String_prefix_range", |b| {
+        b.iter(|| {
+            for prefix in prefixes.iter() {
+                let start = strings.partition_point(|s| s.as_str() < *prefix);
+                let len = strings[start..].partition_point(|s| s.starts_with(prefix));
+                black_box(&strings[start..start + len]);
+            }
+        })
+    });
+    group.bench_function("ColdString_prefix_range", |b| {
+        b.iter(|| {
+            for prefix in prefixes.iter() {
+                black_box(cold_string::equal_range_by_prefix(&cold, prefix));
+            }
+        })
+    });
+    group.finish();
+
+    // Lengths 1 to 12 mix inline and heap strings.
+    let mut strings: Vec<String> = (0..COUNT).map(|_| random_string(1, 12)).collect();
+    strings.sort();
+    strings.dedup();
+    let cold: Vec<ColdString> = strings.iter().map(ColdString::new).collect();
+    let needles = random_needles(&strings, 1, 12);
+
+    let mut group = c.benchmark_group("sorted_search_short");
+    bench_cold_search(&mut group, &cold, &needles);
+    group.finish();
+}
+
+/// Half of the needles are in `strings`, the other half are random.
+fn random_needles(strings: &[String], min: usize, max: usize) -> Vec<String> {
+    (0..LOOKUPS)
+        .map(|i| {
+            if i % 2 == 0 {
+                strings[fastrand::usize(..strings.len())].clone()
+            } else {
+                random_string(min, max)
+            }
+        })
+        .collect()
+}
+
+/// Compares `cold_string::binary_search`, which compares prefix keys first, with a search
+/// that compares the bytes of every probe.
+fn bench_cold_search(
+    group: &mut BenchmarkGroup<'_, WallTime>,
+    cold: &[ColdString],
+    needles: &[String],
+) {
+    group.bench_function("ColdString_binary_search", |b| {
+        b.iter(|| {
+            for needle in needles.iter() {
+                let _ = black_box(cold_string::binary_search(cold, needle));
+            }
+        })
+    });
+    group.bench_function("ColdString_binary_search_bytes", |b| {
+        b.iter(|| {
+            for needle in needles.iter() {
+                let _ = black_box(cold.binary_search_by(|s| s.as_bytes().cmp(needle.as_bytes())));
+            }
+        })
+    });
+}
+
+criterion_group!(benches, bench_search);
+criterion_main!(benches);
@@ -105,6 +105,14 @@ fn btree_workload<T: FromStr + Hash + Eq + Ord>(min: usize, max: usize) {
     std::mem::forget(strings);
 }
 
+fn frozen_list_workload(min: usize, max: usize) {
+    let strings: cold_string::FrozenColdStringList = (0..TRIALS)
+        .map(|_| random_string::<String>(min, max))
+        .collect();
+    let strings = std::hint::black_box(strings);
+    std::mem::forget(strings);
+}
+
 /// Demo data with potential poor "alignment waste"
 #[derive(Eq, Hash, PartialEq)]
 struct Data<T> {
@@ -206,3 +214,50 @@ fn test_system_memory_btreeset() {
     system_memory("smol_str", btree_workload::<smol_str::SmolStr>);
     system_memory("std", btree_workload::<String>);
 }
+
+/// Draws `TRIALS` strings from a vocabulary of heap-sized labels, with a few labels much more
+/// common than the rest, like country names or enum-ish labels.
+fn skewed_workload(name: &str, new: impl Fn(&str) -> cold_string::ColdString) {
+    const VOCABULARY: usize = 1000;
+    let vocabulary: Vec<String> = (0..VOCABULARY).map(|_| random_string(9, 32)).collect();
+
+    let mut sys = sysinfo::System::new_all();
+    let pid = Pid::from(std::process::id() as usize);
+    sys.refresh_processes(sysinfo::ProcessesToUpdate::Some(&[pid]), false);
+    let base_mem = sys.process(pid).unwrap().memory();
+
+    let mut strings = Vec::with_capacity(TRIALS);
+    for _ in 0..TRIALS {
+        let skew = fastrand::f64().powi(3);
+        strings.push(new(&vocabulary[(skew * VOCABULARY as f64) as usize]));
+    }
+    let strings = std::hint::black_box(strings);
+
+    sys.refresh_processes(sysinfo::ProcessesToUpdate::Some(&[pid]), false);
+    let rss = (sys.process(pid).unwrap().memory() - base_mem) as f64 / (TRIALS as f64);
+    println!("{:<NAME_WIDTH$}  | {:>CELL_WIDTH$.1}", name, rss);
+    std::mem::forget(strings);
+}
+
+/// `cargo test test_system_memory_interned --release -- --no-capture --include-ignored`
+#[test]
+#[ignore]
+fn test_system_memory_interned() {
+    println!(
+        "{:<NAME_WIDTH$}  | {:>CELL_WIDTH$}",
+        "Skewed labels", "9..=32"
+    );
+    println!("{: <NAME_WIDTH$}  | {: ^CELL_WIDTH$} |", ":---", ":---:");
+    skewed_workload("cold-string", |s| cold_string::ColdString::new(s));
+    skewed_workload("interned", cold_string::intern);
+}
+
+/// `cargo test test_system_memory_frozen --release -- --no-capture --include-ignored`
+#[test]
+#[rustfmt::skip]
+#[ignore]
+fn test_system_memory_frozen() {
+    print_table_header("Read-only list");
+    system_memory("cold-string", vec_workload::<cold_string::ColdString>);
+    system_memory("frozen list", frozen_list_workload);
+}
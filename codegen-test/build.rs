@@ -0,0 +1,17 @@
+use std::{env, fs, path::Path};
+
+fn main() {
+    let out = env::var("OUT_DIR").unwrap();
+    let keywords = cold_string::codegen::static_table(
+        "KEYWORDS",
+        &[
+            "as", "dyn", "else", "enum", "fn", "for", "if", "impl", "let", "mut", "pub", "use",
+        ],
+    );
+    fs::write(Path::new(&out).join("keywords.rs"), keywords).unwrap();
+    let messages = cold_string::codegen::static_table(
+        "MESSAGES",
+        &["ok", "not found", "a message too long to be stored inline"],
+    );
+    fs::write(Path::new(&out).join("messages.rs"), messages).unwrap();
+}
@@ -0,0 +1,4 @@
+//! Tables generated by `build.rs` with `cold_string::codegen::static_table`.
+
+include!(concat!(env!("OUT_DIR"), "/keywords.rs"));
+include!(concat!(env!("OUT_DIR"), "/messages.rs"));
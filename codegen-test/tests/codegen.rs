@@ -0,0 +1,25 @@
+use codegen_test::{KEYWORDS, MESSAGES};
+use cold_string::{ColdString, StaticStr};
+
+#[test]
+fn inline_table() {
+    let table: &'static [ColdString] = &KEYWORDS;
+    assert_eq!(table.len(), 12);
+    assert!(table.iter().all(ColdString::is_inline));
+    assert_eq!(table[4], "fn");
+    assert_eq!(table.binary_search_by(|k| k.as_str().cmp("let")), Ok(8));
+    assert!(table.iter().all(|k| k != "struct"));
+}
+
+#[test]
+fn static_str_table() {
+    let table: &'static [StaticStr] = &MESSAGES;
+    let cold: Vec<ColdString> = table.iter().map(ColdString::from_static).collect();
+    assert_eq!(
+        cold,
+        ["ok", "not found", "a message too long to be stored inline"]
+    );
+    assert!(cold[0].is_inline());
+    assert!(!cold[2].is_inline());
+    assert_eq!(cold[2].as_ptr(), table[2].as_str().as_ptr());
+}
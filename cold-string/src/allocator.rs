@@ -0,0 +1,400 @@
+#![cfg_attr(docsrs, doc(cfg(feature = "allocator-api2")))]
+//! [`ColdStringIn`], a [`ColdString`] whose heap block comes from an [`Allocator`].
+
+use crate::{header::Header, ColdString, TryNewError, NUL_PAD};
+
+use alloc::string::String;
+use allocator_api2::alloc::{Allocator, Global};
+use core::{
+    borrow::Borrow,
+    cmp::Ordering,
+    fmt,
+    hash::{Hash, Hasher},
+    mem::ManuallyDrop,
+    ops::Deref,
+    ptr::{self, NonNull},
+};
+
+/// An immutable string like [`ColdString`], whose heap block is allocated and freed by `A`.
+///
+/// The allocator is stored next to the string word, so with a zero-sized allocator such as
+/// [`Global`], a `ColdStringIn` is one word, like [`ColdString`]. Strings of at most
+/// `INLINE_CAPACITY` bytes are inline and never call the allocator. This uses the
+/// [`allocator_api2`] crate, which re-exports the unstable `core` trait on nightly.
+///
+/// # Examples
+/// ```
+/// use allocator_api2::alloc::Global;
+/// use cold_string::{ColdString, ColdStringIn};
+///
+/// let s = ColdStringIn::new_in("a string from the global allocator", Global);
+/// assert_eq!(s, "a string from the global allocator");
+/// assert_eq!(s.clone(), s);
+/// assert_eq!(ColdString::from(s), "a string from the global allocator");
+///
+/// assert_eq!(core::mem::size_of::<ColdStringIn>(), core::mem::size_of::<usize>());
+/// ```
+pub struct ColdStringIn<A: Allocator = Global> {
+    /// Either inline, or a heap string whose block was allocated by `alloc`.
+    inner: ManuallyDrop<ColdString>,
+    alloc: A,
+}
+
+impl<A: Allocator> ColdStringIn<A> {
+    /// Creates a new [`ColdStringIn`] by copying `s` into a block allocated by `alloc`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the length overflows, and calls [`handle_alloc_error`] if `alloc` fails.
+    ///
+    /// [`handle_alloc_error`]: alloc::alloc::handle_alloc_error
+    pub fn new_in<T: AsRef<str>>(s: T, alloc: A) -> Self {
+        match Self::try_new_in(s, alloc) {
+            Ok(s) => s,
+            Err(err) => match err.layout() {
+                Some(layout) => alloc::alloc::handle_alloc_error(layout),
+                None => panic!("capacity overflow"),
+            },
+        }
+    }
+
+    /// Like [`ColdStringIn::new_in`], but returns an error instead of aborting if the block
+    /// can't be allocated.
+    ///
+    /// # Examples
+    /// ```
+    /// use allocator_api2::alloc::Global;
+    /// use cold_string::ColdStringIn;
+    ///
+    /// let s = ColdStringIn::try_new_in("a string from the global allocator", Global).unwrap();
+    /// assert_eq!(s.len(), 34);
+    /// ```
+    pub fn try_new_in<T: AsRef<str>>(s: T, alloc: A) -> Result<Self, TryNewError> {
+        let s = s.as_ref();
+        if ColdString::will_inline(s.len()) {
+            return Ok(Self::wrap(ColdString::new(s), alloc));
+        }
+        let (layout, header) = ColdString::heap_layout(s.len())
+            .ok_or_else(|| TryNewError::capacity_overflow(s.len()))?;
+        let block = alloc
+            .allocate(layout)
+            .map_err(|_| TryNewError::alloc_failed(s.len(), layout))?
+            .cast::<u8>()
+            .as_ptr();
+        // SAFETY: the block is aligned like a heap block, and the header, the bytes and the NUL
+        // padding are all written
+        unsafe {
+            Header::write_to(block, s.len());
+            ptr::copy_nonoverlapping(s.as_ptr(), block.add(header), s.len());
+            ptr::write_bytes(block.add(header + s.len()), 0, NUL_PAD);
+            Ok(Self::wrap(ColdString::from_heap_ptr(block), alloc))
+        }
+    }
+
+    #[inline]
+    fn wrap(inner: ColdString, alloc: A) -> Self {
+        Self {
+            inner: ManuallyDrop::new(inner),
+            alloc,
+        }
+    }
+
+    /// Returns a string slice of the whole string.
+    #[inline]
+    pub fn as_str(&self) -> &str {
+        self.inner.as_str()
+    }
+
+    /// Returns `true` if the string bytes are inlined, like [`ColdString::is_inline`].
+    #[inline]
+    pub fn is_inline(&self) -> bool {
+        self.inner.is_inline()
+    }
+
+    /// Returns a reference to the allocator.
+    #[inline]
+    pub fn allocator(&self) -> &A {
+        &self.alloc
+    }
+}
+
+impl<A: Allocator + Clone> Clone for ColdStringIn<A> {
+    /// Copies inline strings, and copies the heap block of heap strings into a block allocated
+    /// by a clone of the allocator.
+    fn clone(&self) -> Self {
+        let alloc = self.alloc.clone();
+        if self.inner.is_inline() {
+            return Self::wrap(
+                ColdString {
+                    encoded: self.inner.encoded,
+                },
+                alloc,
+            );
+        }
+        // SAFETY: the string is on the heap, with a block of this layout
+        unsafe {
+            let block = self.inner.heap_ptr();
+            let layout = ColdString::allocated_layout(self.inner.len());
+            let copy = match alloc.allocate(layout) {
+                Ok(copy) => copy.cast::<u8>().as_ptr(),
+                Err(_) => alloc::alloc::handle_alloc_error(layout),
+            };
+            ptr::copy_nonoverlapping(block, copy, layout.size());
+            Self::wrap(ColdString::from_heap_ptr(copy), alloc)
+        }
+    }
+}
+
+impl<A: Allocator> Drop for ColdStringIn<A> {
+    fn drop(&mut self) {
+        if self.inner.is_inline() {
+            return;
+        }
+        // SAFETY: the block was allocated by `alloc` with this layout
+        unsafe {
+            let layout = ColdString::allocated_layout(self.inner.len());
+            let block = NonNull::new_unchecked(self.inner.heap_ptr() as *mut u8);
+            self.alloc.deallocate(block, layout);
+        }
+    }
+}
+
+impl<A: Allocator + Default> Default for ColdStringIn<A> {
+    #[inline]
+    fn default() -> Self {
+        Self::wrap(ColdString::EMPTY, A::default())
+    }
+}
+
+impl<A: Allocator> Deref for ColdStringIn<A> {
+    type Target = str;
+
+    #[inline]
+    fn deref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl<A: Allocator> AsRef<str> for ColdStringIn<A> {
+    #[inline]
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl<A: Allocator> AsRef<[u8]> for ColdStringIn<A> {
+    #[inline]
+    fn as_ref(&self) -> &[u8] {
+        self.as_bytes()
+    }
+}
+
+impl<A: Allocator> Borrow<str> for ColdStringIn<A> {
+    #[inline]
+    fn borrow(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl<A: Allocator, B: Allocator> PartialEq<ColdStringIn<B>> for ColdStringIn<A> {
+    #[inline]
+    fn eq(&self, other: &ColdStringIn<B>) -> bool {
+        *self.inner == *other.inner
+    }
+}
+
+impl<A: Allocator> Eq for ColdStringIn<A> {}
+
+impl<A: Allocator> PartialEq<str> for ColdStringIn<A> {
+    #[inline]
+    fn eq(&self, other: &str) -> bool {
+        *self.inner == *other
+    }
+}
+
+impl<A: Allocator> PartialEq<&str> for ColdStringIn<A> {
+    #[inline]
+    fn eq(&self, other: &&str) -> bool {
+        *self.inner == **other
+    }
+}
+
+impl<A: Allocator> PartialEq<String> for ColdStringIn<A> {
+    #[inline]
+    fn eq(&self, other: &String) -> bool {
+        *self.inner == *other.as_str()
+    }
+}
+
+impl<A: Allocator> PartialEq<ColdString> for ColdStringIn<A> {
+    #[inline]
+    fn eq(&self, other: &ColdString) -> bool {
+        *self.inner == *other
+    }
+}
+
+impl<A: Allocator> PartialEq<ColdStringIn<A>> for ColdString {
+    #[inline]
+    fn eq(&self, other: &ColdStringIn<A>) -> bool {
+        *self == *other.inner
+    }
+}
+
+impl<A: Allocator> Ord for ColdStringIn<A> {
+    #[inline]
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.inner.cmp(&other.inner)
+    }
+}
+
+impl<A: Allocator> PartialOrd for ColdStringIn<A> {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<A: Allocator> Hash for ColdStringIn<A> {
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.as_str().hash(state)
+    }
+}
+
+impl<A: Allocator> fmt::Debug for ColdStringIn<A> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&*self.inner, f)
+    }
+}
+
+impl<A: Allocator> fmt::Display for ColdStringIn<A> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&*self.inner, f)
+    }
+}
+
+/// Inline strings are moved, and heap strings are copied into a global heap block.
+impl<A: Allocator> From<ColdStringIn<A>> for ColdString {
+    #[inline]
+    fn from(s: ColdStringIn<A>) -> Self {
+        if s.inner.is_inline() {
+            ColdString {
+                encoded: s.inner.encoded,
+            }
+        } else {
+            ColdString::new(s.as_str())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::WIDTH;
+
+    use alloc::vec::Vec;
+    use allocator_api2::alloc::{AllocError, Layout};
+    use core::cell::Cell;
+    use core::hash::BuildHasher;
+
+    /// Counts the bytes it allocates, and fails once `limit` bytes are live.
+    #[derive(Clone)]
+    struct Counting<'a> {
+        live: &'a Cell<usize>,
+        limit: usize,
+    }
+
+    unsafe impl Allocator for Counting<'_> {
+        fn allocate(&self, layout: Layout) -> Result<NonNull<[u8]>, AllocError> {
+            if self.live.get() + layout.size() > self.limit {
+                return Err(AllocError);
+            }
+            self.live.set(self.live.get() + layout.size());
+            Global.allocate(layout)
+        }
+
+        unsafe fn deallocate(&self, ptr: NonNull<u8>, layout: Layout) {
+            self.live.set(self.live.get() - layout.size());
+            Global.deallocate(ptr, layout)
+        }
+    }
+
+    const STRS: [&str; 6] = [
+        "",
+        "cold",
+        "12345678",
+        "123456789",
+        "a string long enough for the heap",
+        "🦀🦀🦀🦀🦀",
+    ];
+
+    #[test]
+    fn every_byte_goes_through_the_allocator() {
+        let live = Cell::new(0);
+        let alloc = Counting {
+            live: &live,
+            limit: usize::MAX,
+        };
+        let mut expected = 0;
+        let mut strings = Vec::new();
+        for s in STRS {
+            let cold = ColdStringIn::new_in(s, alloc.clone());
+            if s.len() > WIDTH {
+                expected += ColdString::heap_layout(s.len()).unwrap().0.size();
+            }
+            assert_eq!(live.get(), expected);
+            assert_eq!(cold, s);
+            assert_eq!(cold.len(), s.len());
+            assert_eq!(cold.is_inline(), s.len() <= WIDTH);
+            assert_eq!(cold, ColdString::new(s));
+            let copy = cold.clone();
+            assert_eq!(copy, cold);
+            if !copy.is_inline() {
+                assert_ne!(copy.as_ptr(), cold.as_ptr());
+            }
+            drop(copy);
+            assert_eq!(live.get(), expected);
+            strings.push(cold);
+        }
+        let hasher = std::collections::hash_map::RandomState::new();
+        for (a, s) in strings.iter().zip(STRS) {
+            assert_eq!(hasher.hash_one(a), hasher.hash_one(s));
+            for (b, t) in strings.iter().zip(STRS) {
+                assert_eq!(a.cmp(b), s.cmp(t));
+            }
+        }
+        let owned: Vec<ColdString> = strings.drain(..).map(ColdString::from).collect();
+        assert_eq!(live.get(), 0);
+        assert_eq!(owned, STRS);
+    }
+
+    #[test]
+    fn failing_allocator() {
+        let live = Cell::new(0);
+        let long = "a string long enough for the heap";
+        let size = ColdString::heap_layout(long.len()).unwrap().0.size();
+        let alloc = Counting {
+            live: &live,
+            limit: size,
+        };
+        let first = ColdStringIn::try_new_in(long, alloc.clone()).unwrap();
+        let err = ColdStringIn::try_new_in(long, alloc.clone()).unwrap_err();
+        assert_eq!(err.requested_len(), long.len());
+        assert_eq!(err.layout().map(|l| l.size()), Some(size));
+        let short = ColdStringIn::try_new_in("short", alloc.clone()).unwrap();
+        assert_eq!(short, "short");
+        assert_eq!(live.get(), size);
+        drop(first);
+        assert_eq!(live.get(), 0);
+        let second = ColdStringIn::try_new_in(String::from(long), alloc).unwrap();
+        assert_eq!(second, long);
+    }
+
+    #[test]
+    fn zero_sized_allocator() {
+        assert_eq!(core::mem::size_of::<ColdStringIn>(), WIDTH);
+        assert_eq!(core::mem::size_of::<Option<ColdStringIn>>(), WIDTH);
+        let s: ColdStringIn = ColdStringIn::default();
+        assert_eq!(s, "");
+    }
+}
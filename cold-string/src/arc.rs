@@ -0,0 +1,478 @@
+//! [`ColdArcString`], a [`ColdString`] whose heap block is shared by its clones.
+
+use crate::{header::Header, ColdString, NUL_PAD, WIDTH};
+
+use alloc::{
+    alloc::{alloc, dealloc, handle_alloc_error, Layout},
+    borrow::Cow,
+    string::String,
+};
+use core::{
+    borrow::Borrow,
+    cmp::Ordering,
+    fmt,
+    hash::{Hash, Hasher},
+    mem::ManuallyDrop,
+    ops::Deref,
+    ptr,
+    sync::atomic::{self, AtomicUsize},
+};
+
+/// A reference count above this is treated as a leak of clones, like in `Arc`.
+const MAX_COUNT: usize = isize::MAX as usize;
+
+/// An immutable string like [`ColdString`], whose heap block is reference counted, so cloning
+/// a long string is O(1).
+///
+/// Strings of at most `INLINE_CAPACITY` bytes are inline and copied on clone, like in
+/// [`ColdString`]. Longer strings are in a heap block that starts with an [`AtomicUsize`]
+/// reference count, before the length header and the bytes. The handle is one word, like
+/// [`ColdString`], and `Option<ColdArcString>` is too.
+///
+/// # Examples
+/// ```
+/// use cold_string::{ColdArcString, ColdString};
+///
+/// let s = ColdArcString::new("a string shared by several indexes");
+/// let t = s.clone();
+/// assert!(ColdArcString::ptr_eq(&s, &t));
+/// assert_eq!(t, "a string shared by several indexes");
+/// assert_eq!(ColdString::from(t), s.as_str());
+///
+/// assert_eq!(core::mem::size_of::<ColdArcString>(), core::mem::size_of::<usize>());
+/// ```
+#[repr(transparent)]
+pub struct ColdArcString {
+    /// Either inline, static, or a heap string whose block is preceded by the count.
+    inner: ManuallyDrop<ColdString>,
+}
+
+impl ColdArcString {
+    /// Creates a new [`ColdArcString`] by copying `s`.
+    pub fn new<T: AsRef<str>>(s: T) -> Self {
+        let s = s.as_ref();
+        if ColdString::will_inline(s.len()) {
+            return Self::wrap(ColdString::new(s));
+        }
+        let (layout, header) = Self::layout(s.len());
+        // SAFETY: the layout is non-zero, and the count is followed by the header, the bytes
+        // and the NUL padding, which are all written
+        unsafe {
+            let ptr = alloc(layout);
+            if ptr.is_null() {
+                handle_alloc_error(layout);
+            }
+            ptr::write(ptr as *mut AtomicUsize, AtomicUsize::new(1));
+            let block = ptr.add(WIDTH);
+            Header::write_to(block, s.len());
+            ptr::copy_nonoverlapping(s.as_ptr(), block.add(header), s.len());
+            ptr::write_bytes(block.add(header + s.len()), 0, NUL_PAD);
+            Self::wrap(ColdString::from_heap_ptr(block))
+        }
+    }
+
+    /// Returns the layout of the count and heap block for `len` bytes, and the header length.
+    fn layout(len: usize) -> (Layout, usize) {
+        ColdString::heap_layout(len)
+            .and_then(|(block, header)| {
+                let (layout, offset) = Layout::new::<AtomicUsize>().extend(block).ok()?;
+                debug_assert_eq!(offset, WIDTH);
+                Some((layout, header))
+            })
+            .expect("capacity overflow")
+    }
+
+    #[inline]
+    fn wrap(inner: ColdString) -> Self {
+        Self {
+            inner: ManuallyDrop::new(inner),
+        }
+    }
+
+    /// SAFETY: the string must be on the heap, with its count before the block.
+    #[inline]
+    unsafe fn count(&self) -> &AtomicUsize {
+        &*(self.inner.heap_ptr().sub(WIDTH) as *const AtomicUsize)
+    }
+
+    /// Returns a string slice of the whole string.
+    #[inline]
+    pub fn as_str(&self) -> &str {
+        self.inner.as_str()
+    }
+
+    /// Returns `true` if the string bytes are inlined, like [`ColdString::is_inline`].
+    #[inline]
+    pub fn is_inline(&self) -> bool {
+        self.inner.is_inline()
+    }
+
+    /// Returns `true` if `a` and `b` share the same heap block.
+    ///
+    /// Inline strings never share one, even if they are equal.
+    ///
+    /// # Examples
+    /// ```
+    /// use cold_string::ColdArcString;
+    ///
+    /// let a = ColdArcString::new("a string long enough for the heap");
+    /// assert!(ColdArcString::ptr_eq(&a, &a.clone()));
+    /// assert!(!ColdArcString::ptr_eq(&a, &ColdArcString::new(a.as_str())));
+    /// ```
+    #[inline]
+    pub fn ptr_eq(a: &Self, b: &Self) -> bool {
+        a.inner.is_heap_allocated() && a.inner.addr() == b.inner.addr()
+    }
+}
+
+impl Clone for ColdArcString {
+    /// Copies inline strings, and increments the count of heap strings.
+    #[inline]
+    fn clone(&self) -> Self {
+        if self.inner.is_heap_allocated() {
+            // SAFETY: the string is on the heap. Like `Arc`, a new reference is made from an
+            // existing one, so no synchronization is needed.
+            let old = unsafe { self.count() }.fetch_add(1, atomic::Ordering::Relaxed);
+            if old > MAX_COUNT {
+                abort();
+            }
+        }
+        // SAFETY: an inline or static word is copied, or the heap block has a new reference
+        Self::wrap(ColdString {
+            encoded: self.inner.encoded,
+        })
+    }
+}
+
+impl Drop for ColdArcString {
+    fn drop(&mut self) {
+        if !self.inner.is_heap_allocated() {
+            return;
+        }
+        // SAFETY: the string is on the heap. Like `Arc`, the release decrement and the acquire
+        // fence order every use of the block before it is freed by the last owner.
+        unsafe {
+            if self.count().fetch_sub(1, atomic::Ordering::Release) != 1 {
+                return;
+            }
+            atomic::fence(atomic::Ordering::Acquire);
+            let (layout, _) = Self::layout(self.inner.len());
+            dealloc(self.inner.heap_ptr().sub(WIDTH) as *mut u8, layout);
+        }
+    }
+}
+
+/// Aborts the process by panicking while panicking.
+#[cold]
+fn abort() -> ! {
+    struct Abort;
+    impl Drop for Abort {
+        fn drop(&mut self) {
+            panic!("reference count overflow");
+        }
+    }
+    let _abort = Abort;
+    panic!("reference count overflow");
+}
+
+impl Default for ColdArcString {
+    #[inline]
+    fn default() -> Self {
+        Self::wrap(ColdString::EMPTY)
+    }
+}
+
+impl Deref for ColdArcString {
+    type Target = str;
+
+    #[inline]
+    fn deref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl AsRef<str> for ColdArcString {
+    #[inline]
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl AsRef<[u8]> for ColdArcString {
+    #[inline]
+    fn as_ref(&self) -> &[u8] {
+        self.as_bytes()
+    }
+}
+
+impl Borrow<str> for ColdArcString {
+    #[inline]
+    fn borrow(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl PartialEq for ColdArcString {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        ColdArcString::ptr_eq(self, other) || *self.inner == *other.inner
+    }
+}
+
+impl Eq for ColdArcString {}
+
+impl PartialEq<str> for ColdArcString {
+    #[inline]
+    fn eq(&self, other: &str) -> bool {
+        *self.inner == *other
+    }
+}
+
+impl PartialEq<&str> for ColdArcString {
+    #[inline]
+    fn eq(&self, other: &&str) -> bool {
+        *self.inner == **other
+    }
+}
+
+impl PartialEq<String> for ColdArcString {
+    #[inline]
+    fn eq(&self, other: &String) -> bool {
+        *self.inner == *other.as_str()
+    }
+}
+
+impl PartialEq<ColdString> for ColdArcString {
+    #[inline]
+    fn eq(&self, other: &ColdString) -> bool {
+        *self.inner == *other
+    }
+}
+
+impl PartialEq<ColdArcString> for ColdString {
+    #[inline]
+    fn eq(&self, other: &ColdArcString) -> bool {
+        *self == *other.inner
+    }
+}
+
+impl Ord for ColdArcString {
+    #[inline]
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.inner.cmp(&other.inner)
+    }
+}
+
+impl PartialOrd for ColdArcString {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Hash for ColdArcString {
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.as_str().hash(state)
+    }
+}
+
+impl fmt::Debug for ColdArcString {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&*self.inner, f)
+    }
+}
+
+impl fmt::Display for ColdArcString {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&*self.inner, f)
+    }
+}
+
+impl From<&str> for ColdArcString {
+    #[inline]
+    fn from(s: &str) -> Self {
+        Self::new(s)
+    }
+}
+
+impl From<String> for ColdArcString {
+    #[inline]
+    fn from(s: String) -> Self {
+        Self::new(s)
+    }
+}
+
+impl From<Cow<'_, str>> for ColdArcString {
+    #[inline]
+    fn from(s: Cow<'_, str>) -> Self {
+        Self::new(s)
+    }
+}
+
+/// Inline and static strings are moved, and heap strings are copied into a counted block.
+impl From<ColdString> for ColdArcString {
+    #[inline]
+    fn from(s: ColdString) -> Self {
+        if s.is_heap_allocated() {
+            Self::new(s)
+        } else {
+            Self::wrap(s)
+        }
+    }
+}
+
+/// Inline and static strings are moved, and heap strings are copied into their own block.
+impl From<ColdArcString> for ColdString {
+    #[inline]
+    fn from(s: ColdArcString) -> Self {
+        if s.inner.is_heap_allocated() {
+            ColdString::new(s.as_str())
+        } else {
+            // The word is inline or static, so it is moved without touching the count.
+            let s = ManuallyDrop::new(s);
+            ColdString {
+                encoded: s.inner.encoded,
+            }
+        }
+    }
+}
+
+impl alloc::str::FromStr for ColdArcString {
+    type Err = core::convert::Infallible;
+
+    #[inline]
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(Self::new(s))
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for ColdArcString {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for ColdArcString {
+    fn deserialize<D: serde::Deserializer<'de>>(d: D) -> Result<Self, D::Error> {
+        ColdString::deserialize(d).map(ColdArcString::from)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::StaticStr;
+
+    use alloc::vec::Vec;
+    use std::thread;
+
+    const STRS: [&str; 5] = [
+        "",
+        "cold",
+        "12345678",
+        "a string long enough for the heap",
+        "\0\0\0\0\0\0\0\0",
+    ];
+
+    #[test]
+    fn clones_share_heap_block() {
+        for s in STRS {
+            let a = ColdArcString::new(s);
+            let b = a.clone();
+            assert_eq!(a, s);
+            assert_eq!(b, a);
+            assert_eq!(ColdArcString::ptr_eq(&a, &b), !a.is_inline());
+            assert_eq!(a.is_inline(), s.len() <= WIDTH);
+            if !a.is_inline() {
+                assert_eq!(b.as_ptr(), a.as_ptr());
+                assert_eq!(unsafe { a.count() }.load(atomic::Ordering::Relaxed), 2);
+            }
+            drop(a);
+            assert_eq!(b, s);
+        }
+    }
+
+    #[test]
+    fn conversions() {
+        static LONG: StaticStr = StaticStr::new("a static string long enough for the heap");
+        let strs = STRS
+            .iter()
+            .map(ColdString::new)
+            .chain([ColdString::from_static(&LONG)]);
+        for cold in strs {
+            let arc = ColdArcString::from(cold.clone());
+            assert_eq!(arc, cold);
+            assert_eq!(cold, arc);
+            assert_eq!(arc.is_inline(), cold.is_inline());
+            let back = ColdString::from(arc.clone());
+            assert_eq!(back, cold);
+            assert_eq!(back.is_heap_allocated(), cold.is_heap_allocated());
+            if cold.is_static() {
+                assert_eq!(arc.as_ptr(), cold.as_ptr());
+            }
+        }
+    }
+
+    #[test]
+    fn traits() {
+        use std::collections::HashSet;
+
+        let strs: Vec<ColdArcString> = STRS.iter().map(ColdArcString::new).collect();
+        let set: HashSet<ColdArcString> = strs.iter().cloned().collect();
+        for s in STRS {
+            assert!(set.contains(s));
+        }
+        let mut sorted = strs.clone();
+        sorted.sort();
+        let mut expected = STRS;
+        expected.sort();
+        assert_eq!(sorted, expected);
+        assert_eq!(
+            alloc::format!("{:?}", strs[3]),
+            alloc::format!("{:?}", STRS[3])
+        );
+        assert_eq!(alloc::format!("{:>6}", strs[1]), "  cold");
+        assert_eq!(ColdArcString::default(), "");
+    }
+
+    #[test]
+    fn shared_across_threads() {
+        let s = ColdArcString::new("a string cloned and dropped by several threads");
+        let threads: Vec<_> = (0..4)
+            .map(|_| {
+                let s = s.clone();
+                thread::spawn(move || {
+                    let clones: Vec<ColdArcString> = (0..8).map(|_| s.clone()).collect();
+                    assert!(clones.iter().all(|c| ColdArcString::ptr_eq(c, &s)));
+                    clones
+                })
+            })
+            .collect();
+        let mut clones = Vec::new();
+        for t in threads {
+            clones.extend(t.join().unwrap());
+        }
+        assert_eq!(unsafe { s.count() }.load(atomic::Ordering::Relaxed), 33);
+        let threads: Vec<_> = clones
+            .chunks(8)
+            .map(|chunk| {
+                let chunk = chunk.to_vec();
+                thread::spawn(move || drop(chunk))
+            })
+            .collect();
+        drop(clones);
+        for t in threads {
+            t.join().unwrap();
+        }
+        assert_eq!(unsafe { s.count() }.load(atomic::Ordering::Relaxed), 1);
+        // The last owner frees the block, on whichever thread drops it last.
+        let last = s.clone();
+        drop(s);
+        thread::spawn(move || assert_eq!(last, "a string cloned and dropped by several threads"))
+            .join()
+            .unwrap();
+    }
+}
@@ -0,0 +1,119 @@
+use crate::{ColdString, WIDTH};
+
+use core::{fmt, str};
+
+/// The error returned by [`ColdString::from_ascii`] for input containing a non-ASCII byte.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct AsciiError {
+    index: usize,
+}
+
+impl AsciiError {
+    /// Returns the index of the first non-ASCII byte.
+    pub fn index(&self) -> usize {
+        self.index
+    }
+}
+
+impl fmt::Display for AsciiError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "non-ASCII byte at index {}", self.index)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for AsciiError {}
+
+impl ColdString {
+    /// Creates a [`ColdString`] from bytes that must all be ASCII.
+    ///
+    /// This is cheaper than [`ColdString::from_utf8`], checking the high bit of a word of bytes
+    /// at a time.
+    ///
+    /// # Errors
+    /// Returns the index of the first byte that isn't ASCII.
+    ///
+    /// # Examples
+    /// ```
+    /// use cold_string::ColdString;
+    ///
+    /// assert_eq!(ColdString::from_ascii(b"Content-Type").unwrap(), "Content-Type");
+    /// assert_eq!(ColdString::from_ascii("naïve".as_bytes()).unwrap_err().index(), 2);
+    /// ```
+    pub fn from_ascii(v: &[u8]) -> Result<Self, AsciiError> {
+        match first_non_ascii(v) {
+            // SAFETY: ASCII is valid UTF-8
+            None => Ok(unsafe { Self::from_ascii_unchecked(v) }),
+            Some(index) => Err(AsciiError { index }),
+        }
+    }
+
+    /// Creates a [`ColdString`] from bytes without checking that they are ASCII.
+    ///
+    /// # Safety
+    /// The bytes must be valid UTF-8, which they are if they are all ASCII.
+    ///
+    /// # Examples
+    /// ```
+    /// use cold_string::ColdString;
+    ///
+    /// assert_eq!(unsafe { ColdString::from_ascii_unchecked(b"GET") }, "GET");
+    /// ```
+    #[inline]
+    pub unsafe fn from_ascii_unchecked(v: &[u8]) -> Self {
+        Self::new(str::from_utf8_unchecked(v))
+    }
+}
+
+/// Returns the index of the first byte with its high bit set.
+fn first_non_ascii(v: &[u8]) -> Option<usize> {
+    const HIGH_BITS: usize = usize::from_ne_bytes([0x80; WIDTH]);
+    let mut chunks = v.chunks_exact(WIDTH);
+    let mut offset = 0;
+    for chunk in &mut chunks {
+        let mut word = [0; WIDTH];
+        word.copy_from_slice(chunk);
+        if usize::from_ne_bytes(word) & HIGH_BITS != 0 {
+            break;
+        }
+        offset += WIDTH;
+    }
+    v[offset..]
+        .iter()
+        .position(|b| !b.is_ascii())
+        .map(|i| offset + i)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec::Vec;
+
+    #[test]
+    fn ascii() {
+        for len in [0, 1, WIDTH - 1, WIDTH, WIDTH + 1, 3 * WIDTH + 5] {
+            let s: Vec<u8> = (0..len).map(|i| (i % 128) as u8).collect();
+            let cold = ColdString::from_ascii(&s).unwrap();
+            assert_eq!(cold.as_bytes(), &s[..]);
+            assert_eq!(cold.is_inline(), len <= WIDTH);
+        }
+    }
+
+    #[test]
+    fn non_ascii() {
+        for len in [1, 2, WIDTH - 1, WIDTH, WIDTH + 1, 3 * WIDTH + 5] {
+            for index in [0, len / 2, len - 1] {
+                let mut s = alloc::vec![b'a'; len];
+                s[index] = 0x80;
+                assert_eq!(ColdString::from_ascii(&s), Err(AsciiError { index }));
+                // Only the first non-ASCII byte is reported.
+                s[len - 1] = 0xff;
+                assert_eq!(ColdString::from_ascii(&s), Err(AsciiError { index }));
+            }
+        }
+        assert_eq!(
+            ColdString::from_ascii("é".as_bytes()).unwrap_err().index(),
+            0
+        );
+    }
+}
@@ -0,0 +1,100 @@
+use crate::ColdString;
+
+use alloc::vec::Vec;
+use core::{fmt, ops::Range};
+
+/// The error returned by [`from_validated_buffer`] when a span doesn't select a substring of
+/// the buffer.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SpanError {
+    index: usize,
+    span: Range<usize>,
+}
+
+impl SpanError {
+    /// Returns the position of the offending span in the iterator.
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    /// Returns the offending span.
+    pub fn span(&self) -> Range<usize> {
+        self.span.clone()
+    }
+}
+
+impl fmt::Display for SpanError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "span {} ({}..{}) is out of bounds or not on char boundaries",
+            self.index, self.span.start, self.span.end
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for SpanError {}
+
+/// Creates a [`ColdString`] for each span of `buf`.
+///
+/// `buf` is already known to be UTF-8, so each span is only checked to be in bounds and on char
+/// boundaries, instead of being validated again like with [`ColdString::from_utf8`].
+///
+/// # Errors
+/// Returns a [`SpanError`] for the first span that is out of bounds, reversed, or splits a
+/// char.
+///
+/// # Examples
+/// ```
+/// let buf = "alphabetagamma🦀";
+/// let strings = cold_string::from_validated_buffer(buf, vec![0..5, 5..9, 9..14].into_iter()).unwrap();
+/// assert_eq!(strings, ["alpha", "beta", "gamma"]);
+///
+/// let err = cold_string::from_validated_buffer(buf, vec![0..5, 14..16].into_iter()).unwrap_err();
+/// assert_eq!((err.index(), err.span()), (1, 14..16));
+/// ```
+pub fn from_validated_buffer<I: Iterator<Item = Range<usize>>>(
+    buf: &str,
+    spans: I,
+) -> Result<Vec<ColdString>, SpanError> {
+    let mut strings = Vec::with_capacity(spans.size_hint().0);
+    for (index, span) in spans.enumerate() {
+        match buf.get(span.clone()) {
+            Some(s) => strings.push(ColdString::new(s)),
+            None => return Err(SpanError { index, span }),
+        }
+    }
+    Ok(strings)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn valid_spans() {
+        let buf = "short🦀a string long enough for the heap";
+        let spans = [0..5, 5..9, 9..buf.len(), 0..0, 3..3, 0..buf.len(), 1..5];
+        let strings = from_validated_buffer(buf, spans.iter().cloned()).unwrap();
+        assert_eq!(strings.len(), spans.len());
+        for (s, span) in strings.iter().zip(spans) {
+            assert_eq!(s, &buf[span]);
+        }
+        assert!(from_validated_buffer(buf, core::iter::empty())
+            .unwrap()
+            .is_empty());
+    }
+
+    #[test]
+    fn invalid_spans() {
+        let buf = "é🦀abc";
+        let len = buf.len();
+        #[allow(clippy::reversed_empty_ranges)]
+        for span in [0..1, 3..4, 2..5, 5..7, 0..len + 1, len + 1..len + 1, 4..2] {
+            let spans = [0..2, span.clone(), 6..7];
+            let err = from_validated_buffer(buf, spans.iter().cloned()).unwrap_err();
+            assert_eq!(err, SpanError { index: 1, span });
+        }
+    }
+}
@@ -0,0 +1,251 @@
+use crate::{ColdString, WIDTH};
+
+use alloc::string::String;
+use core::{fmt, str};
+
+/// Builds a [`ColdString`] incrementally, including through [`fmt::Write`].
+///
+/// Content is kept in an inline buffer while it fits in `core::mem::size_of::<usize>()` bytes,
+/// so short strings are built without allocating. Longer content is accumulated in a
+/// [`String`] and copied into the heap block once by [`finish`](Self::finish).
+///
+/// # Examples
+/// ```
+/// use cold_string::ColdStringBuilder;
+/// use core::fmt::Write;
+///
+/// let mut b = ColdStringBuilder::new();
+/// b.push_str("id");
+/// b.push('=');
+/// write!(b, "{}", 42).unwrap();
+/// assert_eq!(b.finish(), "id=42");
+/// ```
+#[derive(Clone, Default)]
+pub struct ColdStringBuilder {
+    repr: Repr,
+}
+
+#[derive(Clone)]
+enum Repr {
+    Inline { buf: [u8; WIDTH], len: usize },
+    Heap(String),
+}
+
+impl Default for Repr {
+    fn default() -> Self {
+        Repr::Inline {
+            buf: [0; WIDTH],
+            len: 0,
+        }
+    }
+}
+
+impl ColdStringBuilder {
+    /// Creates an empty builder.
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates an empty builder with room for `capacity` bytes.
+    ///
+    /// Capacities that fit inline don't allocate.
+    pub fn with_capacity(capacity: usize) -> Self {
+        let repr = if capacity <= WIDTH {
+            Repr::default()
+        } else {
+            Repr::Heap(String::with_capacity(capacity))
+        };
+        Self { repr }
+    }
+
+    /// Returns the length of the content so far, in bytes.
+    #[inline]
+    pub fn len(&self) -> usize {
+        match &self.repr {
+            Repr::Inline { len, .. } => *len,
+            Repr::Heap(s) => s.len(),
+        }
+    }
+
+    /// Returns `true` if nothing has been pushed yet.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the content so far.
+    #[inline]
+    pub fn as_str(&self) -> &str {
+        match &self.repr {
+            // SAFETY: only whole `str`s are copied into the buffer
+            Repr::Inline { buf, len } => unsafe { str::from_utf8_unchecked(&buf[..*len]) },
+            Repr::Heap(s) => s,
+        }
+    }
+
+    /// Appends a char.
+    #[inline]
+    pub fn push(&mut self, c: char) {
+        self.push_str(c.encode_utf8(&mut [0; 4]));
+    }
+
+    /// Appends a string slice.
+    pub fn push_str(&mut self, s: &str) {
+        match &mut self.repr {
+            Repr::Inline { buf, len } if s.len() <= WIDTH - *len => {
+                buf[*len..*len + s.len()].copy_from_slice(s.as_bytes());
+                *len += s.len();
+            }
+            Repr::Inline { buf, len } => {
+                let mut heap = String::with_capacity((*len + s.len()).max(2 * WIDTH));
+                // SAFETY: only whole `str`s are copied into the buffer
+                heap.push_str(unsafe { str::from_utf8_unchecked(&buf[..*len]) });
+                heap.push_str(s);
+                self.repr = Repr::Heap(heap);
+            }
+            Repr::Heap(heap) => heap.push_str(s),
+        }
+    }
+
+    /// Builds the [`ColdString`].
+    #[inline]
+    pub fn finish(self) -> ColdString {
+        ColdString::new(self.as_str())
+    }
+}
+
+impl ColdString {
+    /// Returns a copy of this string with only the chars for which `pred` returns `true`.
+    ///
+    /// The chars are streamed through a [`ColdStringBuilder`], so a result that fits inline
+    /// is inline, even if this string is on the heap.
+    ///
+    /// # Examples
+    /// ```
+    /// use cold_string::ColdString;
+    ///
+    /// let s = ColdString::new("line one\r\n\tline two\x07");
+    /// assert_eq!(s.retain_chars(|c| !c.is_control()), "line oneline two");
+    /// ```
+    pub fn retain_chars<F: FnMut(char) -> bool>(&self, mut pred: F) -> ColdString {
+        let mut b = ColdStringBuilder::with_capacity(self.len());
+        for c in self.chars().filter(|&c| pred(c)) {
+            b.push(c);
+        }
+        b.finish()
+    }
+
+    /// Returns a copy of this string with each char replaced by `f(char)`.
+    ///
+    /// The chars may be encoded with a different number of bytes, and are streamed through a
+    /// [`ColdStringBuilder`], so a result that fits inline is inline.
+    ///
+    /// # Examples
+    /// ```
+    /// use cold_string::ColdString;
+    ///
+    /// let s = ColdString::new("C:\\Users\\cold");
+    /// assert_eq!(s.map_chars(|c| if c == '\\' { '/' } else { c }), "C:/Users/cold");
+    /// assert_eq!(ColdString::new("abc").map_chars(|_| '🦀'), "🦀🦀🦀");
+    /// ```
+    pub fn map_chars<F: FnMut(char) -> char>(&self, f: F) -> ColdString {
+        let mut b = ColdStringBuilder::with_capacity(self.len());
+        for c in self.chars().map(f) {
+            b.push(c);
+        }
+        b.finish()
+    }
+}
+
+impl fmt::Write for ColdStringBuilder {
+    #[inline]
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.push_str(s);
+        Ok(())
+    }
+
+    #[inline]
+    fn write_char(&mut self, c: char) -> fmt::Result {
+        self.push(c);
+        Ok(())
+    }
+}
+
+impl fmt::Debug for ColdStringBuilder {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("ColdStringBuilder")
+            .field(&self.as_str())
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::fmt::Write;
+
+    #[test]
+    fn interleaved() {
+        let mut b = ColdStringBuilder::new();
+        let mut expected = String::new();
+        assert!(b.is_empty());
+        for i in 0..20 {
+            match i % 3 {
+                0 => b.push('é'),
+                1 => b.push_str("ab"),
+                _ => write!(b, "{}", i).unwrap(),
+            }
+            match i % 3 {
+                0 => expected.push('é'),
+                1 => expected.push_str("ab"),
+                _ => write!(expected, "{}", i).unwrap(),
+            }
+            assert_eq!(b.len(), expected.len());
+            assert_eq!(b.as_str(), expected);
+            let cold = b.clone().finish();
+            assert_eq!(cold, expected.as_str());
+            assert_eq!(cold.is_inline(), expected.len() <= WIDTH);
+        }
+    }
+
+    #[test]
+    fn char_transforms() {
+        let s = "a \u{7}string\r\n long enough for the heap 🦀";
+        let cold = ColdString::new(s);
+        let retained = cold.retain_chars(|c| !c.is_control());
+        assert_eq!(retained, "a string long enough for the heap 🦀");
+        let short = cold.retain_chars(|c| c.is_ascii_uppercase() || c == '🦀');
+        assert_eq!(short, "🦀");
+        assert!(short.is_inline());
+        assert_eq!(cold.retain_chars(|_| false), ColdString::EMPTY);
+
+        let mapped = ColdString::new("a-b_c").map_chars(|c| if c == '_' { '-' } else { c });
+        assert_eq!(mapped, "a-b-c");
+        // Mapping changes the encoded length in both directions.
+        let grown = ColdString::new("abc").map_chars(|_| 'é');
+        assert_eq!(grown, "ééé");
+        assert_eq!(ColdString::new("ééé").map_chars(|_| 'e'), "eee");
+        assert_eq!(cold.map_chars(|c| c), cold);
+    }
+
+    #[test]
+    fn threshold() {
+        for capacity in [0, WIDTH, WIDTH + 1, 100] {
+            let mut b = ColdStringBuilder::with_capacity(capacity);
+            b.push_str(&"x".repeat(WIDTH - 1));
+            b.push('y');
+            assert!(b.clone().finish().is_inline());
+            b.push('z');
+            let cold = b.finish();
+            assert!(!cold.is_inline());
+            assert_eq!(cold.len(), WIDTH + 1);
+        }
+        assert_eq!(ColdStringBuilder::new().finish(), ColdString::EMPTY);
+        let mut b = ColdStringBuilder::new();
+        b.push('🦀');
+        b.push('🦀');
+        b.push('🦀');
+        assert_eq!(b.finish(), "🦀🦀🦀");
+    }
+}
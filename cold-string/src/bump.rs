@@ -0,0 +1,313 @@
+#![cfg_attr(docsrs, doc(cfg(feature = "bumpalo")))]
+//! [`ColdBumpString`], a [`ColdString`] whose heap block is in a [`bumpalo::Bump`] arena.
+
+use crate::{header::Header, ColdString, NUL_PAD};
+
+use alloc::string::String;
+use bumpalo::Bump;
+use core::{
+    borrow::Borrow,
+    cmp::Ordering,
+    fmt,
+    hash::{Hash, Hasher},
+    marker::PhantomData,
+    mem::ManuallyDrop,
+    ops::Deref,
+    ptr,
+};
+
+/// An immutable string like [`ColdString`], whose heap block is allocated in a [`Bump`] arena
+/// and freed with it.
+///
+/// A [`ColdString`] has no spare bit to mark an arena block, so it would free the block with
+/// the global allocator. A `ColdBumpString` never frees its block, and borrows the arena for
+/// `'bump`, so it can't outlive it. Cloning and dropping one is free. Strings of at most
+/// `INLINE_CAPACITY` bytes are inline, like in [`ColdString`], and don't use the arena.
+///
+/// # Examples
+/// ```
+/// use bumpalo::Bump;
+/// use cold_string::{ColdBumpString, ColdString};
+///
+/// let bump = Bump::new();
+/// let s = ColdString::new_in("a string in an arena", &bump);
+/// assert_eq!(s, "a string in an arena");
+/// assert_eq!(s.clone().as_ptr(), s.as_ptr());
+///
+/// // An owned copy on the global heap outlives the arena.
+/// let owned = ColdString::from(s);
+/// drop(bump);
+/// assert_eq!(owned, "a string in an arena");
+///
+/// assert_eq!(core::mem::size_of::<ColdBumpString>(), core::mem::size_of::<usize>());
+/// ```
+///
+/// A `ColdBumpString` can't be used after its arena is dropped:
+/// ```compile_fail
+/// use bumpalo::Bump;
+/// use cold_string::ColdString;
+///
+/// let bump = Bump::new();
+/// let s = ColdString::new_in("a string in an arena", &bump);
+/// drop(bump);
+/// assert_eq!(s, "a string in an arena");
+/// ```
+#[repr(transparent)]
+pub struct ColdBumpString<'bump> {
+    /// Either inline, or a heap string whose block is in the arena, so it is never dropped.
+    inner: ManuallyDrop<ColdString>,
+    bump: PhantomData<&'bump Bump>,
+}
+
+impl ColdString {
+    /// Creates a new [`ColdBumpString`] by copying `s` into the `bump` arena.
+    ///
+    /// Allocating in an arena is cheaper than in the global allocator, and freeing is free.
+    /// The string borrows `bump`, and its block is freed when the arena is dropped or reset.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the arena can't allocate the block.
+    ///
+    /// # Examples
+    /// ```
+    /// use bumpalo::Bump;
+    /// use cold_string::ColdString;
+    ///
+    /// let bump = Bump::new();
+    /// let names: Vec<_> = ["a short", "a name long enough for the heap"]
+    ///     .into_iter()
+    ///     .map(|s| ColdString::new_in(s, &bump))
+    ///     .collect();
+    /// assert_eq!(names[1], "a name long enough for the heap");
+    /// ```
+    pub fn new_in<'bump>(s: &str, bump: &'bump Bump) -> ColdBumpString<'bump> {
+        if ColdString::will_inline(s.len()) {
+            return ColdBumpString::wrap(ColdString::new(s));
+        }
+        let (layout, header) = ColdString::heap_layout(s.len()).expect("capacity overflow");
+        // SAFETY: the block is aligned like a heap block, and the header, the bytes and the NUL
+        // padding are all written
+        unsafe {
+            let block = bump.alloc_layout(layout).as_ptr();
+            Header::write_to(block, s.len());
+            ptr::copy_nonoverlapping(s.as_ptr(), block.add(header), s.len());
+            ptr::write_bytes(block.add(header + s.len()), 0, NUL_PAD);
+            ColdBumpString::wrap(ColdString::from_heap_ptr(block))
+        }
+    }
+}
+
+impl<'bump> ColdBumpString<'bump> {
+    #[inline]
+    fn wrap(inner: ColdString) -> Self {
+        Self {
+            inner: ManuallyDrop::new(inner),
+            bump: PhantomData,
+        }
+    }
+
+    /// Returns a string slice of the whole string.
+    #[inline]
+    pub fn as_str(&self) -> &str {
+        self.inner.as_str()
+    }
+
+    /// Returns `true` if the string bytes are inlined, like [`ColdString::is_inline`].
+    #[inline]
+    pub fn is_inline(&self) -> bool {
+        self.inner.is_inline()
+    }
+}
+
+impl Clone for ColdBumpString<'_> {
+    /// Copies the word, which shares the arena block of a heap string.
+    #[inline]
+    fn clone(&self) -> Self {
+        // SAFETY: the block is in the arena and never freed by either copy
+        Self::wrap(ColdString {
+            encoded: self.inner.encoded,
+        })
+    }
+}
+
+impl Deref for ColdBumpString<'_> {
+    type Target = str;
+
+    #[inline]
+    fn deref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl AsRef<str> for ColdBumpString<'_> {
+    #[inline]
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl AsRef<[u8]> for ColdBumpString<'_> {
+    #[inline]
+    fn as_ref(&self) -> &[u8] {
+        self.as_bytes()
+    }
+}
+
+impl Borrow<str> for ColdBumpString<'_> {
+    #[inline]
+    fn borrow(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl PartialEq for ColdBumpString<'_> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        *self.inner == *other.inner
+    }
+}
+
+impl Eq for ColdBumpString<'_> {}
+
+impl PartialEq<str> for ColdBumpString<'_> {
+    #[inline]
+    fn eq(&self, other: &str) -> bool {
+        *self.inner == *other
+    }
+}
+
+impl PartialEq<&str> for ColdBumpString<'_> {
+    #[inline]
+    fn eq(&self, other: &&str) -> bool {
+        *self.inner == **other
+    }
+}
+
+impl PartialEq<String> for ColdBumpString<'_> {
+    #[inline]
+    fn eq(&self, other: &String) -> bool {
+        *self.inner == *other.as_str()
+    }
+}
+
+impl PartialEq<ColdString> for ColdBumpString<'_> {
+    #[inline]
+    fn eq(&self, other: &ColdString) -> bool {
+        *self.inner == *other
+    }
+}
+
+impl PartialEq<ColdBumpString<'_>> for ColdString {
+    #[inline]
+    fn eq(&self, other: &ColdBumpString<'_>) -> bool {
+        *self == *other.inner
+    }
+}
+
+impl Ord for ColdBumpString<'_> {
+    #[inline]
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.inner.cmp(&other.inner)
+    }
+}
+
+impl PartialOrd for ColdBumpString<'_> {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Hash for ColdBumpString<'_> {
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.as_str().hash(state)
+    }
+}
+
+impl fmt::Debug for ColdBumpString<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&*self.inner, f)
+    }
+}
+
+impl fmt::Display for ColdBumpString<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&*self.inner, f)
+    }
+}
+
+/// Inline strings are moved, and heap strings are copied into a global heap block.
+impl From<ColdBumpString<'_>> for ColdString {
+    #[inline]
+    fn from(s: ColdBumpString<'_>) -> Self {
+        if s.inner.is_inline() {
+            ColdString {
+                encoded: s.inner.encoded,
+            }
+        } else {
+            ColdString::new(s.as_str())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::WIDTH;
+
+    use alloc::vec::Vec;
+
+    #[test]
+    fn strings_in_arena() {
+        let bump = Bump::new();
+        let strs = [
+            "",
+            "cold",
+            "12345678",
+            "\0\0\0\0\0\0\0\0",
+            "123456789",
+            "a string long enough for the heap",
+            "🦀🦀🦀",
+        ];
+        let arena: Vec<ColdBumpString<'_>> =
+            strs.iter().map(|s| ColdString::new_in(s, &bump)).collect();
+        for (s, cold) in strs.iter().zip(&arena) {
+            assert_eq!(cold, s);
+            assert_eq!(cold.len(), s.len());
+            assert_eq!(cold.is_inline(), s.len() <= WIDTH);
+            assert_eq!(*cold, ColdString::new(s));
+            assert_eq!(cold.clone(), *cold);
+            if !cold.is_inline() {
+                assert_eq!(cold.clone().as_ptr(), cold.as_ptr());
+                let block = cold.inner.heap_ptr() as usize;
+                // SAFETY: the chunks are only compared with, not read
+                let mut chunks = unsafe { bump.iter_allocated_chunks_raw() };
+                assert!(chunks
+                    .any(|(start, len)| (start as usize..start as usize + len).contains(&block)));
+            }
+            let owned = ColdString::from(cold.clone());
+            assert_eq!(owned, *s);
+            assert_eq!(owned.is_inline(), s.len() <= WIDTH);
+        }
+        for (a, b) in arena.iter().zip(strs.iter()) {
+            for (c, d) in arena.iter().zip(strs.iter()) {
+                assert_eq!(a.cmp(c), b.cmp(d));
+            }
+        }
+    }
+
+    #[test]
+    fn reset_arena() {
+        let mut bump = Bump::new();
+        for round in 0..3 {
+            let strings: Vec<ColdBumpString<'_>> = (0..100)
+                .map(|i| ColdString::new_in(&alloc::format!("round {} string {}", round, i), &bump))
+                .collect();
+            assert_eq!(strings[99], alloc::format!("round {} string 99", round));
+            drop(strings);
+            bump.reset();
+        }
+    }
+}
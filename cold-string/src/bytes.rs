@@ -0,0 +1,82 @@
+#![cfg_attr(docsrs, doc(cfg(feature = "bytes")))]
+
+use crate::ColdString;
+
+use alloc::str::Utf8Error;
+use bytes::{Bytes, BytesMut};
+use core::convert::TryFrom;
+
+/// Copies the string into an exactly sized [`Bytes`].
+///
+/// The heap block of a [`ColdString`] starts with its length header, so the buffer can't be
+/// handed over without copying. Conversions in both directions copy the string bytes once.
+impl From<ColdString> for Bytes {
+    #[inline]
+    fn from(s: ColdString) -> Self {
+        Bytes::copy_from_slice(s.as_bytes())
+    }
+}
+
+/// Copies the string into an exactly sized [`Bytes`].
+impl From<&ColdString> for Bytes {
+    #[inline]
+    fn from(s: &ColdString) -> Self {
+        Bytes::copy_from_slice(s.as_bytes())
+    }
+}
+
+/// Validates the buffer as UTF-8 and copies it into a [`ColdString`].
+impl TryFrom<Bytes> for ColdString {
+    type Error = Utf8Error;
+
+    #[inline]
+    fn try_from(b: Bytes) -> Result<Self, Self::Error> {
+        ColdString::from_utf8(&b)
+    }
+}
+
+/// Validates the buffer as UTF-8 and copies it into a [`ColdString`].
+impl TryFrom<BytesMut> for ColdString {
+    type Error = Utf8Error;
+
+    #[inline]
+    fn try_from(b: BytesMut) -> Result<Self, Self::Error> {
+        ColdString::from_utf8(&b)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bytes_roundtrip() {
+        for s in [
+            "",
+            "cold",
+            "12345678",
+            "this is a longer cold string",
+            "🦀💯",
+        ] {
+            let cold = ColdString::new(s);
+            let bytes = Bytes::from(&cold);
+            assert_eq!(bytes, s.as_bytes());
+            assert_eq!(Bytes::from(cold.clone()), bytes);
+            assert_eq!(ColdString::try_from(bytes).unwrap(), cold);
+            let bytes_mut = BytesMut::from(s.as_bytes());
+            assert_eq!(ColdString::try_from(bytes_mut).unwrap(), cold);
+        }
+    }
+
+    #[test]
+    fn bytes_invalid_utf8() {
+        for b in [
+            &[0xff][..],
+            &[b'a', 0xc3][..],
+            &[0xf0, 0x9f, 0xa6, b'a', b'b', b'c', b'd', b'e', b'f'],
+        ] {
+            assert!(ColdString::try_from(Bytes::copy_from_slice(b)).is_err());
+            assert!(ColdString::try_from(BytesMut::from(b)).is_err());
+        }
+    }
+}
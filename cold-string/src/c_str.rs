@@ -0,0 +1,95 @@
+#![cfg_attr(docsrs, doc(cfg(feature = "nul-terminated")))]
+//! With the `nul-terminated` feature, every heap block has one extra NUL byte after the string
+//! bytes, so heap strings can be borrowed as [`CStr`]s without copying. Inline strings of at
+//! most `WIDTH - 2` bytes are already followed by a zeroed padding byte.
+
+use crate::{ColdString, WIDTH};
+
+use core::slice;
+use std::ffi::CStr;
+
+impl ColdString {
+    /// Borrows this string as a NUL-terminated [`CStr`], without copying.
+    ///
+    /// Returns `None` if the string contains a NUL byte, if it is an inline string of
+    /// `INLINE_CAPACITY - 1` or `INLINE_CAPACITY` bytes, which fill the word and leave no room
+    /// for the terminator, or if it borrows a [`StaticStr`](crate::StaticStr). Use
+    /// [`CString::new`](std::ffi::CString::new) for those.
+    ///
+    /// # Examples
+    /// ```
+    /// use cold_string::ColdString;
+    ///
+    /// let s = ColdString::new("a string on the heap");
+    /// assert_eq!(s.as_c_str().unwrap().to_bytes(), s.as_bytes());
+    /// assert_eq!(ColdString::new("cold").as_c_str().unwrap().to_bytes(), b"cold");
+    /// assert_eq!(ColdString::new("1234567").as_c_str(), None);
+    /// assert_eq!(ColdString::new("nul\0 inside the string").as_c_str(), None);
+    /// ```
+    pub fn as_c_str(&self) -> Option<&CStr> {
+        let bytes = self.as_bytes();
+        let terminated = if self.is_inline() {
+            bytes.len() + 1 < WIDTH
+        } else {
+            !self.is_static()
+        };
+        if !terminated || bytes.contains(&0) {
+            return None;
+        }
+        // SAFETY: inline bytes are followed by zeroed padding inside the word, and heap blocks
+        // end with a NUL byte after the string bytes
+        unsafe {
+            let with_nul = slice::from_raw_parts(bytes.as_ptr(), bytes.len() + 1);
+            Some(CStr::from_bytes_with_nul_unchecked(with_nul))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::StaticStr;
+
+    use alloc::{string::String, vec::Vec};
+
+    fn assert_terminated(s: &ColdString, expected: &str) {
+        let c = s.as_c_str().unwrap();
+        assert_eq!(c.to_bytes(), expected.as_bytes());
+        assert_eq!(c.to_bytes().as_ptr(), s.as_bytes().as_ptr());
+    }
+
+    #[test]
+    fn lengths_around_inline_boundary() {
+        for len in 0..=3 * WIDTH {
+            let expected: String = "abcdefghij".chars().cycle().take(len).collect();
+            let parts = [&expected[..len / 2], &expected[len / 2..]];
+            let mut uninit = ColdString::with_len(len);
+            uninit.writer().copy_from_slice(expected.as_bytes());
+            let strings = [
+                ColdString::new(&expected),
+                ColdString::from_utf8(expected.as_bytes()).unwrap(),
+                ColdString::try_new(&expected).unwrap(),
+                ColdString::concat(parts),
+                parts.iter().copied().collect(),
+                unsafe { uninit.finish_unchecked() },
+            ];
+            for s in strings.iter().chain(&[strings[0].clone()]) {
+                if len + 1 < WIDTH || len > WIDTH {
+                    assert_terminated(s, &expected);
+                } else {
+                    assert_eq!(s.as_c_str(), None);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn unterminated() {
+        static LONG: StaticStr = StaticStr::new("a static string without a terminator");
+        assert_eq!(ColdString::from_static(&LONG).as_c_str(), None);
+        assert_eq!(ColdString::new("\0").as_c_str(), None);
+        assert_eq!(ColdString::new("\0\0\0\0\0\0\0\0").as_c_str(), None);
+        let long: Vec<u8> = b"a heap string with a NUL\0 inside".to_vec();
+        assert_eq!(ColdString::from_utf8(&long).unwrap().as_c_str(), None);
+    }
+}
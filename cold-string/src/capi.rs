@@ -0,0 +1,229 @@
+#![cfg_attr(docsrs, doc(cfg(feature = "capi")))]
+//! A C API over owned [`ColdString`]s.
+//!
+//! A string is passed to C as a [`ColdStringHandle`], which is the one-word encoding of a
+//! [`ColdString`] and is only ever null if a constructor failed. Inline strings keep their bytes
+//! in the handle itself, so functions that read a string take a pointer to the caller's handle,
+//! and pointers into the string are valid until that handle is moved, overwritten or freed.
+//!
+//! Every type and function is `#[repr(C)]` or `extern "C"`, so a header can be generated with
+//! cbindgen. In C, a string is used like this:
+//! ```c
+//! ColdStringHandle s = cold_string_new((const uint8_t *)"hello", 5);
+//! if (cold_string_is_null(&s)) { /* invalid UTF-8 */ }
+//! const uint8_t *data;
+//! size_t len;
+//! cold_string_data(&s, &data, &len);
+//! printf("%.*s\n", (int)len, data);
+//! cold_string_free(s);
+//! ```
+
+use crate::ColdString;
+
+use core::{ffi::c_void, mem::ManuallyDrop, ptr, ptr::NonNull, slice, str};
+
+/// Returned by fallible functions on success.
+pub const COLD_STRING_OK: i32 = 0;
+/// Returned by fallible functions when a required pointer argument is null.
+pub const COLD_STRING_ERR_NULL: i32 = -1;
+
+/// An owned [`ColdString`], or null.
+///
+/// The handle is the encoded word of the string rather than the address of one. It must be
+/// freed with [`cold_string_free`] exactly once, and not copied, except by
+/// [`cold_string_clone`].
+#[repr(transparent)]
+#[derive(Debug, PartialEq, Eq)]
+pub struct ColdStringHandle(*mut c_void);
+
+impl ColdStringHandle {
+    /// The handle that holds no string.
+    pub const NULL: ColdStringHandle = ColdStringHandle(ptr::null_mut());
+
+    /// Returns `true` if this handle holds no string.
+    #[inline]
+    pub fn is_null(&self) -> bool {
+        self.0.is_null()
+    }
+
+    /// Converts `s` into a handle, which then owns its heap block, if any.
+    #[inline]
+    pub fn from_cold_string(s: ColdString) -> Self {
+        Self(ManuallyDrop::new(s).encoded.as_ptr() as *mut c_void)
+    }
+
+    /// Converts this handle back into a [`ColdString`], or returns `None` if it is null.
+    ///
+    /// # Safety
+    /// The handle must be null or have been returned by this module, and not freed.
+    #[inline]
+    pub unsafe fn into_cold_string(self) -> Option<ColdString> {
+        NonNull::new(self.0 as *mut u8).map(|encoded| ColdString { encoded })
+    }
+
+    /// SAFETY: `handle` must be null or point to a valid handle, which outlives `'a`.
+    unsafe fn borrow<'a>(handle: *const Self) -> Option<&'a ColdString> {
+        if handle.is_null() || (*handle).is_null() {
+            return None;
+        }
+        // SAFETY: a non-null handle has the layout of a `ColdString`
+        Some(&*(handle as *const ColdString))
+    }
+}
+
+/// Creates a string by copying `len` bytes from `data`.
+///
+/// Returns a null handle if `data` is null or the bytes are not UTF-8.
+///
+/// # Safety
+/// If `data` is not null, it must be valid for reads of `len` bytes.
+#[no_mangle]
+pub unsafe extern "C" fn cold_string_new(data: *const u8, len: usize) -> ColdStringHandle {
+    if data.is_null() {
+        return ColdStringHandle::NULL;
+    }
+    match str::from_utf8(slice::from_raw_parts(data, len)) {
+        Ok(s) => ColdStringHandle::from_cold_string(ColdString::new(s)),
+        Err(_) => ColdStringHandle::NULL,
+    }
+}
+
+/// Returns `true` if `handle` is null or points to a null handle.
+///
+/// # Safety
+/// If `handle` is not null, it must point to a handle.
+#[no_mangle]
+pub unsafe extern "C" fn cold_string_is_null(handle: *const ColdStringHandle) -> bool {
+    handle.is_null() || (*handle).is_null()
+}
+
+/// Returns the length in bytes of the string, or 0 if either handle is null.
+///
+/// # Safety
+/// If `handle` is not null, it must point to a handle that is null or not freed.
+#[no_mangle]
+pub unsafe extern "C" fn cold_string_len(handle: *const ColdStringHandle) -> usize {
+    ColdStringHandle::borrow(handle).map_or(0, ColdString::len)
+}
+
+/// Writes a pointer to the UTF-8 bytes of the string, which are not NUL-terminated, to `data`,
+/// and their length to `len`.
+///
+/// The pointer is valid until the handle at `handle` is moved, overwritten or freed, since
+/// inline strings are stored in the handle. Returns [`COLD_STRING_ERR_NULL`] without writing
+/// anything if any argument or the handle is null.
+///
+/// # Safety
+/// Non-null arguments must point to a handle that is null or not freed, and to writable
+/// locations.
+#[no_mangle]
+pub unsafe extern "C" fn cold_string_data(
+    handle: *const ColdStringHandle,
+    data: *mut *const u8,
+    len: *mut usize,
+) -> i32 {
+    match ColdStringHandle::borrow(handle) {
+        Some(s) if !data.is_null() && !len.is_null() => {
+            *data = s.as_bytes().as_ptr();
+            *len = s.len();
+            COLD_STRING_OK
+        }
+        _ => COLD_STRING_ERR_NULL,
+    }
+}
+
+/// Returns a new handle to a copy of the string, or a null handle if either handle is null.
+///
+/// # Safety
+/// If `handle` is not null, it must point to a handle that is null or not freed.
+#[no_mangle]
+pub unsafe extern "C" fn cold_string_clone(handle: *const ColdStringHandle) -> ColdStringHandle {
+    match ColdStringHandle::borrow(handle) {
+        Some(s) => ColdStringHandle::from_cold_string(s.clone()),
+        None => ColdStringHandle::NULL,
+    }
+}
+
+/// Frees the string. Does nothing if the handle is null.
+///
+/// # Safety
+/// The handle must be null or not freed, and must not be used afterwards.
+#[no_mangle]
+pub unsafe extern "C" fn cold_string_free(handle: ColdStringHandle) {
+    drop(handle.into_cold_string());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn data(handle: &ColdStringHandle) -> &str {
+        let mut data = ptr::null();
+        let mut len = usize::MAX;
+        unsafe {
+            assert_eq!(
+                cold_string_data(handle, &mut data, &mut len),
+                COLD_STRING_OK
+            );
+            str::from_utf8(slice::from_raw_parts(data, len)).unwrap()
+        }
+    }
+
+    #[test]
+    fn round_trip() {
+        for s in [
+            "",
+            "cold",
+            "12345678",
+            "🦀🦀",
+            "a string that is long enough for the heap",
+        ] {
+            unsafe {
+                let handle = cold_string_new(s.as_ptr(), s.len());
+                assert!(!cold_string_is_null(&handle));
+                assert_eq!(cold_string_len(&handle), s.len());
+                assert_eq!(data(&handle), s);
+
+                let clone = cold_string_clone(&handle);
+                cold_string_free(handle);
+                assert_eq!(data(&clone), s);
+                assert_eq!(clone.into_cold_string().unwrap(), s);
+            }
+        }
+    }
+
+    #[test]
+    fn null_and_invalid() {
+        unsafe {
+            assert!(cold_string_new(ptr::null(), 0).is_null());
+            let invalid = [b'a', 0xff];
+            assert!(cold_string_new(invalid.as_ptr(), invalid.len()).is_null());
+
+            let null = ColdStringHandle::NULL;
+            assert!(cold_string_is_null(&null));
+            assert!(cold_string_is_null(ptr::null()));
+            assert_eq!(cold_string_len(&null), 0);
+            assert_eq!(cold_string_len(ptr::null()), 0);
+            assert!(cold_string_clone(&null).is_null());
+            assert!(cold_string_clone(ptr::null()).is_null());
+            cold_string_free(ColdStringHandle::NULL);
+
+            let (mut data, mut len) = (ptr::null(), 0);
+            assert_eq!(
+                cold_string_data(&null, &mut data, &mut len),
+                COLD_STRING_ERR_NULL
+            );
+            let handle = ColdStringHandle::from_cold_string(ColdString::new("cold"));
+            assert_eq!(
+                cold_string_data(&handle, ptr::null_mut(), &mut len),
+                COLD_STRING_ERR_NULL
+            );
+            assert_eq!(
+                cold_string_data(&handle, &mut data, ptr::null_mut()),
+                COLD_STRING_ERR_NULL
+            );
+            assert!(data.is_null());
+            cold_string_free(handle);
+        }
+    }
+}
@@ -0,0 +1,715 @@
+use crate::ColdString;
+
+use core::{
+    borrow::Borrow,
+    cmp::Ordering,
+    hash::{Hash, Hasher},
+    iter::Copied,
+    ops::Deref,
+    slice,
+};
+#[cfg(feature = "unicase")]
+use unicase::UniCase;
+
+/// Iterator over the bytes of a string with ASCII uppercase letters mapped to lowercase.
+///
+/// Returned by [`ColdString::ascii_lowercase_bytes`].
+#[derive(Clone, Debug)]
+pub struct AsciiLowercaseBytes<'a> {
+    bytes: Copied<slice::Iter<'a, u8>>,
+}
+
+impl<'a> AsciiLowercaseBytes<'a> {
+    #[inline]
+    fn new(s: &'a str) -> Self {
+        Self {
+            bytes: s.as_bytes().iter().copied(),
+        }
+    }
+}
+
+impl Iterator for AsciiLowercaseBytes<'_> {
+    type Item = u8;
+
+    #[inline]
+    fn next(&mut self) -> Option<u8> {
+        self.bytes.next().map(|b| b.to_ascii_lowercase())
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.bytes.size_hint()
+    }
+}
+
+impl ExactSizeIterator for AsciiLowercaseBytes<'_> {}
+
+impl DoubleEndedIterator for AsciiLowercaseBytes<'_> {
+    #[inline]
+    fn next_back(&mut self) -> Option<u8> {
+        self.bytes.next_back().map(|b| b.to_ascii_lowercase())
+    }
+}
+
+#[inline]
+fn cmp_ignore_ascii_case(a: &str, b: &str) -> Ordering {
+    AsciiLowercaseBytes::new(a).cmp(AsciiLowercaseBytes::new(b))
+}
+
+impl ColdString {
+    /// Returns an iterator over the bytes of this string with ASCII letters lowercased, without
+    /// allocating. Non-ASCII bytes are returned unchanged.
+    ///
+    /// # Examples
+    /// ```
+    /// use cold_string::ColdString;
+    ///
+    /// let s = ColdString::new("HeLLo, Wörld");
+    /// assert!(s.ascii_lowercase_bytes().eq("hello, wörld".bytes()));
+    /// ```
+    #[inline]
+    pub fn ascii_lowercase_bytes(&self) -> AsciiLowercaseBytes<'_> {
+        AsciiLowercaseBytes::new(self.as_str())
+    }
+
+    /// Compares this string with `other`, ignoring ASCII case, without allocating.
+    ///
+    /// The result is the same as comparing [`str::to_ascii_lowercase`] of both strings.
+    ///
+    /// # Examples
+    /// ```
+    /// use cold_string::ColdString;
+    /// use core::cmp::Ordering;
+    ///
+    /// let s = ColdString::new("Apple");
+    /// assert_eq!(s.cmp_ignore_ascii_case("APPLE"), Ordering::Equal);
+    /// assert_eq!(s.cmp_ignore_ascii_case("banana"), Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn cmp_ignore_ascii_case(&self, other: &str) -> Ordering {
+        cmp_ignore_ascii_case(self.as_str(), other)
+    }
+
+    /// Returns `true` if this string equals `other`, ignoring ASCII case, like
+    /// [`str::eq_ignore_ascii_case`], without allocating.
+    ///
+    /// Non-ASCII bytes must match exactly. To compare with another [`ColdString`], use
+    /// [`ColdString::eq_ignore_ascii_case_cold`], which compares short strings a word at a
+    /// time.
+    ///
+    /// # Examples
+    /// ```
+    /// use cold_string::ColdString;
+    ///
+    /// let s = ColdString::new("Content-Type");
+    /// assert!(s.eq_ignore_ascii_case("content-type"));
+    /// assert!(!s.eq_ignore_ascii_case("content-typ"));
+    /// assert!(!ColdString::new("É").eq_ignore_ascii_case("é"));
+    /// ```
+    #[inline]
+    pub fn eq_ignore_ascii_case(&self, other: &str) -> bool {
+        let (a, b) = (self.as_bytes(), other.as_bytes());
+        a.len() == b.len() && a.eq_ignore_ascii_case(b)
+    }
+
+    /// Returns `true` if this string equals `other`, ignoring ASCII case.
+    ///
+    /// Inline strings of the same length have the same tag, so two inline strings are compared
+    /// by lowercasing both words at once.
+    ///
+    /// # Examples
+    /// ```
+    /// use cold_string::ColdString;
+    ///
+    /// let a = ColdString::new("ACCEPT");
+    /// assert!(a.eq_ignore_ascii_case_cold(&ColdString::new("accept")));
+    /// assert!(!a.eq_ignore_ascii_case_cold(&ColdString::new("accepts")));
+    /// ```
+    #[inline]
+    pub fn eq_ignore_ascii_case_cold(&self, other: &ColdString) -> bool {
+        if self.is_inline() && other.is_inline() {
+            return swar_to_ascii_lowercase(self.addr()) == swar_to_ascii_lowercase(other.addr());
+        }
+        self.eq_ignore_ascii_case(other.as_str())
+    }
+
+    /// Returns the lowercase equivalent of this string, like [`str::to_lowercase`].
+    ///
+    /// Lowercasing can change the length of the string, so the mapped chars are measured
+    /// first and then written once into an inline buffer or an exactly sized heap block. The
+    /// exception is a string containing `'Σ'`, whose mapping depends on the surrounding
+    /// letters and is left to [`str::to_lowercase`].
+    ///
+    /// # Examples
+    /// ```
+    /// use cold_string::ColdString;
+    ///
+    /// assert_eq!(ColdString::new("HELLO, WÖRLD").to_lowercase(), "hello, wörld");
+    /// assert_eq!(ColdString::new("İ").to_lowercase(), "i\u{307}");
+    /// assert_eq!(ColdString::new("ὈΔΥΣΣΕΎΣ").to_lowercase(), "ὀδυσσεύς");
+    /// ```
+    pub fn to_lowercase(&self) -> ColdString {
+        let s = self.as_str();
+        if s.contains('Σ') {
+            return ColdString::new(s.to_lowercase());
+        }
+        map_chars(s, char::to_lowercase)
+    }
+
+    /// Returns the uppercase equivalent of this string, like [`str::to_uppercase`].
+    ///
+    /// Uppercasing can change the length of the string, so the mapped chars are measured
+    /// first and then written once into an inline buffer or an exactly sized heap block.
+    ///
+    /// # Examples
+    /// ```
+    /// use cold_string::ColdString;
+    ///
+    /// assert_eq!(ColdString::new("hello, wörld").to_uppercase(), "HELLO, WÖRLD");
+    /// assert_eq!(ColdString::new("straße").to_uppercase(), "STRASSE");
+    /// ```
+    pub fn to_uppercase(&self) -> ColdString {
+        map_chars(self.as_str(), char::to_uppercase)
+    }
+
+    /// Returns a copy of this string with ASCII letters lowercased, like
+    /// [`str::to_ascii_lowercase`].
+    ///
+    /// The length doesn't change, so the bytes are mapped while they are copied. An inline
+    /// string is mapped a whole word at a time, and never allocates.
+    ///
+    /// # Examples
+    /// ```
+    /// use cold_string::ColdString;
+    ///
+    /// assert_eq!(ColdString::new("Content-Type").to_ascii_lowercase(), "content-type");
+    /// assert_eq!(ColdString::new("ÉCOLE").to_ascii_lowercase(), "École");
+    /// ```
+    pub fn to_ascii_lowercase(&self) -> ColdString {
+        self.map_ascii(swar_to_ascii_lowercase, u8::to_ascii_lowercase)
+    }
+
+    /// Returns a copy of this string with ASCII letters uppercased, like
+    /// [`str::to_ascii_uppercase`].
+    ///
+    /// The length doesn't change, so the bytes are mapped while they are copied. An inline
+    /// string is mapped a whole word at a time, and never allocates.
+    ///
+    /// # Examples
+    /// ```
+    /// use cold_string::ColdString;
+    ///
+    /// assert_eq!(ColdString::new("select").to_ascii_uppercase(), "SELECT");
+    /// assert_eq!(ColdString::new("école").to_ascii_uppercase(), "éCOLE");
+    /// ```
+    pub fn to_ascii_uppercase(&self) -> ColdString {
+        self.map_ascii(swar_to_ascii_uppercase, u8::to_ascii_uppercase)
+    }
+
+    #[inline]
+    fn map_ascii(&self, word: fn(usize) -> usize, byte: fn(&u8) -> u8) -> ColdString {
+        if self.is_inline() {
+            // The tag and the all-NUL encoding are non-ASCII bytes and padding is zero, so
+            // only the string bytes change.
+            // SAFETY: mapping keeps non-zero bytes non-zero
+            return unsafe { ColdString::from_inline_buf(word(self.addr()).to_ne_bytes()) };
+        }
+        let bytes = self.as_bytes();
+        let mut uninit = ColdString::with_len(bytes.len());
+        for (dst, src) in uninit.writer().iter_mut().zip(bytes) {
+            *dst = byte(src);
+        }
+        // SAFETY: mapping ASCII letters keeps the bytes UTF-8
+        unsafe { uninit.finish_unchecked() }
+    }
+}
+
+const ONES: usize = usize::MAX / 0xff;
+const HIGH: usize = ONES * 0x80;
+
+/// Returns the high bit of each byte of `w` that is in `lo..=hi`, which must be ASCII.
+#[inline]
+const fn swar_in_range(w: usize, lo: u8, hi: u8) -> usize {
+    let low7 = w & !HIGH;
+    // Adding `0x80 - lo` to a 7-bit byte carries into its high bit if it is at least `lo`.
+    let ge_lo = low7 + ONES * (0x80 - lo as usize);
+    let gt_hi = low7 + ONES * (0x7f - hi as usize);
+    ge_lo & !gt_hi & !w & HIGH
+}
+
+#[inline]
+fn swar_to_ascii_lowercase(w: usize) -> usize {
+    w | (swar_in_range(w, b'A', b'Z') >> 2)
+}
+
+#[inline]
+fn swar_to_ascii_uppercase(w: usize) -> usize {
+    w & !(swar_in_range(w, b'a', b'z') >> 2)
+}
+
+/// Maps each char of `s` to zero or more chars, writing the result once.
+fn map_chars<I, F>(s: &str, f: F) -> ColdString
+where
+    I: Iterator<Item = char>,
+    F: Fn(char) -> I,
+{
+    let len = s.chars().flat_map(&f).map(char::len_utf8).sum();
+    let mut uninit = ColdString::with_len(len);
+    let writer = uninit.writer();
+    let mut pos = 0;
+    for c in s.chars().flat_map(&f) {
+        pos += c.encode_utf8(&mut writer[pos..]).len();
+    }
+    // SAFETY: the bytes are encoded chars
+    unsafe { uninit.finish_unchecked() }
+}
+
+/// Wrapper that compares and hashes a string ignoring ASCII case, without allocating.
+///
+/// `Eq`, `Ord` and `Hash` all agree with those of [`str::to_ascii_lowercase`] of the inner
+/// string, so `CaseInsensitive<ColdString>` can be used as a `BTreeMap` or `HashMap` key.
+///
+/// # Examples
+/// ```
+/// use cold_string::{CaseInsensitive, ColdString};
+/// use std::collections::BTreeMap;
+///
+/// let mut map = BTreeMap::new();
+/// map.insert(CaseInsensitive(ColdString::new("Content-Type")), 1);
+/// *map.entry(CaseInsensitive(ColdString::new("content-type"))).or_insert(0) += 1;
+/// assert_eq!(map.len(), 1);
+/// assert_eq!(map[&CaseInsensitive(ColdString::new("CONTENT-TYPE"))], 2);
+/// ```
+#[derive(Clone, Copy, Debug, Default)]
+pub struct CaseInsensitive<T>(pub T);
+
+impl<T: AsRef<str>> PartialEq for CaseInsensitive<T> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.0.as_ref().eq_ignore_ascii_case(other.0.as_ref())
+    }
+}
+
+impl<T: AsRef<str>> Eq for CaseInsensitive<T> {}
+
+impl<T: AsRef<str>> PartialOrd for CaseInsensitive<T> {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T: AsRef<str>> Ord for CaseInsensitive<T> {
+    #[inline]
+    fn cmp(&self, other: &Self) -> Ordering {
+        cmp_ignore_ascii_case(self.0.as_ref(), other.0.as_ref())
+    }
+}
+
+impl<T: AsRef<str>> Hash for CaseInsensitive<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        // Lowercase through a stack buffer, so the hasher sees a few large writes.
+        let mut buf = [0u8; 64];
+        for chunk in self.0.as_ref().as_bytes().chunks(buf.len()) {
+            let buf = &mut buf[..chunk.len()];
+            buf.copy_from_slice(chunk);
+            buf.make_ascii_lowercase();
+            state.write(buf);
+        }
+        // Same terminator as `str`, so that e.g. ("ab", "c") and ("a", "bc") hash differently.
+        state.write_u8(0xff);
+    }
+}
+
+/// A [`ColdString`] whose `Eq`, `Ord` and `Hash` ignore ASCII case, for use as a map key.
+///
+/// These agree with those of [`str::to_ascii_lowercase`] of the string, like
+/// [`CaseInsensitive`]. The map can be queried by `&str` through [`Caseless`], the borrowed
+/// form of this type, without allocating a key.
+///
+/// With the `unicase` feature, they use Unicode case folding instead, like `unicase::UniCase`,
+/// so e.g. `"Maße"` and `"MASSE"` are equal. Since features are shared by the whole build,
+/// this applies to every `ColdCaseless` in it.
+///
+/// # Examples
+/// ```
+/// use cold_string::{Caseless, ColdCaseless, ColdString};
+/// use std::collections::HashMap;
+///
+/// let mut headers = HashMap::new();
+/// headers.insert(ColdCaseless::new(ColdString::new("Content-Type")), "text/plain");
+/// headers.insert(ColdCaseless::new(ColdString::new("CONTENT-TYPE")), "text/html");
+/// assert_eq!(headers.len(), 1);
+/// assert_eq!(headers[Caseless::new("content-type")], "text/html");
+/// ```
+#[repr(transparent)]
+#[derive(Clone, Debug, Default)]
+pub struct ColdCaseless(ColdString);
+
+impl ColdCaseless {
+    /// Wraps `s`.
+    #[inline]
+    pub const fn new(s: ColdString) -> Self {
+        Self(s)
+    }
+
+    /// Returns the wrapped string, with its original case.
+    #[inline]
+    pub fn as_cold(&self) -> &ColdString {
+        &self.0
+    }
+
+    /// Returns the wrapped string, with its original case.
+    #[inline]
+    pub fn into_inner(self) -> ColdString {
+        self.0
+    }
+}
+
+impl From<ColdString> for ColdCaseless {
+    #[inline]
+    fn from(s: ColdString) -> Self {
+        Self(s)
+    }
+}
+
+impl From<&str> for ColdCaseless {
+    #[inline]
+    fn from(s: &str) -> Self {
+        Self(ColdString::new(s))
+    }
+}
+
+impl Deref for ColdCaseless {
+    type Target = Caseless;
+
+    #[inline]
+    fn deref(&self) -> &Caseless {
+        Caseless::new(self.0.as_str())
+    }
+}
+
+impl Borrow<Caseless> for ColdCaseless {
+    #[inline]
+    fn borrow(&self) -> &Caseless {
+        self
+    }
+}
+
+impl PartialEq for ColdCaseless {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        #[cfg(not(feature = "unicase"))]
+        {
+            self.0.eq_ignore_ascii_case_cold(&other.0)
+        }
+        #[cfg(feature = "unicase")]
+        {
+            Caseless::eq(self, other)
+        }
+    }
+}
+
+impl Eq for ColdCaseless {}
+
+impl PartialOrd for ColdCaseless {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for ColdCaseless {
+    #[inline]
+    fn cmp(&self, other: &Self) -> Ordering {
+        Caseless::cmp(self, other)
+    }
+}
+
+impl Hash for ColdCaseless {
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        Caseless::hash(self, state)
+    }
+}
+
+/// A `str` whose `Eq`, `Ord` and `Hash` ignore case like [`ColdCaseless`]: its borrowed form,
+/// for looking up keys by `&str`.
+#[repr(transparent)]
+#[derive(Debug)]
+pub struct Caseless(str);
+
+impl Caseless {
+    /// Borrows `s` as a [`Caseless`].
+    #[inline]
+    pub fn new(s: &str) -> &Self {
+        // SAFETY: `Caseless` is a transparent wrapper around `str`
+        unsafe { &*(s as *const str as *const Caseless) }
+    }
+
+    /// Returns the string, with its original case.
+    #[inline]
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Returns the value that `Eq`, `Ord` and `Hash` compare.
+    #[cfg(not(feature = "unicase"))]
+    #[inline]
+    fn key(&self) -> CaseInsensitive<&str> {
+        CaseInsensitive(&self.0)
+    }
+
+    /// Returns the value that `Eq`, `Ord` and `Hash` compare.
+    #[cfg(feature = "unicase")]
+    #[inline]
+    fn key(&self) -> UniCase<&str> {
+        UniCase::new(&self.0)
+    }
+}
+
+impl PartialEq for Caseless {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.key() == other.key()
+    }
+}
+
+impl Eq for Caseless {}
+
+impl PartialOrd for Caseless {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Caseless {
+    #[inline]
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.key().cmp(&other.key())
+    }
+}
+
+impl Hash for Caseless {
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.key().hash(state)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::{collections::BTreeMap, string::String};
+    use core::hash::BuildHasher;
+    use hashbrown::hash_map::DefaultHashBuilder;
+
+    fn hash<T: Hash + ?Sized>(bh: &DefaultHashBuilder, t: &T) -> u64 {
+        let mut hasher = bh.build_hasher();
+        t.hash(&mut hasher);
+        hasher.finish()
+    }
+
+    #[test]
+    fn case_insensitive_keys() {
+        let bh = DefaultHashBuilder::new();
+        let long = "A Much Longer Key That Spans More Than One Chunk Of The Hash Buffer!";
+        for (a, b) in [
+            ("", ""),
+            ("a", "A"),
+            ("Cold", "cOLD"),
+            ("ÉCOLE", "École"),
+            (long, &*long.to_ascii_uppercase()),
+        ] {
+            let (a, b) = (
+                CaseInsensitive(ColdString::new(a)),
+                CaseInsensitive(ColdString::new(b)),
+            );
+            assert_eq!(a, b);
+            assert_eq!(a.cmp(&b), Ordering::Equal);
+            assert_eq!(hash(&bh, &a), hash(&bh, &b));
+            let lower = String::from(a.0.as_str()).to_ascii_lowercase();
+            assert_eq!(hash(&bh, &a), hash(&bh, &CaseInsensitive(lower.as_str())));
+        }
+        assert_ne!(CaseInsensitive("École"), CaseInsensitive("éCOLE"));
+        assert!(CaseInsensitive("apple") < CaseInsensitive("Banana"));
+        assert!(CaseInsensitive("Z") > CaseInsensitive("a"));
+    }
+
+    #[test]
+    fn case_insensitive_btree_map() {
+        let mut map = BTreeMap::new();
+        for key in ["b", "A", "a", "B", "c", "a longer key", "A LONGER KEY"] {
+            *map.entry(CaseInsensitive(ColdString::new(key)))
+                .or_insert(0) += 1;
+        }
+        let keys: alloc::vec::Vec<&str> = map.keys().map(|k| k.0.as_str()).collect();
+        assert_eq!(keys, ["A", "a longer key", "b", "c"]);
+        assert_eq!(map.values().sum::<i32>(), 7);
+    }
+
+    #[test]
+    fn unicode_case_mapping() {
+        for s in [
+            "",
+            "ABC",
+            "İstanbul",
+            "ıi",
+            "straße",
+            "ΣΑΣ",
+            "ὈΔΥΣΣΕΎΣ",
+            "a string long enough for the heap, MIXED Case 🦀",
+            "ﬃ ŉ ǰ",
+        ] {
+            let cold = ColdString::new(s);
+            let (lower, upper) = (s.to_lowercase(), s.to_uppercase());
+            assert_eq!(cold.to_lowercase(), lower.as_str());
+            assert_eq!(cold.to_uppercase(), upper.as_str());
+            assert_eq!(cold.to_lowercase().is_inline(), lower.len() <= crate::WIDTH);
+            assert_eq!(cold.to_uppercase().is_inline(), upper.len() <= crate::WIDTH);
+        }
+        // Both grow past the inline capacity.
+        assert!(!ColdString::new("ßßßßß").to_uppercase().is_inline());
+        assert!(!ColdString::new("İİİ").to_lowercase().is_inline());
+    }
+
+    #[test]
+    fn swar_matches_bytes() {
+        for b in 0..=255u8 {
+            for pos in 0..crate::WIDTH {
+                let mut bytes = [b'@'; crate::WIDTH];
+                bytes[pos] = b;
+                let w = usize::from_ne_bytes(bytes);
+                let lower = swar_to_ascii_lowercase(w).to_ne_bytes();
+                let upper = swar_to_ascii_uppercase(w).to_ne_bytes();
+                assert_eq!(lower, bytes.map(|b| b.to_ascii_lowercase()));
+                assert_eq!(upper, bytes.map(|b| b.to_ascii_uppercase()));
+            }
+        }
+    }
+
+    #[test]
+    fn eq_ignore_ascii_case() {
+        let strs = [
+            "",
+            "a",
+            "A",
+            "b",
+            "Accept",
+            "aCCEPT",
+            "accept!",
+            "ÉCOLE",
+            "éCOLE",
+            "École",
+            "\0\0\0\0\0\0\0\0",
+            "12345678",
+            "CONTENT-TYPE",
+            "content-type",
+            "Content-Typo",
+            "A STRING LONG ENOUGH FOR THE HEAP",
+            "a string long enough for the heap",
+        ];
+        for a in strs {
+            let cold_a = ColdString::new(a);
+            for b in strs {
+                let expected = a.eq_ignore_ascii_case(b);
+                assert_eq!(cold_a.eq_ignore_ascii_case(b), expected, "{:?} {:?}", a, b);
+                let cold_b = ColdString::new(b);
+                assert_eq!(cold_a.eq_ignore_ascii_case_cold(&cold_b), expected);
+                let ordering = a.to_ascii_lowercase().cmp(&b.to_ascii_lowercase());
+                assert_eq!(cold_a.cmp_ignore_ascii_case(b), ordering);
+            }
+        }
+    }
+
+    #[test]
+    fn ascii_case_mapping() {
+        let long = "MiXeD CaSe 🦀 and a heap-allocated TAIL";
+        for end in 0..=long.len() {
+            for s in (0..=end).filter_map(|start| long.get(start..end)) {
+                let cold = ColdString::new(s);
+                let lower = cold.to_ascii_lowercase();
+                assert_eq!(lower, s.to_ascii_lowercase().as_str());
+                assert_eq!(lower.is_inline(), cold.is_inline());
+                assert_eq!(cold.to_ascii_uppercase(), s.to_ascii_uppercase().as_str());
+            }
+        }
+        let nul = ColdString::new("\0\0\0\0\0\0\0\0");
+        assert_eq!(nul.to_ascii_lowercase(), nul);
+        assert_eq!(nul.to_ascii_uppercase(), nul);
+    }
+
+    #[test]
+    fn caseless_keys() {
+        let bh = DefaultHashBuilder::new();
+        let keys = [
+            "Content-Type",
+            "ACCEPT",
+            "École",
+            "A Header Name Long Enough For The Heap",
+        ];
+        let mut map = hashbrown::HashMap::new();
+        for (i, key) in keys.iter().enumerate() {
+            for variant in [
+                String::from(*key),
+                key.to_ascii_lowercase(),
+                key.to_ascii_uppercase(),
+            ] {
+                let cold = ColdCaseless::from(variant.as_str());
+                assert_eq!(cold, ColdCaseless::from(*key));
+                assert_eq!(hash(&bh, &cold), hash(&bh, &ColdCaseless::from(*key)));
+                assert_eq!(hash(&bh, &cold), hash(&bh, Caseless::new(key)));
+                map.insert(cold, i);
+            }
+        }
+        assert_eq!(map.len(), keys.len());
+        for (i, key) in keys.iter().enumerate() {
+            assert_eq!(map[Caseless::new(&key.to_ascii_uppercase())], i);
+            let (stored, _) = map.get_key_value(Caseless::new(key)).unwrap();
+            assert_eq!(
+                stored.as_cold().to_ascii_lowercase(),
+                key.to_ascii_lowercase().as_str()
+            );
+        }
+        #[cfg(not(feature = "unicase"))]
+        assert_eq!(map.get(Caseless::new("éCOLE")), None);
+        assert_ne!(ColdCaseless::from("a"), ColdCaseless::from("b"));
+        assert!(ColdCaseless::from("apple") < ColdCaseless::from("Banana"));
+        assert_eq!(ColdCaseless::from("Cold").into_inner(), "Cold");
+    }
+
+    #[cfg(feature = "unicase")]
+    #[test]
+    fn unicase_keys() {
+        let bh = DefaultHashBuilder::new();
+        for (a, b) in [
+            ("École", "éCOLE"),
+            ("Maße", "MASSE"),
+            ("\u{212a}elvin", "kelvin"),
+            (
+                "A Header Name Long Enough For The Heap",
+                "a header name long enough for the heap",
+            ),
+        ] {
+            let (a, b) = (ColdCaseless::from(a), ColdCaseless::from(b));
+            assert_eq!(a, b);
+            assert_eq!(a.cmp(&b), Ordering::Equal);
+            assert_eq!(hash(&bh, &a), hash(&bh, &b));
+            assert_eq!(hash(&bh, &a), hash(&bh, Caseless::new(b.as_str())));
+        }
+        let mut map = hashbrown::HashMap::new();
+        map.insert(ColdCaseless::from("Straße"), 1);
+        assert_eq!(map[Caseless::new("STRASSE")], 1);
+        assert_ne!(ColdCaseless::from("Maße"), ColdCaseless::from("Mase"));
+    }
+
+    #[test]
+    fn lowercase_bytes() {
+        let s = ColdString::new("MiXeD CaSe 🦀 and a heap-allocated TAIL");
+        let expected = s.to_ascii_lowercase();
+        assert!(s.ascii_lowercase_bytes().eq(expected.bytes()));
+        assert!(s.ascii_lowercase_bytes().rev().eq(expected.bytes().rev()));
+        assert_eq!(s.ascii_lowercase_bytes().len(), s.len());
+    }
+}
@@ -0,0 +1,124 @@
+#![cfg_attr(docsrs, doc(cfg(feature = "std")))]
+//! Build-time generation of `static` [`ColdString`] tables.
+//!
+//! Building a large keyword table with [`ColdString::new`] at startup costs both time and
+//! heap. The helpers here are meant to be called from a `build.rs` and emit Rust source that
+//! declares the table with [`ColdString::new_inline_const`], so the whole table lives in the
+//! binary's read-only data and nothing is allocated at runtime.
+//!
+//! A heap [`ColdString`] encodes the address of its allocation, or of a [`StaticStr`], which
+//! can't be computed in a const context. So a table with an entry too long to be inline is
+//! emitted as an array of [`StaticStr`]s instead, and [`ColdString::from_static`] turns each
+//! entry into a [`ColdString`] without allocating. The generated code requires Rust 1.61, the
+//! first version with `new_inline_const`.
+//!
+//! # Examples
+//! In `build.rs`:
+//! ```no_run
+//! let src = cold_string::codegen::static_table("KEYWORDS", &["fn", "let", "mut"]);
+//! let out = std::path::Path::new(&std::env::var("OUT_DIR").unwrap()).join("keywords.rs");
+//! std::fs::write(out, src).unwrap();
+//! ```
+//! And in the crate:
+//! ```ignore
+//! include!(concat!(env!("OUT_DIR"), "/keywords.rs"));
+//! ```
+
+use crate::WIDTH;
+#[cfg(doc)]
+use crate::{ColdString, StaticStr};
+
+use core::fmt::Write;
+use std::{env, string::String};
+
+/// Returns the number of bytes a [`ColdString`] inlines on the compilation target.
+///
+/// Inside a build script this is read from `CARGO_CFG_TARGET_POINTER_WIDTH`, since the script
+/// itself may run on a host with a different pointer width. Elsewhere it is the host's width.
+fn target_width() -> usize {
+    env::var("CARGO_CFG_TARGET_POINTER_WIDTH")
+        .ok()
+        .and_then(|bits| bits.parse::<usize>().ok())
+        .map_or(WIDTH, |bits| bits / 8)
+}
+
+/// Generates the source of a `pub static` array named `name`, holding each of `entries`, in
+/// order.
+///
+/// If every entry is at most `size_of::<usize>()` bytes long on the target, the array holds
+/// inline [`ColdString`]s. Otherwise it holds [`StaticStr`]s, which [`ColdString::from_static`]
+/// converts without allocating.
+///
+/// # Examples
+/// ```
+/// let src = cold_string::codegen::static_table("TABLE", &["ab", "c"]);
+/// assert_eq!(
+///     src,
+///     r#"pub static TABLE: [::cold_string::ColdString; 2] = [
+///     ::cold_string::ColdString::new_inline_const("ab"),
+///     ::cold_string::ColdString::new_inline_const("c"),
+/// ];
+/// "#
+/// );
+///
+/// let src = cold_string::codegen::static_table("TABLE", &["ab", "a longer entry"]);
+/// assert_eq!(
+///     src,
+///     r#"pub static TABLE: [::cold_string::StaticStr; 2] = [
+///     ::cold_string::StaticStr::new("ab"),
+///     ::cold_string::StaticStr::new("a longer entry"),
+/// ];
+/// "#
+/// );
+/// ```
+pub fn static_table<S: AsRef<str>>(name: &str, entries: &[S]) -> String {
+    let max = target_width();
+    let (ty, ctor) = if entries.iter().all(|entry| entry.as_ref().len() <= max) {
+        ("ColdString", "ColdString::new_inline_const")
+    } else {
+        ("StaticStr", "StaticStr::new")
+    };
+    let mut src = String::new();
+    let _ = writeln!(
+        src,
+        "pub static {}: [::cold_string::{}; {}] = [",
+        name,
+        ty,
+        entries.len()
+    );
+    for entry in entries {
+        // `str`'s Debug output is a valid Rust string literal.
+        let _ = writeln!(src, "    ::cold_string::{}({:?}),", ctor, entry.as_ref());
+    }
+    src.push_str("];\n");
+    src
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::format;
+
+    #[test]
+    fn escapes_entries() {
+        let src = static_table("T", &["\"", "\\", "\n\0", "é\u{301}"]);
+        assert!(src.contains(r#"new_inline_const("\"")"#));
+        assert!(src.contains(r#"new_inline_const("\\")"#));
+        assert!(src.contains(r#"new_inline_const("\n\0")"#));
+        assert!(src.contains(r#"new_inline_const("é\u{301}")"#));
+    }
+
+    #[test]
+    fn long_entries_use_static_str() {
+        let long = "x".repeat(WIDTH + 1);
+        let src = static_table("T", &["ok", &long]);
+        assert!(src.starts_with("pub static T: [::cold_string::StaticStr; 2] = [\n"));
+        assert!(src.contains(r#"::cold_string::StaticStr::new("ok"),"#));
+        assert!(src.contains(&format!("::cold_string::StaticStr::new({:?}),", long)));
+        assert!(static_table("T", &["x".repeat(WIDTH)]).contains("new_inline_const"));
+        assert_eq!(
+            static_table::<&str>("EMPTY", &[]),
+            "pub static EMPTY: [::cold_string::ColdString; 0] = [\n];\n"
+        );
+    }
+}
@@ -0,0 +1,129 @@
+use crate::ColdString;
+
+use core::fmt;
+
+/// The error returned by [`ColdString::copy_to_slice`] when the destination is too small.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct CopyError {
+    required: usize,
+    available: usize,
+}
+
+impl CopyError {
+    /// Returns the number of bytes the destination needs, which is the string's length.
+    pub fn required(&self) -> usize {
+        self.required
+    }
+
+    /// Returns the length of the destination.
+    pub fn available(&self) -> usize {
+        self.available
+    }
+}
+
+impl fmt::Display for CopyError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "destination of {} bytes is too small for {} bytes",
+            self.available, self.required
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for CopyError {}
+
+impl ColdString {
+    /// Copies the bytes of this string to the start of `dst`, returning the number of bytes
+    /// copied, which is [`ColdString::len`].
+    ///
+    /// # Errors
+    /// Returns a [`CopyError`] holding the required length, without copying anything, if `dst`
+    /// is shorter than the string.
+    ///
+    /// # Examples
+    /// ```
+    /// use cold_string::ColdString;
+    ///
+    /// let mut buf = [0u8; 8];
+    /// assert_eq!(ColdString::new("cold").copy_to_slice(&mut buf), Ok(4));
+    /// assert_eq!(&buf[..4], b"cold");
+    ///
+    /// let err = ColdString::new("a string on the heap").copy_to_slice(&mut buf).unwrap_err();
+    /// assert_eq!(err.required(), 20);
+    /// ```
+    pub fn copy_to_slice(&self, dst: &mut [u8]) -> Result<usize, CopyError> {
+        let bytes = self.as_bytes();
+        match dst.get_mut(..bytes.len()) {
+            Some(dst) => {
+                dst.copy_from_slice(bytes);
+                Ok(bytes.len())
+            }
+            None => Err(CopyError {
+                required: bytes.len(),
+                available: dst.len(),
+            }),
+        }
+    }
+
+    /// Writes this string to a [`fmt::Write`] sink.
+    ///
+    /// # Examples
+    /// ```
+    /// use cold_string::ColdString;
+    ///
+    /// let mut out = String::from("key=");
+    /// ColdString::new("value").write_into(&mut out).unwrap();
+    /// assert_eq!(out, "key=value");
+    /// ```
+    #[inline]
+    pub fn write_into<W: fmt::Write + ?Sized>(&self, w: &mut W) -> fmt::Result {
+        w.write_str(self.as_str())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use alloc::string::String;
+
+    #[test]
+    fn copy_to_slice() {
+        let mut buf = [0xaau8; 64];
+        for s in [
+            "",
+            "cold",
+            "12345678",
+            "🦀🦀",
+            "a string long enough for the heap",
+        ] {
+            let cold = ColdString::new(s);
+            assert_eq!(cold.copy_to_slice(&mut buf[..s.len()]), Ok(s.len()));
+            assert_eq!(&buf[..s.len()], s.as_bytes());
+            assert_eq!(cold.copy_to_slice(&mut buf), Ok(s.len()));
+            if !s.is_empty() {
+                let mut short = [0xaau8; 40];
+                let short = &mut short[..s.len() - 1];
+                let err = cold.copy_to_slice(short).unwrap_err();
+                assert_eq!((err.required(), err.available()), (s.len(), s.len() - 1));
+                assert!(short.iter().all(|&b| b == 0xaa));
+            }
+        }
+        assert!(ColdString::new("x").copy_to_slice(&mut []).is_err());
+        assert_eq!(ColdString::EMPTY.copy_to_slice(&mut []), Ok(0));
+    }
+
+    #[test]
+    fn write_into() {
+        let mut out = String::new();
+        for s in ["cold", ", ", "a string long enough for the heap"] {
+            ColdString::new(s).write_into(&mut out).unwrap();
+        }
+        assert_eq!(out, "cold, a string long enough for the heap");
+        let sink: &mut dyn fmt::Write = &mut out;
+        ColdString::new("!").write_into(sink).unwrap();
+        assert!(out.ends_with('!'));
+    }
+}
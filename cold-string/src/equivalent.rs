@@ -0,0 +1,75 @@
+#![cfg_attr(docsrs, doc(cfg(feature = "equivalent")))]
+//! `str` keys already work with maps built on the `equivalent` crate, such as hashbrown and
+//! indexmap, through its blanket impls over [`Borrow<str>`](core::borrow::Borrow). These impls
+//! add lookups by `&String`.
+
+use crate::ColdString;
+
+use alloc::string::String;
+use core::cmp::Ordering;
+use equivalent::{Comparable, Equivalent};
+
+impl Equivalent<ColdString> for String {
+    #[inline]
+    fn equivalent(&self, key: &ColdString) -> bool {
+        self.as_str() == key.as_str()
+    }
+}
+
+impl Comparable<ColdString> for String {
+    #[inline]
+    fn compare(&self, key: &ColdString) -> Ordering {
+        self.as_str().cmp(key.as_str())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use hashbrown_equivalent::HashMap;
+    use indexmap::IndexMap;
+
+    const KEYS: [&str; 4] = ["", "cold", "12345678", "a string long enough for the heap"];
+
+    #[test]
+    fn hashbrown_lookups() {
+        let map: HashMap<ColdString, usize> = KEYS
+            .iter()
+            .enumerate()
+            .map(|(i, k)| (ColdString::new(k), i))
+            .collect();
+        for (i, k) in KEYS.iter().enumerate() {
+            assert_eq!(map.get(*k), Some(&i));
+            assert_eq!(map.get(&String::from(*k)), Some(&i));
+        }
+        assert_eq!(map.get("missing"), None);
+        assert_eq!(map.get(&String::from("a string that is missing")), None);
+    }
+
+    #[test]
+    fn indexmap_lookups() {
+        let map: IndexMap<ColdString, usize> = KEYS
+            .iter()
+            .enumerate()
+            .map(|(i, k)| (ColdString::new(k), i))
+            .collect();
+        for (i, k) in KEYS.iter().enumerate() {
+            assert_eq!(map.get_index_of(*k), Some(i));
+            assert_eq!(map.get(&String::from(*k)), Some(&i));
+        }
+        assert_eq!(map.get("missing"), None);
+        assert_eq!(map.get(&String::from("a string that is missing")), None);
+    }
+
+    #[test]
+    fn compare() {
+        for a in KEYS {
+            for b in KEYS {
+                let cold = ColdString::new(b);
+                assert_eq!(String::from(a).compare(&cold), a.cmp(b));
+                assert_eq!(String::from(a).equivalent(&cold), a == b);
+                assert_eq!(a.compare(&cold), a.cmp(b));
+            }
+        }
+    }
+}
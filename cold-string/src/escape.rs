@@ -0,0 +1,83 @@
+use crate::ColdString;
+
+impl ColdString {
+    /// Returns this string escaped like [`str::escape_debug`], as used by its `Debug` output.
+    ///
+    /// Escaping can make the string much longer, so the escaped chars are measured first and
+    /// then written once into an inline buffer or an exactly sized heap block.
+    ///
+    /// # Examples
+    /// ```
+    /// use cold_string::ColdString;
+    ///
+    /// let s = ColdString::new("user \"input\"\n\u{0}");
+    /// assert_eq!(s.escape_debug(), r#"user \"input\"\n\0"#);
+    /// assert_eq!(ColdString::new("🦀\t").escape_debug(), "🦀\\t");
+    /// ```
+    pub fn escape_debug(&self) -> ColdString {
+        let s = self.as_str();
+        collect_twice(|| s.escape_debug())
+    }
+
+    /// Returns this string escaped like [`str::escape_default`], with every char outside of
+    /// printable ASCII escaped.
+    ///
+    /// # Examples
+    /// ```
+    /// use cold_string::ColdString;
+    ///
+    /// assert_eq!(ColdString::new("é\n").escape_default(), r"\u{e9}\n");
+    /// ```
+    pub fn escape_default(&self) -> ColdString {
+        let s = self.as_str();
+        collect_twice(|| s.escape_default())
+    }
+}
+
+/// Collects the chars of an iterator that can be recreated, measuring them in a first pass so
+/// the second pass writes them once.
+fn collect_twice<I, F>(chars: F) -> ColdString
+where
+    I: Iterator<Item = char>,
+    F: Fn() -> I,
+{
+    let len = chars().map(char::len_utf8).sum();
+    let mut uninit = ColdString::with_len(len);
+    let writer = uninit.writer();
+    let mut pos = 0;
+    for c in chars() {
+        pos += c.encode_utf8(&mut writer[pos..]).len();
+    }
+    // SAFETY: the bytes are encoded chars
+    unsafe { uninit.finish_unchecked() }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::WIDTH;
+
+    use alloc::format;
+
+    #[test]
+    fn matches_str_escapes() {
+        for s in [
+            "",
+            "plain",
+            "\"'\\",
+            "\0\x07\x1b\x7f\r\n\t",
+            "\u{301}leading combining mark, then a\u{301}",
+            "astral 🦀 \u{10ffff} \u{e0001}",
+            "\u{200b}\u{feff}\u{ad}",
+        ] {
+            let cold = ColdString::new(s);
+            let expected = format!("{}", s.escape_debug());
+            assert_eq!(cold.escape_debug(), expected.as_str());
+            assert_eq!(cold.escape_debug().is_inline(), expected.len() <= WIDTH);
+            let expected = format!("{}", s.escape_default());
+            assert_eq!(cold.escape_default(), expected.as_str());
+        }
+        assert!(ColdString::new("\n").escape_debug().is_inline());
+        assert!(!ColdString::new("\u{0}\u{1}").escape_default().is_inline());
+    }
+}
@@ -0,0 +1,165 @@
+use crate::{ColdString, WIDTH};
+
+use alloc::alloc::Layout;
+use core::{fmt, ptr, str, str::Utf8Error};
+
+/// The error returned by the fallible constructors, such as [`ColdString::try_new`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TryNewError {
+    len: usize,
+    kind: Kind,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+enum Kind {
+    CapacityOverflow,
+    AllocFailed(Layout),
+    Utf8(Utf8Error),
+}
+
+impl TryNewError {
+    pub(crate) fn capacity_overflow(len: usize) -> Self {
+        Self {
+            len,
+            kind: Kind::CapacityOverflow,
+        }
+    }
+
+    pub(crate) fn alloc_failed(len: usize, layout: Layout) -> Self {
+        Self {
+            len,
+            kind: Kind::AllocFailed(layout),
+        }
+    }
+
+    /// Returns the length in bytes of the string that couldn't be created.
+    pub fn requested_len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if the string was rejected for not being valid UTF-8, rather than for
+    /// failing to allocate.
+    pub fn is_utf8_error(&self) -> bool {
+        matches!(self.kind, Kind::Utf8(_))
+    }
+
+    /// Returns the UTF-8 error, if the string was rejected for not being valid UTF-8.
+    pub fn utf8_error(&self) -> Option<Utf8Error> {
+        match self.kind {
+            Kind::Utf8(err) => Some(err),
+            _ => None,
+        }
+    }
+
+    /// Returns the layout of the failed allocation, if the allocator was called.
+    pub(crate) fn layout(&self) -> Option<Layout> {
+        match self.kind {
+            Kind::AllocFailed(layout) => Some(layout),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for TryNewError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.kind {
+            Kind::CapacityOverflow => write!(f, "capacity overflow for {} bytes", self.len),
+            Kind::AllocFailed(_) => write!(f, "failed to allocate a string of {} bytes", self.len),
+            Kind::Utf8(err) => fmt::Display::fmt(err, f),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for TryNewError {}
+
+impl ColdString {
+    /// Creates a new [`ColdString`], returning an error instead of aborting if the heap block
+    /// can't be allocated.
+    ///
+    /// Strings of up to `core::mem::size_of::<usize>()` bytes are inline and never fail.
+    ///
+    /// # Errors
+    /// Returns [`TryNewError`] if the allocation fails or its size overflows.
+    ///
+    /// # Examples
+    /// ```
+    /// use cold_string::ColdString;
+    ///
+    /// let s = ColdString::try_new("a string that lives on the heap").unwrap();
+    /// assert_eq!(s, "a string that lives on the heap");
+    /// ```
+    pub fn try_new<T: AsRef<str>>(x: T) -> Result<Self, TryNewError> {
+        let s = x.as_ref();
+        if s.len() <= WIDTH {
+            return Ok(Self::new_inline(s));
+        }
+        let (ptr, header) = Self::try_alloc_heap(s.len(), false)?;
+        unsafe {
+            ptr::copy_nonoverlapping(s.as_ptr(), ptr.add(header), s.len());
+            // SAFETY: the block is from `try_alloc_heap`, and filled with a `str`
+            Ok(Self::from_heap_ptr(ptr))
+        }
+    }
+
+    /// Converts a slice of bytes into a [`ColdString`], like [`ColdString::from_utf8`], but
+    /// returns an error instead of aborting if the heap block can't be allocated.
+    ///
+    /// # Errors
+    /// Returns [`TryNewError`] if the bytes are not valid UTF-8, or the allocation fails.
+    ///
+    /// # Examples
+    /// ```
+    /// use cold_string::ColdString;
+    ///
+    /// assert_eq!(ColdString::try_from_utf8(b"cold").unwrap(), "cold");
+    /// assert!(ColdString::try_from_utf8(b"\xff").unwrap_err().is_utf8_error());
+    /// ```
+    pub fn try_from_utf8<B: AsRef<[u8]>>(v: B) -> Result<Self, TryNewError> {
+        let v = v.as_ref();
+        match str::from_utf8(v) {
+            Ok(s) => Self::try_new(s),
+            Err(err) => Err(TryNewError {
+                len: v.len(),
+                kind: Kind::Utf8(err),
+            }),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn inline_never_fails() {
+        for s in ["", "a", "cold", "12345678", "\0\0\0\0\0\0\0\0"] {
+            let s = &s[..s.len().min(WIDTH)];
+            assert_eq!(ColdString::try_new(s).unwrap(), s);
+            assert!(ColdString::try_new(s).unwrap().is_inline());
+            assert_eq!(ColdString::try_from_utf8(s).unwrap(), s);
+        }
+    }
+
+    #[test]
+    fn heap() {
+        let s = "a string long enough for the heap";
+        assert_eq!(ColdString::try_new(s).unwrap(), s);
+        assert_eq!(ColdString::try_from_utf8(s).unwrap(), s);
+    }
+
+    #[test]
+    fn errors() {
+        let err = ColdString::try_from_utf8(b"cold\xff").unwrap_err();
+        assert!(err.is_utf8_error());
+        assert_eq!(err.requested_len(), 5);
+        assert_eq!(err.utf8_error().unwrap().valid_up_to(), 4);
+
+        let err = ColdString::try_alloc_heap(usize::MAX, false).unwrap_err();
+        assert_eq!(err, TryNewError::capacity_overflow(usize::MAX));
+        assert!(!err.is_utf8_error());
+        assert_eq!(err.layout(), None);
+        let err = ColdString::try_alloc_heap(isize::MAX as usize, false).unwrap_err();
+        assert_eq!(err.requested_len(), isize::MAX as usize);
+    }
+}
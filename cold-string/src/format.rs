@@ -0,0 +1,361 @@
+use crate::{ColdString, WIDTH};
+
+use core::{fmt, str};
+
+/// Creates a [`ColdString`] using interpolation of runtime expressions, like
+/// [`format!`](alloc::format).
+///
+/// Short results are inline and never allocate. Longer results are formatted twice, once to
+/// measure and once into their heap block, so they are allocated exactly once.
+///
+/// # Panics
+/// Panics if a formatting trait implementation returns an error, like `format!`.
+///
+/// # Examples
+/// ```
+/// use cold_string::cold_format;
+///
+/// let id = 42;
+/// assert_eq!(cold_format!("user:{}", id), "user:42");
+/// assert_eq!(cold_format!("{name:>6}|{:.2}", 1.0, name = "x"), "     x|1.00");
+/// ```
+#[macro_export]
+macro_rules! cold_format {
+    ($($arg:tt)*) => {
+        $crate::ColdString::from_fmt(::core::format_args!($($arg)*))
+    };
+}
+
+impl ColdString {
+    /// Creates a [`ColdString`] from formatting arguments. This is what
+    /// [`cold_format!`](crate::cold_format) expands to.
+    ///
+    /// Literal-only arguments, for which [`fmt::Arguments::as_str`] returns the string, are
+    /// copied directly without going through the formatter.
+    ///
+    /// # Panics
+    /// Panics if a formatting trait implementation returns an error.
+    ///
+    /// # Examples
+    /// ```
+    /// use cold_string::ColdString;
+    ///
+    /// assert_eq!(ColdString::from_fmt(format_args!("{}-{}", 1, 2)), "1-2");
+    /// ```
+    pub fn from_fmt(args: fmt::Arguments<'_>) -> Self {
+        if let Some(s) = args.as_str() {
+            return Self::new(s);
+        }
+        let mut measure = Measure {
+            buf: [0; WIDTH],
+            len: 0,
+        };
+        fmt::write(&mut measure, args)
+            .expect("a formatting trait implementation returned an error");
+        if measure.len <= WIDTH {
+            // SAFETY: the buffer holds all of the formatted `str`s
+            return Self::new(unsafe { str::from_utf8_unchecked(&measure.buf[..measure.len]) });
+        }
+
+        let mut uninit = Self::with_len(measure.len);
+        let mut fill = Fill {
+            buf: uninit.writer(),
+            len: 0,
+        };
+        if fmt::write(&mut fill, args).is_ok() && fill.len == measure.len {
+            // SAFETY: the buffer is filled with formatted `str`s
+            return unsafe { uninit.finish_unchecked() };
+        }
+        // The arguments formatted differently the second time.
+        let mut builder = crate::ColdStringBuilder::with_capacity(measure.len);
+        fmt::write(&mut builder, args)
+            .expect("a formatting trait implementation returned an error");
+        builder.finish()
+    }
+}
+
+impl ColdString {
+    /// Creates a [`ColdString`] from the [`Display`](fmt::Display) output of `value`, without
+    /// the intermediate [`String`](alloc::string::String) of `ColdString::new(value.to_string())`.
+    ///
+    /// Short output is inline and never allocates, and longer output is allocated exactly once,
+    /// as with [`ColdString::from_fmt`].
+    ///
+    /// # Panics
+    /// Panics if the [`Display`](fmt::Display) implementation returns an error.
+    ///
+    /// # Examples
+    /// ```
+    /// use cold_string::ColdString;
+    /// use std::net::Ipv4Addr;
+    ///
+    /// assert_eq!(ColdString::from_display(&1234u32), "1234");
+    /// assert_eq!(ColdString::from_display(&Ipv4Addr::new(10, 0, 0, 1)), "10.0.0.1");
+    /// ```
+    #[inline]
+    pub fn from_display<T: fmt::Display + ?Sized>(value: &T) -> Self {
+        Self::from_fmt(format_args!("{}", value))
+    }
+
+    /// Pads this string with `fill` to `width` chars, like formatting it with
+    /// `{:<width$}`, `{:>width$}` or `{:^width$}`.
+    ///
+    /// Like the formatter, `width` counts chars, and centering puts the extra fill char on the
+    /// right. The padded length is computed up front, so the result is written once. A string
+    /// of at least `width` chars is cloned.
+    ///
+    /// # Panics
+    /// Panics if the length of the result overflows `usize`.
+    ///
+    /// # Examples
+    /// ```
+    /// use cold_string::ColdString;
+    /// use core::fmt::Alignment;
+    ///
+    /// let s = ColdString::new("42");
+    /// assert_eq!(s.pad(6, Alignment::Right, ' '), "    42");
+    /// assert_eq!(s.pad(6, Alignment::Left, '.'), "42....");
+    /// assert_eq!(s.pad(5, Alignment::Center, '·'), "·42··");
+    /// assert_eq!(s.pad(1, Alignment::Right, ' '), "42");
+    /// ```
+    pub fn pad(&self, width: usize, align: fmt::Alignment, fill: char) -> Self {
+        let s = self.as_str();
+        let chars = s.chars().count();
+        if chars >= width {
+            return self.clone();
+        }
+        let padding = width - chars;
+        let (before, after) = match align {
+            fmt::Alignment::Left => (0, padding),
+            fmt::Alignment::Right => (padding, 0),
+            fmt::Alignment::Center => (padding / 2, padding - padding / 2),
+        };
+        let mut fill_buf = [0u8; 4];
+        let fill = fill.encode_utf8(&mut fill_buf).as_bytes();
+        let len = padding
+            .checked_mul(fill.len())
+            .and_then(|padding| padding.checked_add(s.len()))
+            .expect("capacity overflow");
+        let mut uninit = Self::with_len(len);
+        let writer = uninit.writer();
+        let (head, rest) = writer.split_at_mut(before * fill.len());
+        let (middle, tail) = rest.split_at_mut(s.len());
+        for chunk in head.chunks_exact_mut(fill.len()) {
+            chunk.copy_from_slice(fill);
+        }
+        middle.copy_from_slice(s.as_bytes());
+        for chunk in tail.chunks_exact_mut(fill.len()) {
+            chunk.copy_from_slice(fill);
+        }
+        debug_assert_eq!(tail.len(), after * fill.len());
+        // SAFETY: the bytes are a `str` surrounded by encoded chars
+        unsafe { uninit.finish_unchecked() }
+    }
+}
+
+/// Converts a value to a [`ColdString`], like [`ToString`](alloc::string::ToString).
+///
+/// Implemented for every [`Display`](fmt::Display) type through
+/// [`ColdString::from_display`], so short output never allocates. Without specialization,
+/// `str`, `String` and [`ColdString`] go through the formatter too, which for them only
+/// measures the string and copies it once.
+///
+/// # Examples
+/// ```
+/// use cold_string::ToColdString;
+///
+/// assert_eq!(42.to_cold_string(), "42");
+/// assert_eq!("abc".to_cold_string(), "abc");
+/// ```
+pub trait ToColdString {
+    /// Converts `self` to a [`ColdString`].
+    fn to_cold_string(&self) -> ColdString;
+}
+
+impl<T: fmt::Display + ?Sized> ToColdString for T {
+    #[inline]
+    fn to_cold_string(&self) -> ColdString {
+        ColdString::from_display(self)
+    }
+}
+
+/// Measures formatted output, keeping it while it fits inline.
+struct Measure {
+    buf: [u8; WIDTH],
+    len: usize,
+}
+
+impl fmt::Write for Measure {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        let len = self.len.checked_add(s.len()).ok_or(fmt::Error)?;
+        if len <= WIDTH {
+            self.buf[self.len..len].copy_from_slice(s.as_bytes());
+        }
+        self.len = len;
+        Ok(())
+    }
+}
+
+/// Writes formatted output into a buffer of exactly the measured length.
+struct Fill<'a> {
+    buf: &'a mut [u8],
+    len: usize,
+}
+
+impl fmt::Write for Fill<'_> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        let dst = self
+            .buf
+            .get_mut(self.len..self.len + s.len())
+            .ok_or(fmt::Error)?;
+        dst.copy_from_slice(s.as_bytes());
+        self.len += s.len();
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::cell::Cell;
+
+    #[test]
+    fn pad_matches_format() {
+        use alloc::format;
+        use fmt::Alignment::*;
+
+        for s in ["", "a", "42", "é🦀", "a string long enough for the heap"] {
+            let cold = ColdString::new(s);
+            for width in [0, 1, 2, 5, 8, 9, 40] {
+                let cases = [
+                    (Left, ' ', format!("{:<1$}", s, width)),
+                    (Right, ' ', format!("{:>1$}", s, width)),
+                    (Center, ' ', format!("{:^1$}", s, width)),
+                    (Left, '·', format!("{:·<1$}", s, width)),
+                    (Right, '🦀', format!("{:🦀>1$}", s, width)),
+                    (Center, 'é', format!("{:é^1$}", s, width)),
+                ];
+                for (align, fill, expected) in cases {
+                    let padded = cold.pad(width, align, fill);
+                    assert_eq!(padded, expected.as_str());
+                    assert_eq!(padded.is_inline(), expected.len() <= WIDTH);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn format_args_syntax() {
+        let (x, name) = (7, "cold");
+        assert_eq!(cold_format!(""), "");
+        assert_eq!(cold_format!("literal only"), "literal only");
+        assert_eq!(cold_format!("{}", x), "7");
+        assert_eq!(cold_format!("{x}"), "7");
+        assert_eq!(cold_format!("{n}:{n}", n = name), "cold:cold");
+        assert_eq!(cold_format!("{:>8}", name), "    cold");
+        assert_eq!(cold_format!("{:<1$}|", name, 6), "cold  |");
+        assert_eq!(cold_format!("{:.3}", 1.23456), "1.235");
+        assert_eq!(cold_format!("{:08.3}", -1.5), "-001.500");
+        assert_eq!(cold_format!("{:?}", "q"), "\"q\"");
+        assert_eq!(cold_format!("{:#x}", 255), "0xff");
+        let long = cold_format!("{name} is a string long enough for the {}", "heap");
+        assert_eq!(long, "cold is a string long enough for the heap");
+        for len in [WIDTH - 1, WIDTH, WIDTH + 1] {
+            let s = cold_format!("{:1$}", "", len);
+            assert_eq!(s.len(), len);
+            assert_eq!(s.is_inline(), len <= WIDTH);
+        }
+    }
+
+    /// Writes each char of a string with its own `write_str` call.
+    struct Pieces<'a>(&'a str);
+
+    impl fmt::Display for Pieces<'_> {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            for c in self.0.chars() {
+                f.write_str(c.encode_utf8(&mut [0; 4]))?;
+            }
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn from_fmt() {
+        let literal = format_args!("a literal long enough for the heap");
+        assert_eq!(literal.as_str(), Some("a literal long enough for the heap"));
+        assert_eq!(
+            ColdString::from_fmt(literal),
+            "a literal long enough for the heap"
+        );
+        assert_eq!(ColdString::from_fmt(format_args!("")), ColdString::EMPTY);
+        for s in [
+            "",
+            "é",
+            "🦀é",
+            "a🦀b🦀",
+            "né🦀é🦀é🦀 and a tail for the heap",
+        ] {
+            let cold = ColdString::from_fmt(format_args!("{}", Pieces(s)));
+            assert_eq!(cold, s);
+            assert_eq!(cold.is_inline(), s.len() <= WIDTH);
+            let cold = ColdString::from_fmt(format_args!("{}|{}", Pieces(s), Pieces(s)));
+            assert_eq!(cold, [s, "|", s].concat().as_str());
+        }
+    }
+
+    #[test]
+    fn from_display() {
+        use alloc::string::ToString;
+
+        for n in [0u64, 42, 12345678, 123456789, u64::MAX] {
+            let cold = ColdString::from_display(&n);
+            assert_eq!(cold, n.to_string().as_str());
+            assert_eq!(cold.is_inline(), n.to_string().len() <= WIDTH);
+        }
+        assert_eq!(ColdString::from_display(&'🦀'), "🦀");
+        assert_eq!(ColdString::from_display("str"), "str");
+        let pieces = Pieces("🦀 streamed one char at a time 🦀");
+        assert_eq!(ColdString::from_display(&pieces), pieces.0);
+        let dyn_display: &dyn fmt::Display = &-1.5f32;
+        assert_eq!(ColdString::from_display(dyn_display), "-1.5");
+    }
+
+    #[test]
+    fn to_cold_string() {
+        use alloc::{
+            format,
+            string::{String, ToString},
+        };
+
+        for s in ["", "abc", "12345678", "a string long enough for the heap"] {
+            assert_eq!(s.to_cold_string(), s);
+            assert_eq!(String::from(s).to_cold_string(), s);
+            assert_eq!(ColdString::new(s).to_cold_string(), s);
+            assert_eq!(
+                format_args!("{:>10}", s).to_cold_string(),
+                format!("{:>10}", s).as_str()
+            );
+        }
+        assert_eq!(42.to_cold_string(), 42.to_string().as_str());
+        assert_eq!((-0.5f64).to_cold_string(), (-0.5f64).to_string().as_str());
+        assert_eq!('é'.to_cold_string(), "é");
+        assert_eq!(true.to_cold_string(), "true");
+    }
+
+    /// Formats longer the second time it is formatted.
+    struct Unstable(Cell<usize>);
+
+    impl fmt::Display for Unstable {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            self.0.set(self.0.get() + 1);
+            write!(f, "{}", "x".repeat(self.0.get() * WIDTH))
+        }
+    }
+
+    #[test]
+    fn unstable_display() {
+        // Measured at 2 * WIDTH, filled at 3 * WIDTH, then built again.
+        let s = cold_format!("{}", Unstable(Cell::new(1)));
+        assert_eq!(s, "x".repeat(4 * WIDTH).as_str());
+    }
+}
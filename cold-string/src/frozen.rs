@@ -0,0 +1,264 @@
+//! [`FrozenColdStringList`], an immutable list of strings packed into one buffer.
+
+use crate::ColdString;
+
+use alloc::{boxed::Box, string::String, vec::Vec};
+use core::{
+    cmp::Ordering,
+    convert::TryFrom,
+    fmt,
+    iter::{FromIterator, FusedIterator},
+    ops::Index,
+};
+
+/// An immutable list of strings, whose bytes are packed back to back in one allocation.
+///
+/// Each string costs its bytes plus a 4-byte offset, with no allocator overhead or alignment
+/// padding per string, which suits large read-only dictionaries. The total length of the
+/// strings must fit in a `u32`. [`FrozenColdStringList::to_cold`] copies one entry into an
+/// owned [`ColdString`].
+///
+/// # Examples
+/// ```
+/// use cold_string::FrozenColdStringList;
+///
+/// let list: FrozenColdStringList = ["apple", "banana", "cherry"].iter().collect();
+/// assert_eq!(list.len(), 3);
+/// assert_eq!(&list[1], "banana");
+/// assert_eq!(list.get(3), None);
+/// assert_eq!(list.binary_search("cherry"), Ok(2));
+/// assert_eq!(list.iter().collect::<Vec<_>>(), ["apple", "banana", "cherry"]);
+/// ```
+#[derive(Clone, Default, PartialEq, Eq, Hash)]
+pub struct FrozenColdStringList {
+    bytes: Box<str>,
+    /// The end of each string in `bytes`, where the next one starts.
+    ends: Box<[u32]>,
+}
+
+impl FrozenColdStringList {
+    /// Returns the number of strings.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.ends.len()
+    }
+
+    /// Returns `true` if there are no strings.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.ends.is_empty()
+    }
+
+    /// Returns the string at `index`, or `None` if it is out of bounds.
+    #[inline]
+    pub fn get(&self, index: usize) -> Option<&str> {
+        let end = *self.ends.get(index)? as usize;
+        let start = match index {
+            0 => 0,
+            _ => self.ends[index - 1] as usize,
+        };
+        // SAFETY: every string starts and ends on a boundary of the strings it was built from
+        Some(unsafe { self.bytes.get_unchecked(start..end) })
+    }
+
+    /// Copies the string at `index` into a [`ColdString`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds.
+    ///
+    /// # Examples
+    /// ```
+    /// use cold_string::FrozenColdStringList;
+    ///
+    /// let list: FrozenColdStringList = ["a string long enough for the heap"].iter().collect();
+    /// let owned = list.to_cold(0);
+    /// drop(list);
+    /// assert_eq!(owned, "a string long enough for the heap");
+    /// ```
+    #[inline]
+    pub fn to_cold(&self, index: usize) -> ColdString {
+        ColdString::new(&self[index])
+    }
+
+    /// Returns an iterator over the strings.
+    #[inline]
+    pub fn iter(&self) -> FrozenIter<'_> {
+        FrozenIter {
+            list: self,
+            front: 0,
+            back: self.len(),
+        }
+    }
+
+    /// Binary searches this list for `s`, like [`slice::binary_search`].
+    ///
+    /// The list must be sorted, for example by collecting a sorted iterator, or the result is
+    /// unspecified.
+    pub fn binary_search(&self, s: &str) -> Result<usize, usize> {
+        let (mut lo, mut hi) = (0, self.len());
+        while lo < hi {
+            let mid = lo + (hi - lo) / 2;
+            match self[mid].cmp(s) {
+                Ordering::Less => lo = mid + 1,
+                Ordering::Greater => hi = mid,
+                Ordering::Equal => return Ok(mid),
+            }
+        }
+        Err(lo)
+    }
+}
+
+/// # Panics
+///
+/// Panics if the total length of the strings doesn't fit in a `u32`.
+impl<S: AsRef<str>> FromIterator<S> for FrozenColdStringList {
+    fn from_iter<I: IntoIterator<Item = S>>(iter: I) -> Self {
+        let iter = iter.into_iter();
+        let mut bytes = String::new();
+        let mut ends = Vec::with_capacity(iter.size_hint().0);
+        for s in iter {
+            bytes.push_str(s.as_ref());
+            ends.push(u32::try_from(bytes.len()).expect("capacity overflow"));
+        }
+        Self {
+            bytes: bytes.into_boxed_str(),
+            ends: ends.into_boxed_slice(),
+        }
+    }
+}
+
+impl Index<usize> for FrozenColdStringList {
+    type Output = str;
+
+    #[inline]
+    fn index(&self, index: usize) -> &str {
+        match self.get(index) {
+            Some(s) => s,
+            None => panic!(
+                "index out of bounds: the len is {} but the index is {}",
+                self.len(),
+                index
+            ),
+        }
+    }
+}
+
+impl fmt::Debug for FrozenColdStringList {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<'a> IntoIterator for &'a FrozenColdStringList {
+    type Item = &'a str;
+    type IntoIter = FrozenIter<'a>;
+
+    #[inline]
+    fn into_iter(self) -> FrozenIter<'a> {
+        self.iter()
+    }
+}
+
+/// An iterator over the strings of a [`FrozenColdStringList`].
+#[derive(Clone, Debug)]
+pub struct FrozenIter<'a> {
+    list: &'a FrozenColdStringList,
+    front: usize,
+    back: usize,
+}
+
+impl<'a> Iterator for FrozenIter<'a> {
+    type Item = &'a str;
+
+    #[inline]
+    fn next(&mut self) -> Option<&'a str> {
+        if self.front == self.back {
+            return None;
+        }
+        self.front += 1;
+        self.list.get(self.front - 1)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.back - self.front;
+        (len, Some(len))
+    }
+}
+
+impl<'a> DoubleEndedIterator for FrozenIter<'a> {
+    #[inline]
+    fn next_back(&mut self) -> Option<&'a str> {
+        if self.front == self.back {
+            return None;
+        }
+        self.back -= 1;
+        self.list.get(self.back)
+    }
+}
+
+impl ExactSizeIterator for FrozenIter<'_> {}
+
+impl FusedIterator for FrozenIter<'_> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn packed_strings() {
+        let strs = [
+            "",
+            "cold",
+            "",
+            "a string long enough for the heap",
+            "🦀",
+            "12345678",
+        ];
+        let list: FrozenColdStringList = strs.iter().collect();
+        assert_eq!(list.len(), strs.len());
+        assert_eq!(
+            list.bytes.len(),
+            strs.iter().map(|s| s.len()).sum::<usize>()
+        );
+        for (i, s) in strs.iter().enumerate() {
+            assert_eq!(list.get(i), Some(*s));
+            assert_eq!(&list[i], *s);
+            assert_eq!(list.to_cold(i), *s);
+        }
+        assert_eq!(list.get(strs.len()), None);
+        assert!(list.iter().eq(strs));
+        assert!(list.iter().rev().eq(strs.iter().rev().copied()));
+        let mut iter = list.iter();
+        assert_eq!(iter.next(), Some(""));
+        assert_eq!(iter.next_back(), Some("12345678"));
+        assert_eq!(iter.len(), strs.len() - 2);
+        assert_eq!(alloc::format!("{:?}", list), alloc::format!("{:?}", strs));
+        assert_eq!(list.clone(), list);
+
+        let empty = FrozenColdStringList::default();
+        assert!(empty.is_empty());
+        assert_eq!(empty.iter().next(), None);
+        assert_eq!(empty.binary_search("a"), Err(0));
+    }
+
+    #[test]
+    fn binary_search() {
+        let strs = ["", "a", "ab", "b", "banana", "cherry", "é"];
+        let list: FrozenColdStringList = strs.iter().collect();
+        for (i, s) in strs.iter().enumerate() {
+            assert_eq!(list.binary_search(s), Ok(i));
+        }
+        for s in ["0", "aa", "c", "z", "🦀"] {
+            assert_eq!(list.binary_search(s), strs.binary_search(&s));
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "index out of bounds")]
+    fn index_out_of_bounds() {
+        let list: FrozenColdStringList = ["a"].iter().collect();
+        let _ = &list[1];
+    }
+}
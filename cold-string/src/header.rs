@@ -0,0 +1,165 @@
+//! The length header at the start of every heap block.
+//!
+//! By default, the header is the length as a LEB128 [`VarInt`] of 1 to 10 bytes. With the
+//! `fixed-header` feature, it is a little-endian `u32` holding `len << 1 | 1`, which is read
+//! without a data-dependent loop, at the cost of up to 3 bytes per heap string and a maximum
+//! length of `u32::MAX >> 1`. Either way, the first byte of a header is non-zero, which tells
+//! heap blocks apart from `StaticStr`s.
+
+#[cfg(not(feature = "fixed-header"))]
+use crate::vint::VarInt;
+
+#[cfg(feature = "fixed-header")]
+use core::ptr;
+
+pub struct Header;
+
+#[cfg(not(feature = "fixed-header"))]
+impl Header {
+    /// The largest header length.
+    pub const MAX: usize = 10;
+
+    /// Encodes the header for `len` bytes, returning its length and bytes, or `None` if `len`
+    /// is too long.
+    #[cfg(test)]
+    pub const fn write(len: usize) -> Option<(usize, [u8; Self::MAX])> {
+        Some(VarInt::write(len as u64))
+    }
+
+    /// Returns the length of the header for `len` bytes, or `None` if `len` is too long.
+    #[inline]
+    pub const fn encoded_len(len: usize) -> Option<usize> {
+        Some(VarInt::encoded_len(len as u64))
+    }
+
+    /// Writes the header for `len` bytes to `ptr`, returning its length.
+    ///
+    /// SAFETY: `ptr` must be valid for writes of `encoded_len(len)` bytes.
+    #[allow(unsafe_op_in_unsafe_fn)]
+    #[inline]
+    pub unsafe fn write_to(ptr: *mut u8, len: usize) -> usize {
+        VarInt::write_to(ptr, len as u64)
+    }
+
+    /// Returns the string length and the header length.
+    ///
+    /// SAFETY: `ptr` must point to a header.
+    #[allow(unsafe_op_in_unsafe_fn)]
+    #[rustversion::attr(since(1.61), const)]
+    #[inline]
+    pub unsafe fn read(ptr: *const u8) -> (usize, usize) {
+        VarInt::read(ptr)
+    }
+
+    /// Like [`Header::read`], but returns `None` if the header is not within `block`.
+    pub fn read_checked(block: &[u8]) -> Option<(usize, usize)> {
+        let mut len = 0usize;
+        for (i, &byte) in block.iter().enumerate().take(Self::MAX) {
+            len |= ((byte & 0x7F) as usize).checked_shl(7 * i as u32)?;
+            if byte & 0x80 == 0 {
+                // SAFETY: the varint is within `block`
+                debug_assert_eq!(unsafe { Self::read(block.as_ptr()) }, (len, i + 1));
+                return Some((len, i + 1));
+            }
+        }
+        None
+    }
+}
+
+#[cfg(feature = "fixed-header")]
+impl Header {
+    /// The largest header length.
+    pub const MAX: usize = 4;
+
+    /// Encodes the header for `len` bytes, returning its length and bytes, or `None` if `len`
+    /// is too long.
+    #[cfg(test)]
+    pub const fn write(len: usize) -> Option<(usize, [u8; Self::MAX])> {
+        if len > (u32::MAX >> 1) as usize {
+            return None;
+        }
+        Some((Self::MAX, ((len as u32) << 1 | 1).to_le_bytes()))
+    }
+
+    /// Returns the length of the header for `len` bytes, or `None` if `len` is too long.
+    #[inline]
+    pub const fn encoded_len(len: usize) -> Option<usize> {
+        if len > (u32::MAX >> 1) as usize {
+            return None;
+        }
+        Some(Self::MAX)
+    }
+
+    /// Writes the header for `len` bytes to `ptr`, returning its length.
+    ///
+    /// SAFETY: `ptr` must be valid for writes of `encoded_len(len)` bytes, and `len` must not
+    /// be too long.
+    #[allow(unsafe_op_in_unsafe_fn)]
+    #[inline]
+    pub unsafe fn write_to(ptr: *mut u8, len: usize) -> usize {
+        ptr::write_unaligned(
+            ptr as *mut [u8; Self::MAX],
+            ((len as u32) << 1 | 1).to_le_bytes(),
+        );
+        Self::MAX
+    }
+
+    /// Returns the string length and the header length.
+    ///
+    /// SAFETY: `ptr` must point to a header.
+    #[allow(unsafe_op_in_unsafe_fn)]
+    #[rustversion::attr(since(1.61), const)]
+    #[inline]
+    pub unsafe fn read(ptr: *const u8) -> (usize, usize) {
+        let header = u32::from_le_bytes(*(ptr as *const [u8; Self::MAX]));
+        ((header >> 1) as usize, Self::MAX)
+    }
+
+    /// Like [`Header::read`], but returns `None` if the header is not within `block` or its
+    /// marker bit is not set.
+    pub fn read_checked(block: &[u8]) -> Option<(usize, usize)> {
+        let header = block.get(..Self::MAX)?;
+        if header[0] & 1 == 0 {
+            return None;
+        }
+        // SAFETY: the header is within `block`
+        Some(unsafe { Self::read(header.as_ptr()) })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn header_round_trip() {
+        let lens = (1..=u16::MAX as usize).chain([
+            u32::MAX as usize >> 1,
+            u32::MAX as usize >> 2,
+            1 << 24,
+            (1 << 24) + 1,
+        ]);
+        for len in lens {
+            let (header_len, buf) = Header::write(len).unwrap();
+            assert!(buf[0] != 0);
+            assert_eq!(unsafe { Header::read(buf.as_ptr()) }, (len, header_len));
+            assert_eq!(Header::read_checked(&buf), Some((len, header_len)));
+            assert_eq!(Header::read_checked(&buf[..header_len - 1]), None);
+            assert_eq!(Header::encoded_len(len), Some(header_len));
+            let mut direct = [0u8; Header::MAX];
+            assert_eq!(
+                unsafe { Header::write_to(direct.as_mut_ptr(), len) },
+                header_len
+            );
+            assert_eq!(direct[..header_len], buf[..header_len]);
+        }
+    }
+
+    #[cfg(feature = "fixed-header")]
+    #[test]
+    fn fixed_header_limits() {
+        assert_eq!(Header::write((u32::MAX >> 1) as usize + 1), None);
+        assert_eq!(Header::encoded_len((u32::MAX >> 1) as usize + 1), None);
+        assert_eq!(Header::read_checked(&[0, 1, 0, 0]), None);
+    }
+}
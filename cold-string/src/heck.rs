@@ -0,0 +1,269 @@
+#![cfg_attr(docsrs, doc(cfg(feature = "heck")))]
+//! Identifier case styles from the [`heck`] crate, built into a [`ColdString`].
+
+use crate::{ColdString, ColdStringBuilder};
+
+use core::fmt;
+use heck::{AsKebabCase, AsLowerCamelCase, AsShoutySnakeCase, AsSnakeCase, AsUpperCamelCase};
+
+/// Writes `case` through a [`ColdStringBuilder`], so short results stay inline.
+fn build<T: fmt::Display>(len: usize, case: T) -> ColdString {
+    let mut b = ColdStringBuilder::with_capacity(len);
+    fmt::write(&mut b, format_args!("{}", case)).expect("heck's Display never fails");
+    b.finish()
+}
+
+impl ColdString {
+    /// Converts this identifier to `snake_case`, like [`heck::ToSnakeCase`].
+    ///
+    /// # Examples
+    /// ```
+    /// use cold_string::ColdString;
+    ///
+    /// assert_eq!(ColdString::new("XMLHttpRequest").to_snake_case(), "xml_http_request");
+    /// assert_eq!(ColdString::new("maxRetries").to_snake_case(), "max_retries");
+    /// ```
+    pub fn to_snake_case(&self) -> ColdString {
+        build(self.len(), AsSnakeCase(self.as_str()))
+    }
+
+    /// Converts this identifier to `kebab-case`, like [`heck::ToKebabCase`].
+    ///
+    /// # Examples
+    /// ```
+    /// use cold_string::ColdString;
+    ///
+    /// assert_eq!(ColdString::new("maxRetries").to_kebab_case(), "max-retries");
+    /// ```
+    pub fn to_kebab_case(&self) -> ColdString {
+        build(self.len(), AsKebabCase(self.as_str()))
+    }
+
+    /// Converts this identifier to `SHOUTY_SNAKE_CASE`, like [`heck::ToShoutySnakeCase`].
+    ///
+    /// # Examples
+    /// ```
+    /// use cold_string::ColdString;
+    ///
+    /// assert_eq!(ColdString::new("maxRetries").to_shouty_snake_case(), "MAX_RETRIES");
+    /// ```
+    pub fn to_shouty_snake_case(&self) -> ColdString {
+        build(self.len(), AsShoutySnakeCase(self.as_str()))
+    }
+
+    /// Converts this identifier to `camelCase`, with the first word in lowercase, like
+    /// [`heck::ToLowerCamelCase`].
+    ///
+    /// # Examples
+    /// ```
+    /// use cold_string::ColdString;
+    ///
+    /// assert_eq!(ColdString::new("max_retries").to_camel_case(), "maxRetries");
+    /// assert_eq!(ColdString::new("XMLHttpRequest").to_camel_case(), "xmlHttpRequest");
+    /// ```
+    pub fn to_camel_case(&self) -> ColdString {
+        build(self.len(), AsLowerCamelCase(self.as_str()))
+    }
+
+    /// Converts this identifier to `PascalCase`, like [`heck::ToUpperCamelCase`].
+    ///
+    /// # Examples
+    /// ```
+    /// use cold_string::ColdString;
+    ///
+    /// assert_eq!(ColdString::new("max_retries").to_pascal_case(), "MaxRetries");
+    /// ```
+    pub fn to_pascal_case(&self) -> ColdString {
+        build(self.len(), AsUpperCamelCase(self.as_str()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::WIDTH;
+
+    /// Inputs with the output of `heck` 0.5 for snake, shouty snake, camel and Pascal case.
+    const CASES: [(&str, &str, &str, &str, &str); 24] = [
+        ("", "", "", "", ""),
+        (
+            "CamelCase",
+            "camel_case",
+            "CAMEL_CASE",
+            "camelCase",
+            "CamelCase",
+        ),
+        (
+            "This is Human case.",
+            "this_is_human_case",
+            "THIS_IS_HUMAN_CASE",
+            "thisIsHumanCase",
+            "ThisIsHumanCase",
+        ),
+        (
+            "MixedUP CamelCase, with some Spaces",
+            "mixed_up_camel_case_with_some_spaces",
+            "MIXED_UP_CAMEL_CASE_WITH_SOME_SPACES",
+            "mixedUpCamelCaseWithSomeSpaces",
+            "MixedUpCamelCaseWithSomeSpaces",
+        ),
+        (
+            "mixed_up_ snake_case with some _spaces",
+            "mixed_up_snake_case_with_some_spaces",
+            "MIXED_UP_SNAKE_CASE_WITH_SOME_SPACES",
+            "mixedUpSnakeCaseWithSomeSpaces",
+            "MixedUpSnakeCaseWithSomeSpaces",
+        ),
+        (
+            "kebab-case",
+            "kebab_case",
+            "KEBAB_CASE",
+            "kebabCase",
+            "KebabCase",
+        ),
+        (
+            "SHOUTY_SNAKE_CASE",
+            "shouty_snake_case",
+            "SHOUTY_SNAKE_CASE",
+            "shoutySnakeCase",
+            "ShoutySnakeCase",
+        ),
+        (
+            "this-contains_ ALLKinds OfWord_Boundaries",
+            "this_contains_all_kinds_of_word_boundaries",
+            "THIS_CONTAINS_ALL_KINDS_OF_WORD_BOUNDARIES",
+            "thisContainsAllKindsOfWordBoundaries",
+            "ThisContainsAllKindsOfWordBoundaries",
+        ),
+        (
+            "XΣXΣ baﬄe",
+            "xσxς_baﬄe",
+            "XΣXΣ_BAFFLE",
+            "xσxςBaﬄe",
+            "XσxςBaﬄe",
+        ),
+        (
+            "XMLHttpRequest",
+            "xml_http_request",
+            "XML_HTTP_REQUEST",
+            "xmlHttpRequest",
+            "XmlHttpRequest",
+        ),
+        (
+            "FIELD_NAME11",
+            "field_name11",
+            "FIELD_NAME11",
+            "fieldName11",
+            "FieldName11",
+        ),
+        (
+            "99BOTTLES",
+            "99bottles",
+            "99BOTTLES",
+            "99bottles",
+            "99bottles",
+        ),
+        (
+            "FieldNamE11",
+            "field_nam_e11",
+            "FIELD_NAM_E11",
+            "fieldNamE11",
+            "FieldNamE11",
+        ),
+        (
+            "abc123def456",
+            "abc123def456",
+            "ABC123DEF456",
+            "abc123def456",
+            "Abc123def456",
+        ),
+        (
+            "abc123DEF456",
+            "abc123_def456",
+            "ABC123_DEF456",
+            "abc123Def456",
+            "Abc123Def456",
+        ),
+        (
+            "abc123Def456",
+            "abc123_def456",
+            "ABC123_DEF456",
+            "abc123Def456",
+            "Abc123Def456",
+        ),
+        (
+            "abc123DEf456",
+            "abc123_d_ef456",
+            "ABC123_D_EF456",
+            "abc123DEf456",
+            "Abc123DEf456",
+        ),
+        (
+            "ABC123def456",
+            "abc123def456",
+            "ABC123DEF456",
+            "abc123def456",
+            "Abc123def456",
+        ),
+        (
+            "ABC123DEf456",
+            "abc123d_ef456",
+            "ABC123D_EF456",
+            "abc123dEf456",
+            "Abc123dEf456",
+        ),
+        (
+            "ABC123dEEf456FOO",
+            "abc123d_e_ef456_foo",
+            "ABC123D_E_EF456_FOO",
+            "abc123dEEf456Foo",
+            "Abc123dEEf456Foo",
+        ),
+        ("ABcDE", "a_bc_de", "A_BC_DE", "aBcDe", "ABcDe"),
+        (
+            "__leading_underscores",
+            "leading_underscores",
+            "LEADING_UNDERSCORES",
+            "leadingUnderscores",
+            "LeadingUnderscores",
+        ),
+        (
+            "über_größe",
+            "über_größe",
+            "ÜBER_GRÖSSE",
+            "überGröße",
+            "ÜberGröße",
+        ),
+        (
+            "ÉcoleNormale",
+            "école_normale",
+            "ÉCOLE_NORMALE",
+            "écoleNormale",
+            "ÉcoleNormale",
+        ),
+    ];
+
+    #[test]
+    fn matches_heck() {
+        for (s, snake, shouty, camel, pascal) in CASES {
+            let cold = ColdString::new(s);
+            assert_eq!(cold.to_snake_case(), snake, "{:?}", s);
+            assert_eq!(cold.to_kebab_case(), snake.replace('_', "-").as_str());
+            assert_eq!(cold.to_shouty_snake_case(), shouty, "{:?}", s);
+            assert_eq!(cold.to_camel_case(), camel, "{:?}", s);
+            assert_eq!(cold.to_pascal_case(), pascal, "{:?}", s);
+        }
+    }
+
+    #[test]
+    fn short_results_are_inline() {
+        let cold = ColdString::new("a_heap_sized_identifier_x");
+        assert!(!cold.is_inline());
+        let pascal = ColdString::new("A_b_C").to_pascal_case();
+        assert_eq!(pascal, "ABC");
+        assert!(pascal.is_inline());
+        for s in [cold.to_camel_case(), cold.to_snake_case()] {
+            assert_eq!(s.is_inline(), s.len() <= WIDTH);
+        }
+    }
+}
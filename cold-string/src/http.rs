@@ -0,0 +1,85 @@
+#![cfg_attr(docsrs, doc(cfg(feature = "http")))]
+
+use crate::ColdString;
+
+use alloc::str::Utf8Error;
+use core::convert::TryFrom;
+use http::header::{HeaderName, HeaderValue, InvalidHeaderName, InvalidHeaderValue};
+
+impl TryFrom<&ColdString> for HeaderValue {
+    type Error = InvalidHeaderValue;
+
+    #[inline]
+    fn try_from(s: &ColdString) -> Result<Self, Self::Error> {
+        HeaderValue::from_bytes(s.as_bytes())
+    }
+}
+
+impl TryFrom<ColdString> for HeaderValue {
+    type Error = InvalidHeaderValue;
+
+    #[inline]
+    fn try_from(s: ColdString) -> Result<Self, Self::Error> {
+        HeaderValue::try_from(&s)
+    }
+}
+
+impl TryFrom<&HeaderValue> for ColdString {
+    type Error = Utf8Error;
+
+    #[inline]
+    fn try_from(v: &HeaderValue) -> Result<Self, Self::Error> {
+        ColdString::from_utf8(v.as_bytes())
+    }
+}
+
+impl TryFrom<&ColdString> for HeaderName {
+    type Error = InvalidHeaderName;
+
+    #[inline]
+    fn try_from(s: &ColdString) -> Result<Self, Self::Error> {
+        HeaderName::from_bytes(s.as_bytes())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn header_value_roundtrip() {
+        for s in ["", "gzip", "12345678", "text/html; charset=utf-8", "café"] {
+            let cold = ColdString::new(s);
+            let value = HeaderValue::try_from(&cold).unwrap();
+            assert_eq!(value.as_bytes(), s.as_bytes());
+            assert_eq!(HeaderValue::try_from(cold.clone()).unwrap(), value);
+            assert_eq!(ColdString::try_from(&value).unwrap(), cold);
+        }
+    }
+
+    #[test]
+    fn header_value_invalid() {
+        for s in ["\n", "bad\r\nvalue", "a longer value with a \0 in it"] {
+            let cold = ColdString::new(s);
+            assert!(HeaderValue::try_from(&cold).is_err());
+            assert!(HeaderValue::try_from(cold).is_err());
+        }
+    }
+
+    #[test]
+    fn header_value_non_utf8() {
+        let value = HeaderValue::from_bytes(&[b'a', 0xff, b'b']).unwrap();
+        assert!(ColdString::try_from(&value).is_err());
+    }
+
+    #[test]
+    fn header_name() {
+        let cold = ColdString::new("x-request-id");
+        assert_eq!(HeaderName::try_from(&cold).unwrap(), "x-request-id");
+        let cold = ColdString::new("Content-Type");
+        assert_eq!(HeaderName::try_from(&cold).unwrap(), "content-type");
+        for s in ["", "bad name", "naïve"] {
+            assert!(HeaderName::try_from(&ColdString::new(s)).is_err());
+        }
+    }
+}
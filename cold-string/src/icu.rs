@@ -0,0 +1,116 @@
+#![cfg_attr(docsrs, doc(cfg(feature = "icu")))]
+//! Locale-aware comparison of [`ColdString`]s with an [`icu_collator::Collator`].
+
+use crate::ColdString;
+
+use core::cmp::Ordering;
+use icu_collator::Collator;
+
+impl ColdString {
+    /// Compares this string with `other` in the order of `collator`, for locale-aware sorting.
+    ///
+    /// The string is compared in place, inline or on the heap, so this doesn't allocate.
+    ///
+    /// There is no `sort_key` to store next to the string, since icu_collator 1.5 has no API
+    /// for binary sort keys. Sort with `collate` as the comparator instead.
+    ///
+    /// # Examples
+    /// ```
+    /// use cold_string::ColdString;
+    /// use core::cmp::Ordering;
+    /// use icu_collator::{Collator, CollatorOptions};
+    /// use icu_locid::locale;
+    ///
+    /// let swedish = Collator::try_new(&locale!("sv").into(), CollatorOptions::new()).unwrap();
+    /// let german = Collator::try_new(&locale!("de").into(), CollatorOptions::new()).unwrap();
+    /// let s = ColdString::new("ärlig");
+    /// // Swedish sorts "ä" after "z", and German sorts it with "a".
+    /// assert_eq!(s.collate("zon", &swedish), Ordering::Greater);
+    /// assert_eq!(s.collate("zon", &german), Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn collate(&self, other: &str, collator: &Collator) -> Ordering {
+        collator.compare(self.as_str(), other)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::WIDTH;
+
+    use alloc::vec::Vec;
+    use icu_collator::{CollatorOptions, Strength};
+    use icu_locid::Locale;
+
+    const CORPUS: [&str; 20] = [
+        "apple",
+        "Äpfel",
+        "Apfelbaum",
+        "zebra",
+        "Zürich",
+        "Österreich",
+        "Ozean",
+        "Übel",
+        "Ufer",
+        "été",
+        "Eté",
+        "ete",
+        "Straße",
+        "Strasse",
+        "ångström",
+        "Ängel",
+        "東京",
+        "Ωmega",
+        "Привет",
+        "",
+    ];
+
+    #[test]
+    fn collate_matches_str() {
+        assert!(CORPUS.iter().any(|s| s.len() <= WIDTH));
+        assert!(CORPUS.iter().any(|s| s.len() > WIDTH));
+        for locale in ["en", "de", "de-u-co-phonebk", "sv", "ja"] {
+            let locale: Locale = locale.parse().unwrap();
+            for strength in [None, Some(Strength::Primary)] {
+                let mut options = CollatorOptions::new();
+                options.strength = strength;
+                let collator = Collator::try_new(&(&locale).into(), options).unwrap();
+                for a in CORPUS {
+                    let cold = ColdString::new(a);
+                    for b in CORPUS {
+                        assert_eq!(
+                            cold.collate(b, &collator),
+                            collator.compare(a, b),
+                            "{} {} {}",
+                            locale,
+                            a,
+                            b
+                        );
+                    }
+                }
+
+                let mut expected = CORPUS;
+                expected.sort_by(|a, b| collator.compare(a, b));
+                let mut sorted: Vec<ColdString> = CORPUS.iter().map(ColdString::new).collect();
+                sorted.sort_by(|a, b| a.collate(b, &collator));
+                assert_eq!(sorted, expected);
+            }
+        }
+    }
+
+    #[test]
+    fn phonebook_order() {
+        let collator = |locale: &str| {
+            let locale: Locale = locale.parse().unwrap();
+            Collator::try_new(&(&locale).into(), CollatorOptions::new()).unwrap()
+        };
+        // The phonebook order sorts "ü" as "ue", and the default order sorts it with "u".
+        let s = ColdString::new("Mütze");
+        assert_eq!(s.collate("Muffe", &collator("de")), Ordering::Greater);
+        assert_eq!(
+            s.collate("Muffe", &collator("de-u-co-phonebk")),
+            Ordering::Less
+        );
+    }
+}
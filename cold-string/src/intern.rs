@@ -0,0 +1,184 @@
+#![cfg_attr(docsrs, doc(cfg(feature = "intern")))]
+//! A global string interner, which returns [`ColdString`]s that share one copy of each distinct
+//! string.
+//!
+//! Interned strings are leaked and borrowed through a [`StaticStr`], like
+//! [`ColdString::from_static`], so the returned strings are cloned and dropped for free. Strings
+//! that are short enough to be inline are returned without being interned. The set of interned
+//! strings is split into shards, each behind a spin lock, so interning works without `std`.
+
+use crate::{ColdString, StaticStr, WIDTH};
+
+use alloc::boxed::Box;
+use core::hash::{BuildHasher, Hash, Hasher};
+use hashbrown_intern::{hash_table::Entry, DefaultHashBuilder, HashTable};
+use spin::{Lazy, Mutex};
+
+/// The number of shards, a power of 2.
+const SHARDS: usize = 16;
+
+struct Interner {
+    hasher: DefaultHashBuilder,
+    shards: [Mutex<HashTable<&'static StaticStr>>; SHARDS],
+}
+
+static INTERNER: Lazy<Interner> = Lazy::new(|| {
+    #[allow(clippy::declare_interior_mutable_const)]
+    const EMPTY: Mutex<HashTable<&'static StaticStr>> = Mutex::new(HashTable::new());
+    Interner {
+        hasher: DefaultHashBuilder::default(),
+        shards: [EMPTY; SHARDS],
+    }
+});
+
+impl Interner {
+    fn hash(&self, s: &str) -> u64 {
+        let mut hasher = self.hasher.build_hasher();
+        s.hash(&mut hasher);
+        hasher.finish()
+    }
+
+    /// Returns the interned copy of `s`, calling `leak` for the string to intern if there is
+    /// none.
+    fn get_or_insert(&self, s: &str, leak: impl FnOnce() -> &'static str) -> ColdString {
+        let hash = self.hash(s);
+        // The top 7 bits pick the control byte in a shard's table, so use the bits below them.
+        let shard = (hash << 7 >> (64 - SHARDS.trailing_zeros())) as usize;
+        let mut table = self.shards[shard].lock();
+        let interned = match table.entry(
+            hash,
+            |interned| interned.as_str() == s,
+            |interned| self.hash(interned.as_str()),
+        ) {
+            Entry::Occupied(entry) => *entry.get(),
+            Entry::Vacant(entry) => {
+                let interned: &'static StaticStr = Box::leak(Box::new(StaticStr::new(leak())));
+                entry.insert(interned);
+                interned
+            }
+        };
+        ColdString::from_static(interned)
+    }
+}
+
+/// Returns a [`ColdString`] equal to `s`, which shares its bytes with every other string
+/// interned from an equal `s`.
+///
+/// The first time a string is interned, it is copied and never freed. Strings of at most
+/// `core::mem::size_of::<usize>()` bytes are inline, so they are returned without being
+/// interned.
+///
+/// # Examples
+/// ```
+/// let a = cold_string::intern("a label that many strings have");
+/// let b = cold_string::intern(&String::from("a label that many strings have"));
+/// assert_eq!(a, "a label that many strings have");
+/// assert_eq!(a.as_ptr(), b.as_ptr());
+/// ```
+pub fn intern(s: &str) -> ColdString {
+    if s.len() <= WIDTH {
+        return ColdString::new(s);
+    }
+    INTERNER.get_or_insert(s, || ColdString::new(s).leak())
+}
+
+/// Like [`intern`], but a `'static` string is borrowed instead of copied when it is interned
+/// for the first time.
+///
+/// # Examples
+/// ```
+/// let a = cold_string::intern_static("a label from a string literal");
+/// let b = cold_string::intern("a label from a string literal");
+/// assert_eq!(a.as_ptr(), b.as_ptr());
+/// ```
+pub fn intern_static(s: &'static str) -> ColdString {
+    if s.len() <= WIDTH {
+        return ColdString::new(s);
+    }
+    INTERNER.get_or_insert(s, || s)
+}
+
+/// Returns the number of distinct strings that have been interned.
+///
+/// Inline strings are not interned, so they are not counted.
+///
+/// # Examples
+/// ```
+/// let before = cold_string::interned_count();
+/// cold_string::intern("a string that is interned once");
+/// cold_string::intern("a string that is interned once");
+/// cold_string::intern("inline");
+/// assert_eq!(cold_string::interned_count(), before + 1);
+/// ```
+pub fn interned_count() -> usize {
+    INTERNER.shards.iter().map(|shard| shard.lock().len()).sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use alloc::{format, string::String, vec::Vec};
+    use std::thread;
+
+    #[test]
+    fn interned_strings_share_bytes() {
+        for s in [
+            "",
+            "cold",
+            "12345678",
+            "123456789",
+            "a string for the interner test",
+        ] {
+            let a = intern(s);
+            let b = intern(&String::from(s));
+            assert_eq!(a, s);
+            assert_eq!(b, s);
+            assert_eq!(a.is_inline(), s.len() <= WIDTH);
+            if !a.is_inline() {
+                assert_eq!(a.as_ptr(), b.as_ptr());
+                assert!(a.is_static());
+            }
+        }
+        let literal = "a literal for the interner test, never copied";
+        let a = intern_static(literal);
+        assert_eq!(a.as_ptr(), literal.as_ptr());
+        assert_eq!(intern(literal).as_ptr(), literal.as_ptr());
+        let copied = intern("a copied string for the interner test");
+        let b = intern_static("a copied string for the interner test");
+        assert_eq!(b.as_ptr(), copied.as_ptr());
+    }
+
+    #[test]
+    fn concurrent_interning() {
+        const THREADS: usize = 4;
+        const WORDS: usize = 64;
+        let before = interned_count();
+        let threads: Vec<_> = (0..THREADS)
+            .map(|t| {
+                thread::spawn(move || {
+                    // Each thread interns the same words in a different order.
+                    (0..WORDS)
+                        .map(|i| (i * (2 * t + 1)) % WORDS)
+                        .map(|i| (i, intern(&format!("concurrent interning test {}", i))))
+                        .collect::<Vec<_>>()
+                })
+            })
+            .collect();
+        let mut interned: Vec<Vec<(usize, ColdString)>> =
+            threads.into_iter().map(|t| t.join().unwrap()).collect();
+        for words in &mut interned {
+            words.sort_by_key(|&(i, _)| i);
+        }
+        for (i, s) in interned[0].iter() {
+            assert_eq!(s, format!("concurrent interning test {}", i).as_str());
+        }
+        for words in &interned[1..] {
+            for (a, b) in words.iter().zip(&interned[0]) {
+                assert_eq!(a.1.as_ptr(), b.1.as_ptr());
+            }
+        }
+        // Other tests may intern strings at the same time.
+        assert!(interned_count() >= before + WORDS);
+    }
+}
@@ -4,16 +4,21 @@
 #![no_std]
 
 extern crate alloc;
+#[cfg(any(test, feature = "std"))]
+extern crate std;
 
 #[rustversion::before(1.84)]
 use sptr::Strict;
 
 use alloc::{
-    alloc::{alloc, dealloc, Layout},
+    alloc::{alloc, alloc_zeroed, dealloc, Layout},
     borrow::{Cow, ToOwned},
     boxed::Box,
+    rc::Rc,
     str::Utf8Error,
     string::String,
+    sync::Arc,
+    vec::Vec,
 };
 use core::{
     cmp::Ordering,
@@ -21,20 +26,158 @@ use core::{
     hash::{Hash, Hasher},
     iter::FromIterator,
     mem,
-    ops::Deref,
+    ops::{Add, Bound, Deref, Index, Range, RangeBounds},
     ptr,
     ptr::NonNull,
-    slice, str,
+    slice,
+    slice::SliceIndex,
+    str,
+    str::{Bytes, CharIndices, Chars},
 };
 
+#[cfg(not(feature = "fixed-header"))]
 mod vint;
-use crate::vint::VarInt;
+
+mod header;
+use crate::header::Header;
+
+mod search;
+pub use crate::search::{binary_search, equal_range_by_prefix, partition_point_prefix};
+
+mod case;
+pub use crate::case::{AsciiLowercaseBytes, CaseInsensitive, Caseless, ColdCaseless};
+
+mod validate;
+pub use crate::validate::InvalidChar;
+#[cfg(feature = "unicode-ident")]
+pub use crate::validate::InvalidIdent;
+
+mod sanitize;
+pub use crate::sanitize::SanitizeOptions;
+
+pub mod option_ext;
+pub use crate::option_ext::or_empty;
+
+mod uninit;
+pub use crate::uninit::UninitColdString;
+use crate::uninit::{write_slice, write_zeros};
+
+mod buffer;
+pub use crate::buffer::{from_validated_buffer, SpanError};
+
+pub mod portable;
+
+mod utf8;
+pub use crate::utf8::FromUtf8Error;
+
+mod utf16;
+pub use crate::utf16::FromUtf16Error;
+
+mod ascii;
+pub use crate::ascii::AsciiError;
+
+mod fallible;
+pub use crate::fallible::TryNewError;
+
+mod copy;
+pub use crate::copy::CopyError;
+
+mod static_str;
+pub use crate::static_str::StaticStr;
+
+mod builder;
+pub use crate::builder::ColdStringBuilder;
+
+mod format;
+pub use crate::format::ToColdString;
+
+mod replace;
+pub use crate::replace::ReplacePattern;
+
+mod split;
+
+mod escape;
+
+mod natural;
+pub use crate::natural::sort_natural;
+
+mod prefix_key;
+pub use crate::prefix_key::sort_by_prefix;
+
+mod frozen;
+pub use crate::frozen::{FrozenColdStringList, FrozenIter};
+
+#[cfg(target_has_atomic = "ptr")]
+mod arc;
+#[cfg(target_has_atomic = "ptr")]
+pub use crate::arc::ColdArcString;
 
 #[cfg(feature = "rkyv")]
 mod rkyv;
 
+#[cfg(feature = "log-kv")]
+mod log;
+
+#[cfg(feature = "slog")]
+mod slog;
+
+#[cfg(feature = "metrics")]
+mod metrics;
+
+#[cfg(feature = "http")]
+mod http;
+
+#[cfg(feature = "bytes")]
+mod bytes;
+
+#[cfg(feature = "bumpalo")]
+mod bump;
+#[cfg(feature = "bumpalo")]
+pub use crate::bump::ColdBumpString;
+
+#[cfg(feature = "allocator-api2")]
+mod allocator;
+#[cfg(feature = "allocator-api2")]
+pub use crate::allocator::ColdStringIn;
+
+#[cfg(feature = "equivalent")]
+mod equivalent;
+
+#[cfg(feature = "capi")]
+pub mod capi;
+
+#[cfg(feature = "nul-terminated")]
+mod c_str;
+
+#[cfg(feature = "heck")]
+mod heck;
+
+#[cfg(feature = "icu")]
+mod icu;
+
+#[cfg(feature = "intern")]
+mod intern;
+#[cfg(feature = "intern")]
+pub use crate::intern::{intern, intern_static, interned_count};
+
+#[cfg(feature = "std")]
+mod path;
+#[cfg(feature = "std")]
+pub use crate::path::NonUtf8Error;
+
+#[cfg(feature = "std")]
+mod reader;
+
+#[cfg(feature = "std")]
+pub mod codegen;
+
+#[cfg(feature = "serde")]
+pub mod serde_helpers;
+
 const HEAP_ALIGN: usize = 4;
 const WIDTH: usize = mem::size_of::<usize>();
+/// The number of NUL bytes after the string bytes in a heap block.
+const NUL_PAD: usize = cfg!(feature = "nul-terminated") as usize;
 
 /// Compact representation of immutable UTF-8 strings. Optimized for memory usage and struct packing.
 ///
@@ -55,8 +198,14 @@ pub struct ColdString {
     /// The first byte of `encoded` is the "tag" and it determines the type:
     /// - 10xxxxxx: an encoded address for the heap. To decode, 10 is set to 00 and swapped
     ///   with the LSB bits of the tag byte. The address is always a multiple of 4 (`HEAP_ALIGN`).
+    ///   It points either to a heap block, which starts with the non-zero length header, or to
+    ///   a `StaticStr`, which starts with a 0 byte.
+    ///   The tag takes the 2 bits that the alignment leaves free, so no bits are left to cache
+    ///   the length of a heap string, and the header can't be dropped: a headerless block could
+    ///   start with a 0 byte and be read as a `StaticStr`.
     /// - 11111xxx: xxx is the length in range 0..=7, followed by length UTF-8 bytes.
     /// - xxxxxxxx (valid UTF-8): 8 UTF-8 bytes.
+    ///
     /// The exception is if `encoded` is `usize::MAX`, the UTF-8 bytes are "\0\0\0\0\0\0\0\0".
     encoded: NonNull<u8>,
 }
@@ -69,12 +218,41 @@ impl ColdString {
     const PTR_TAG: usize = usize::from_ne_bytes(0b10000000usize.to_le_bytes());
     const LEN_MASK: usize = usize::from_ne_bytes(0b111usize.to_le_bytes());
     const EIGHT_NUL_MAP: usize = usize::MAX;
+    const EMPTY_MAP: usize = usize::from_ne_bytes(0b11111000usize.to_le_bytes());
     const ROT: u32 = if cfg!(target_endian = "little") {
         0
     } else {
         8 * (WIDTH - 1) as u32
     };
 
+    /// The empty string.
+    ///
+    /// # Examples
+    /// ```
+    /// use cold_string::ColdString;
+    ///
+    /// assert_eq!(ColdString::EMPTY, "");
+    /// assert_eq!(ColdString::EMPTY, ColdString::default());
+    /// ```
+    // SAFETY: the inline tag is non-zero, and the length bits are zero
+    pub const EMPTY: ColdString = ColdString {
+        encoded: unsafe { NonNull::new_unchecked(Self::EMPTY_MAP as *mut u8) },
+    };
+
+    /// The maximum length in bytes of an inline string, which is the size of a pointer.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::mem::{align_of, size_of};
+    /// use cold_string::ColdString;
+    ///
+    /// assert_eq!(ColdString::INLINE_CAPACITY, size_of::<usize>());
+    /// assert_eq!(size_of::<ColdString>(), ColdString::INLINE_CAPACITY);
+    /// assert_eq!(align_of::<ColdString>(), align_of::<usize>());
+    /// assert!(ColdString::new("a".repeat(ColdString::INLINE_CAPACITY)).is_inline());
+    /// ```
+    pub const INLINE_CAPACITY: usize = WIDTH;
+
     /// Convert a slice of bytes into a [`ColdString`].
     ///
     /// A [`ColdString`] is a contiguous collection of bytes (`u8`s) that is valid [`UTF-8`](https://en.wikipedia.org/wiki/UTF-8).
@@ -108,6 +286,10 @@ impl ColdString {
     ///
     /// See the safe version, [`ColdString::from_utf8`], for more details.
     ///
+    /// # Safety
+    ///
+    /// The bytes passed in must be valid UTF-8.
+    ///
     /// # Examples
     ///
     /// Basic usage:
@@ -130,6 +312,7 @@ impl ColdString {
     /// Creates a new [`ColdString`] from any type that implements `AsRef<str>`.
     /// If the string is shorter than `core::mem::size_of::<usize>()`, then it
     /// will be inlined on the stack.
+    #[inline]
     pub fn new<T: AsRef<str>>(x: T) -> Self {
         let s = x.as_ref();
         if s.len() <= WIDTH {
@@ -147,17 +330,16 @@ impl ColdString {
     }
 
     #[inline]
-    fn is_eight_nul(&self) -> bool {
+    const fn is_eight_nul(&self) -> bool {
         self.addr() == Self::EIGHT_NUL_MAP
     }
 
     #[inline]
-    const fn inline_buf(s: &str) -> [u8; WIDTH] {
-        debug_assert!(s.len() <= WIDTH);
+    const fn inline_buf(len: usize) -> [u8; WIDTH] {
+        debug_assert!(len <= WIDTH);
         let mut buf = [0u8; WIDTH];
-        if s.len() < WIDTH {
-            let tag =
-                (Self::INLINE_TAG | s.len().rotate_left(Self::ROT)).rotate_right(Self::ROT) as u8;
+        if len < WIDTH {
+            let tag = (Self::INLINE_TAG | len.rotate_left(Self::ROT)).rotate_right(Self::ROT) as u8;
             buf[0] = tag;
         }
         buf
@@ -182,7 +364,10 @@ impl ColdString {
         if s.as_bytes() == EIGHT_NUL {
             return Self::new_eight_nul();
         }
-        let mut buf = Self::inline_buf(s);
+        // Starting from a zeroed word keeps the padding zero, which equality,
+        // `eq_ignore_ascii_case` and `prefix_key` rely on. Zeroing one word is
+        // cheaper than a `MaybeUninit` buffer with a separate tail memset.
+        let mut buf = Self::inline_buf(s.len());
         let start = Self::utf8_start(s.len());
         buf[start..s.len() + start].copy_from_slice(s.as_bytes());
         // SAFETY:
@@ -222,7 +407,7 @@ impl ColdString {
                 return Self::new_eight_nul();
             }
         }
-        let mut buf = Self::inline_buf(s);
+        let mut buf = Self::inline_buf(s.len());
         let start = Self::utf8_start(s.len());
         let mut i = 0;
         while i < s.len() {
@@ -235,6 +420,93 @@ impl ColdString {
         unsafe { Self::from_inline_buf(buf) }
     }
 
+    /// Creates an inline [`ColdString`] if `s` fits in `core::mem::size_of::<usize>()` bytes,
+    /// and returns `None` otherwise.
+    ///
+    /// This is guaranteed never to touch the allocator, whether it returns `Some` or `None`.
+    ///
+    /// # Examples
+    /// ```
+    /// use cold_string::ColdString;
+    ///
+    /// assert_eq!(ColdString::try_inline("cold").unwrap(), "cold");
+    /// assert_eq!(ColdString::try_inline("a string that is too long to inline"), None);
+    /// ```
+    #[inline]
+    pub fn try_inline(s: &str) -> Option<Self> {
+        if s.len() <= WIDTH {
+            Some(Self::new_inline(s))
+        } else {
+            None
+        }
+    }
+
+    /// Like [`ColdString::try_inline`], but usable in a const context. Unlike
+    /// [`ColdString::new_inline_const`], this doesn't panic on long strings.
+    ///
+    /// # Examples
+    /// ```
+    /// use cold_string::ColdString;
+    ///
+    /// const SHORT: Option<ColdString> = ColdString::try_inline_const("cold");
+    /// const LONG: Option<ColdString> = ColdString::try_inline_const("too long to inline");
+    /// assert_eq!(SHORT.unwrap(), "cold");
+    /// assert!(LONG.is_none());
+    /// ```
+    #[rustversion::since(1.61)]
+    #[inline]
+    pub const fn try_inline_const(s: &str) -> Option<Self> {
+        if s.len() <= WIDTH {
+            Some(Self::new_inline_const(s))
+        } else {
+            None
+        }
+    }
+
+    /// Creates a new inline [`ColdString`] holding the single char `c`, without allocating.
+    ///
+    /// This is a `const fn` from Rust 1.61, like [`ColdString::new_inline_const`].
+    ///
+    /// # Examples
+    /// ```
+    /// use cold_string::ColdString;
+    ///
+    /// let s = ColdString::from_char('🦀');
+    /// assert_eq!(s, "🦀");
+    /// assert!(s.is_inline());
+    /// ```
+    #[rustversion::attr(since(1.61), const)]
+    #[inline]
+    pub fn from_char(c: char) -> Self {
+        let len = c.len_utf8();
+        let mut buf = Self::inline_buf(len);
+        let start = Self::utf8_start(len);
+        // `char::encode_utf8` isn't const before 1.83.
+        let code = c as u32;
+        match len {
+            1 => buf[start] = code as u8,
+            2 => {
+                buf[start] = 0xC0 | (code >> 6) as u8;
+                buf[start + 1] = 0x80 | (code & 0x3F) as u8;
+            }
+            3 => {
+                buf[start] = 0xE0 | (code >> 12) as u8;
+                buf[start + 1] = 0x80 | ((code >> 6) & 0x3F) as u8;
+                buf[start + 2] = 0x80 | (code & 0x3F) as u8;
+            }
+            _ => {
+                buf[start] = 0xF0 | (code >> 18) as u8;
+                buf[start + 1] = 0x80 | ((code >> 12) & 0x3F) as u8;
+                buf[start + 2] = 0x80 | ((code >> 6) & 0x3F) as u8;
+                buf[start + 3] = 0x80 | (code & 0x3F) as u8;
+            }
+        }
+        // SAFETY:
+        // Shorter strings have the non-zero inline tag. A 4 byte char can fill the buffer on
+        // 32-bit targets, but its leading byte is never 0.
+        unsafe { Self::from_inline_buf(buf) }
+    }
+
     #[rustversion::attr(since(1.71), const)]
     #[inline]
     fn ptr(&self) -> *const u8 {
@@ -242,63 +514,225 @@ impl ColdString {
     }
 
     #[inline]
-    fn addr(&self) -> usize {
-        self.ptr().addr()
+    const fn addr(&self) -> usize {
+        // SAFETY: at runtime this discards the provenance, like `addr`, which is stable from
+        // 1.84 and not const. In a const context, transmuting a pointer with provenance is a
+        // const-eval error. Only inline strings, whose pointer is created from an integer, can
+        // be built there: `new` allocates and `from_static` isn't a `const fn`.
+        unsafe { mem::transmute::<NonNull<u8>, usize>(self.encoded) }
     }
 
     #[inline]
-    fn tag(&self) -> usize {
+    const fn tag(&self) -> usize {
         self.addr() & Self::TAG_MASK
     }
 
     /// Returns `true` if the string bytes are inlined.
+    ///
+    /// Every string of at most [`ColdString::INLINE_CAPACITY`] bytes is inline, however it was
+    /// built, and every longer string isn't.
+    ///
+    /// # Examples
+    /// ```
+    /// use cold_string::ColdString;
+    ///
+    /// assert!(ColdString::new("cold").is_inline());
+    /// assert!(!ColdString::new("a string on the heap").is_inline());
+    /// ```
+    ///
+    /// This is a `const fn`.
     #[inline]
-    pub fn is_inline(&self) -> bool {
+    pub const fn is_inline(&self) -> bool {
         self.tag() != Self::PTR_TAG
     }
 
+    /// Returns `true` if the string bytes are in a heap block owned by this string.
+    ///
+    /// This is `false` for inline strings and for strings borrowing a [`StaticStr`].
+    ///
+    /// # Examples
+    /// ```
+    /// use cold_string::{ColdString, StaticStr};
+    ///
+    /// static LONG: StaticStr = StaticStr::new("a string that is never copied");
+    ///
+    /// assert!(!ColdString::new("cold").is_heap_allocated());
+    /// assert!(ColdString::new("a string on the heap").is_heap_allocated());
+    /// assert!(!ColdString::from_static(&LONG).is_heap_allocated());
+    /// ```
+    #[rustversion::attr(since(1.61), const)]
+    #[inline]
+    pub fn is_heap_allocated(&self) -> bool {
+        !self.is_inline() && !self.is_static()
+    }
+
+    /// Returns `true` if a string of `len` bytes is stored inline.
+    ///
+    /// # Examples
+    /// ```
+    /// use cold_string::ColdString;
+    ///
+    /// assert!(ColdString::will_inline(ColdString::INLINE_CAPACITY));
+    /// assert!(!ColdString::will_inline(ColdString::INLINE_CAPACITY + 1));
+    /// assert_eq!(ColdString::will_inline(4), ColdString::new("cold").is_inline());
+    /// ```
+    ///
+    /// This is a `const fn`.
     #[inline]
+    pub const fn will_inline(len: usize) -> bool {
+        len <= Self::INLINE_CAPACITY
+    }
+
+    /// Kept out of line, so the inline fast path of `new` stays small at every call site.
+    #[cold]
+    #[inline(never)]
     fn new_heap(s: &str) -> Self {
         let len = s.len();
-        let (vint_len, len_buf) = VarInt::write(len as u64);
-        let total = vint_len + len;
-        let layout = Layout::from_size_align(total, HEAP_ALIGN).unwrap();
+        unsafe {
+            let (ptr, vint_len) = Self::alloc_heap(len, false);
+            ptr::copy_nonoverlapping(s.as_ptr(), ptr.add(vint_len), len);
+            Self::from_heap_ptr(ptr)
+        }
+    }
+
+    /// Allocates the heap block for a string of `len` bytes and writes its length header.
+    ///
+    /// Returns the block and the length of the header, which the string bytes follow. The
+    /// string bytes are zeroed if `zeroed`, and uninitialized otherwise.
+    ///
+    /// With the `nul-terminated` feature, the block ends with a NUL byte after the string
+    /// bytes.
+    #[inline]
+    fn alloc_heap(len: usize, zeroed: bool) -> (*mut u8, usize) {
+        match Self::try_alloc_heap(len, zeroed) {
+            Ok(block) => block,
+            Err(err) => Self::alloc_failed(err),
+        }
+    }
+
+    #[cold]
+    #[inline(never)]
+    fn alloc_failed(err: TryNewError) -> ! {
+        match err.layout() {
+            Some(layout) => alloc::alloc::handle_alloc_error(layout),
+            None => panic!("capacity overflow"),
+        }
+    }
+
+    /// Like [`ColdString::alloc_heap`], but returns an error instead of aborting if the
+    /// allocation fails.
+    #[inline]
+    fn try_alloc_heap(len: usize, zeroed: bool) -> Result<(*mut u8, usize), TryNewError> {
+        let (layout, header) =
+            Self::heap_layout(len).ok_or_else(|| TryNewError::capacity_overflow(len))?;
 
         unsafe {
-            // SAFETY: the layout size is non-zero, since the smallest VarInt is one byte
-            let ptr = alloc(layout);
+            // SAFETY: the layout size is non-zero, since the smallest header is one byte
+            let ptr = if zeroed {
+                alloc_zeroed(layout)
+            } else {
+                alloc(layout)
+            };
             if ptr.is_null() {
-                alloc::alloc::handle_alloc_error(layout);
+                return Err(TryNewError::alloc_failed(len, layout));
             }
 
-            // TODO: can optimize this
-            ptr::copy_nonoverlapping(len_buf.as_ptr(), ptr, vint_len);
-            ptr::copy_nonoverlapping(s.as_ptr(), ptr.add(vint_len), len);
-            let encoded = ptr.map_addr(|addr| {
-                debug_assert!(addr % HEAP_ALIGN == 0);
-                let mut addr = addr.rotate_left(6 + Self::ROT);
-                addr |= Self::PTR_TAG;
-                addr
-            });
-            // SAFETY: encoded != 0 because Self::PTR_TAG != 0
-            let encoded = NonNull::new_unchecked(encoded);
-            Self { encoded }
+            // SAFETY: the block starts with `header` bytes for the header
+            Header::write_to(ptr, len);
+            if !zeroed {
+                ptr::write_bytes(ptr.add(header + len), 0, NUL_PAD);
+            }
+            Ok((ptr, header))
         }
     }
 
+    /// Returns the layout of the heap block for a string of `len` bytes, and the length of its
+    /// header, or `None` if the block is too large. Both allocation and deallocation use this,
+    /// so they always agree.
     #[inline]
-    fn heap_ptr(&self) -> *const u8 {
-        debug_assert!(!self.is_inline());
-        self.ptr().map_addr(|mut addr| {
-            addr ^= Self::PTR_TAG;
-            let addr = addr.rotate_right(6 + Self::ROT);
+    fn heap_layout(len: usize) -> Option<(Layout, usize)> {
+        let header = Header::encoded_len(len)?;
+        let layout = header
+            .checked_add(len)
+            .and_then(|total| total.checked_add(NUL_PAD))
+            .and_then(|total| Layout::from_size_align(total, HEAP_ALIGN).ok())?;
+        Some((layout, header))
+    }
+
+    /// Returns the layout of the heap block that holds `len` bytes, which was allocated.
+    ///
+    /// SAFETY: a block for `len` bytes must have been allocated.
+    #[inline]
+    unsafe fn allocated_layout(len: usize) -> Layout {
+        match Self::heap_layout(len) {
+            Some((layout, _)) => layout,
+            None => core::hint::unreachable_unchecked(),
+        }
+    }
+
+    /// Copies this string's heap block, header included, into a block with the same layout.
+    ///
+    /// SAFETY: the string must own a heap block.
+    #[allow(unsafe_op_in_unsafe_fn)]
+    unsafe fn clone_heap_block(&self) -> Self {
+        let block = self.heap_ptr();
+        let (len, _) = Header::read(block);
+        let layout = Self::allocated_layout(len);
+        let ptr = alloc(layout);
+        if ptr.is_null() {
+            alloc::alloc::handle_alloc_error(layout);
+        }
+        ptr::copy_nonoverlapping(block, ptr, layout.size());
+        Self::from_heap_ptr(ptr)
+    }
+
+    /// SAFETY: `ptr` must come from `alloc_heap`, with the string bytes initialized to UTF-8.
+    #[inline]
+    unsafe fn from_heap_ptr(ptr: *mut u8) -> Self {
+        // `map_addr` comes from `sptr::Strict` before 1.84.
+        #[allow(clippy::incompatible_msrv)]
+        let encoded = ptr.map_addr(|addr| {
             debug_assert!(addr % HEAP_ALIGN == 0);
+            let mut addr = addr.rotate_left(6 + Self::ROT);
+            addr |= Self::PTR_TAG;
             addr
-        })
+        });
+        // SAFETY: encoded != 0 because Self::PTR_TAG != 0
+        let encoded = NonNull::new_unchecked(encoded);
+        Self { encoded }
+    }
+
+    #[rustversion::attr(since(1.61), const)]
+    #[inline]
+    fn heap_ptr(&self) -> *const u8 {
+        debug_assert!(!self.is_inline());
+        let addr = self.addr();
+        let heap_addr = (addr ^ Self::PTR_TAG).rotate_right(6 + Self::ROT);
+        debug_assert!(heap_addr % HEAP_ALIGN == 0);
+        // Same as `map_addr`, which isn't const: offsetting keeps the provenance.
+        self.encoded
+            .as_ptr()
+            .wrapping_add(heap_addr.wrapping_sub(addr))
+    }
+
+    /// Returns the bytes of an inline string as a big-endian, zero padded word, and the length.
+    ///
+    /// The words of two strings compare like their first `WIDTH` bytes, and the lengths break
+    /// ties between a string and the same string followed by NUL bytes.
+    #[inline]
+    fn inline_key(&self) -> (usize, usize) {
+        debug_assert!(self.is_inline());
+        if self.is_eight_nul() {
+            return (0, WIDTH);
+        }
+        let len = self.inline_len();
+        // The tag byte is first in memory, and shifted out if there is one.
+        let word = usize::from_be_bytes(self.addr().to_ne_bytes());
+        (word << (8 * Self::utf8_start(len)), len)
     }
 
     #[inline]
-    fn inline_len(&self) -> usize {
+    const fn inline_len(&self) -> usize {
         debug_assert!(!self.is_eight_nul());
         let addr = self.addr();
         match addr & Self::INLINE_TAG {
@@ -323,22 +757,29 @@ impl ColdString {
     /// assert_eq!(fancy_f.len(), 4);
     /// assert_eq!(fancy_f.chars().count(), 3);
     /// ```
+    ///
+    /// This is a `const fn` from Rust 1.61.
+    #[rustversion::attr(since(1.61), const)]
     #[inline]
     pub fn len(&self) -> usize {
         if self.is_eight_nul() {
-            return WIDTH;
+            WIDTH
         } else if self.is_inline() {
             self.inline_len()
         } else {
             unsafe {
                 let ptr = self.heap_ptr();
-                let (len, _) = VarInt::read(ptr);
-                len as usize
+                if Self::is_static_ptr(ptr) {
+                    return Self::static_str(ptr).len();
+                }
+                let (len, _) = Header::read(ptr);
+                len
             }
         }
     }
 
     #[allow(unsafe_op_in_unsafe_fn)]
+    #[rustversion::attr(since(1.83), const)]
     #[inline]
     unsafe fn decode_inline(&self) -> &[u8] {
         if self.is_eight_nul() {
@@ -352,10 +793,14 @@ impl ColdString {
     }
 
     #[allow(unsafe_op_in_unsafe_fn)]
+    #[rustversion::attr(since(1.83), const)]
     #[inline]
     unsafe fn decode_heap(&self) -> &[u8] {
         let ptr = self.heap_ptr();
-        let (len, header) = VarInt::read(ptr);
+        if Self::is_static_ptr(ptr) {
+            return Self::static_str(ptr).as_bytes();
+        }
+        let (len, header) = Header::read(ptr);
         let data = ptr.add(header);
         slice::from_raw_parts(data, len)
     }
@@ -373,6 +818,9 @@ impl ColdString {
     ///
     /// assert_eq!(&[104, 101, 108, 108, 111], s.as_bytes());
     /// ```
+    ///
+    /// This is a `const fn` from Rust 1.83.
+    #[rustversion::attr(since(1.83), const)]
     #[inline]
     pub fn as_bytes(&self) -> &[u8] {
         match self.is_inline() {
@@ -389,6 +837,9 @@ impl ColdString {
     ///
     /// assert_eq!(s.as_str(), "hello");
     /// ```
+    ///
+    /// This is a `const fn` from Rust 1.83.
+    #[rustversion::attr(since(1.83), const)]
     #[inline]
     pub fn as_str(&self) -> &str {
         unsafe { str::from_utf8_unchecked(self.as_bytes()) }
@@ -402,63 +853,577 @@ impl ColdString {
     /// let v = cold_string::ColdString::new("");
     /// assert!(v.is_empty());
     /// ```
+    ///
+    /// This is a `const fn` from Rust 1.61.
+    #[rustversion::attr(since(1.61), const)]
     #[inline]
     pub fn is_empty(&self) -> bool {
         self.len() == 0
     }
-}
-
-impl Default for ColdString {
-    fn default() -> Self {
-        Self::new_inline("")
-    }
-}
 
-impl Deref for ColdString {
-    type Target = str;
-    fn deref(&self) -> &str {
-        self.as_str()
+    /// Converts this [`ColdString`] into a [`String`].
+    ///
+    /// The heap block of a [`ColdString`] starts with its length header, so it can't be reused
+    /// by the [`String`]. Instead the bytes are copied once into an exactly sized allocation,
+    /// and this string's heap block, if any, is freed.
+    ///
+    /// # Examples
+    /// ```
+    /// use cold_string::ColdString;
+    ///
+    /// let s = ColdString::new("a string that lives on the heap").into_string();
+    /// assert_eq!(s, "a string that lives on the heap");
+    /// assert_eq!(s.capacity(), s.len());
+    /// ```
+    #[inline]
+    pub fn into_string(self) -> String {
+        self.as_str().to_owned()
     }
-}
 
-impl Drop for ColdString {
-    fn drop(&mut self) {
-        if !self.is_inline() {
-            let ptr = self.heap_ptr();
-            unsafe {
-                let (len, header) = VarInt::read(ptr);
-                let total = header + len;
-                let layout = Layout::from_size_align(total, HEAP_ALIGN).unwrap();
-                // SAFETY: if ptr is non-null then it was allocated by alloc() in new_heap()
-                dealloc(ptr as *mut u8, layout);
-            }
-        }
+    /// Edits this string through a [`String`].
+    ///
+    /// The contents are copied into a [`String`] with exact capacity, `f` edits it, and the
+    /// result is packed back into this string, which becomes inline or heap-allocated based on
+    /// its new length. If `f` panics, this string keeps its old value.
+    ///
+    /// # Examples
+    /// ```
+    /// use cold_string::ColdString;
+    ///
+    /// let mut s = ColdString::new("cold");
+    /// s.modify(|s| s.push_str(" string on the heap"));
+    /// assert_eq!(s, "cold string on the heap");
+    /// s.modify(|s| s.truncate(4));
+    /// assert!(s.is_inline());
+    /// ```
+    pub fn modify<F: FnOnce(&mut String)>(&mut self, f: F) {
+        let mut s = self.as_str().to_owned();
+        f(&mut s);
+        *self = Self::new(s);
     }
-}
 
-impl Clone for ColdString {
-    fn clone(&self) -> Self {
-        if self.is_inline() {
-            let ptr = self.ptr();
-            let encoded = unsafe { NonNull::new_unchecked(ptr as *mut _) };
-            Self { encoded }
-        } else {
-            Self::new_heap(self.as_str())
-        }
+    /// Consumes this string and builds a new one from `f` applied to its contents.
+    ///
+    /// # Examples
+    /// ```
+    /// use cold_string::ColdString;
+    ///
+    /// let s = ColdString::new("cold").map_str(|s| s.repeat(3));
+    /// assert_eq!(s, "coldcoldcold");
+    /// ```
+    #[inline]
+    pub fn map_str<F: FnOnce(&str) -> String>(self, f: F) -> Self {
+        Self::new(f(self.as_str()))
     }
-}
 
-impl PartialEq for ColdString {
-    fn eq(&self, other: &Self) -> bool {
-        match (self.is_inline(), other.is_inline()) {
-            (true, true) => self.ptr() == other.ptr(),
-            (false, false) => unsafe { self.decode_heap() == other.decode_heap() },
-            _ => false,
-        }
+    /// Converts this [`ColdString`] into a [`Box<str>`].
+    ///
+    /// Like [`ColdString::into_string`], the bytes are copied once into an allocation of exactly
+    /// `len()` bytes, and this string's heap block, if any, is freed.
+    ///
+    /// # Examples
+    /// ```
+    /// use cold_string::ColdString;
+    ///
+    /// let b = ColdString::new("a string that lives on the heap").into_boxed_str();
+    /// assert_eq!(&*b, "a string that lives on the heap");
+    /// ```
+    #[inline]
+    pub fn into_boxed_str(self) -> Box<str> {
+        Box::from(self.as_str())
     }
-}
-
-impl Eq for ColdString {}
+
+    /// Converts this [`ColdString`] into a vector of its UTF-8 bytes.
+    ///
+    /// Like [`ColdString::into_string`], the bytes are copied once into an allocation of exactly
+    /// `len()` bytes, and this string's heap block, if any, is freed.
+    ///
+    /// # Examples
+    /// ```
+    /// use cold_string::ColdString;
+    ///
+    /// let v = ColdString::new("cold").into_bytes();
+    /// assert_eq!(v, b"cold");
+    /// assert_eq!(v.capacity(), v.len());
+    /// ```
+    #[inline]
+    pub fn into_bytes(self) -> Vec<u8> {
+        self.to_vec()
+    }
+
+    /// Copies the UTF-8 bytes of this [`ColdString`] into a new vector of exactly `len()`
+    /// bytes.
+    ///
+    /// # Examples
+    /// ```
+    /// use cold_string::ColdString;
+    ///
+    /// let s = ColdString::new("🦀");
+    /// assert_eq!(s.to_vec(), [240, 159, 166, 128]);
+    /// ```
+    #[inline]
+    pub fn to_vec(&self) -> Vec<u8> {
+        self.as_bytes().to_vec()
+    }
+
+    /// Consumes and leaks this [`ColdString`], returning a reference to its contents that lives
+    /// for the rest of the program, like [`String::leak`].
+    ///
+    /// A heap string is not copied: its whole heap block is leaked, including the length header
+    /// in front of the returned bytes. A string borrowing a [`StaticStr`] returns the borrowed
+    /// `&'static str`. An inline string has no heap block, since its bytes live in the
+    /// [`ColdString`] itself, so they are copied into a new leaked allocation of `len()` bytes.
+    ///
+    /// # Examples
+    /// ```
+    /// use cold_string::ColdString;
+    ///
+    /// let key: &'static str = ColdString::new("config.server.address").leak();
+    /// assert_eq!(key, "config.server.address");
+    /// ```
+    pub fn leak(self) -> &'static str {
+        if self.is_inline() {
+            return Box::leak(self.into_boxed_str());
+        }
+        let this = mem::ManuallyDrop::new(self);
+        let s: *const str = this.as_str();
+        // SAFETY: the heap block is never freed, and a `StaticStr` lives forever
+        unsafe { &*s }
+    }
+
+    /// Returns a subslice of this string, or `None` if the range is out of bounds or not on
+    /// char boundaries.
+    ///
+    /// Unlike [`str::get`], this accepts any [`RangeBounds<usize>`], including `(Bound, Bound)`
+    /// pairs.
+    ///
+    /// # Examples
+    /// ```
+    /// use cold_string::ColdString;
+    ///
+    /// let s = ColdString::new("🦀cold");
+    /// assert_eq!(s.get(4..), Some("cold"));
+    /// assert_eq!(s.get(..=4), Some("🦀c"));
+    /// assert_eq!(s.get(1..), None);
+    /// assert_eq!(s.get(..9), None);
+    /// ```
+    #[inline]
+    pub fn get<R: RangeBounds<usize>>(&self, range: R) -> Option<&str> {
+        let s = self.as_str();
+        s.get(Self::to_range(range, s.len())?)
+    }
+
+    /// Returns a subslice of this string, without checking the range.
+    ///
+    /// # Safety
+    /// The start of the range must not be after the end, the end must not be after
+    /// [`ColdString::len`], and both must be on char boundaries. An inclusive end must be less
+    /// than `usize::MAX`.
+    ///
+    /// # Examples
+    /// ```
+    /// use cold_string::ColdString;
+    ///
+    /// let s = ColdString::new("🦀cold");
+    /// assert_eq!(unsafe { s.get_unchecked(4..6) }, "co");
+    /// ```
+    #[inline]
+    pub unsafe fn get_unchecked<R: RangeBounds<usize>>(&self, range: R) -> &str {
+        let s = self.as_str();
+        s.get_unchecked(Self::to_range(range, s.len()).unwrap_unchecked())
+    }
+
+    /// Copies a substring of this string into a new [`ColdString`].
+    ///
+    /// The result is inline if it is short enough, even if this string is on the heap, in which
+    /// case nothing is allocated.
+    ///
+    /// # Panics
+    /// Panics if the range is out of bounds or not on char boundaries, like indexing a `str`.
+    ///
+    /// # Examples
+    /// ```
+    /// use cold_string::ColdString;
+    ///
+    /// let url = ColdString::new("https://example.com/path");
+    /// let host = url.slice(8..19);
+    /// assert_eq!(host, "example.com");
+    /// assert!(url.slice(..5).is_inline());
+    /// ```
+    #[inline]
+    pub fn slice<R: RangeBounds<usize>>(&self, range: R) -> Self {
+        let s = self.as_str();
+        let range = Self::to_range(range, s.len()).expect("range end overflows usize");
+        Self::new(&s[range])
+    }
+
+    /// Copies a substring of this string into a new [`ColdString`], or returns `None` if the
+    /// range is out of bounds or not on char boundaries.
+    ///
+    /// # Examples
+    /// ```
+    /// use cold_string::ColdString;
+    ///
+    /// let s = ColdString::new("🦀cold");
+    /// assert_eq!(s.try_slice(4..), Some(ColdString::new("cold")));
+    /// assert_eq!(s.try_slice(1..), None);
+    /// ```
+    #[inline]
+    pub fn try_slice<R: RangeBounds<usize>>(&self, range: R) -> Option<Self> {
+        self.get(range).map(Self::new)
+    }
+
+    /// Returns a copy of this string with leading and trailing whitespace removed, like
+    /// [`str::trim`].
+    ///
+    /// If nothing is trimmed, this is a clone, which doesn't allocate for inline strings. A
+    /// trimmed heap string that becomes short enough is inline.
+    ///
+    /// # Examples
+    /// ```
+    /// use cold_string::ColdString;
+    ///
+    /// let field = ColdString::new("   a field padded to the heap   ");
+    /// assert_eq!(field.trimmed(), "a field padded to the heap");
+    /// assert!(ColdString::new("  short   ").trimmed().is_inline());
+    /// ```
+    #[inline]
+    pub fn trimmed(&self) -> Self {
+        self.trimmed_to(self.as_str().trim())
+    }
+
+    /// Returns a copy of this string with leading whitespace removed, like
+    /// [`str::trim_start`].
+    ///
+    /// # Examples
+    /// ```
+    /// use cold_string::ColdString;
+    ///
+    /// assert_eq!(ColdString::new("\t cold \n").trim_start_cold(), "cold \n");
+    /// ```
+    #[inline]
+    pub fn trim_start_cold(&self) -> Self {
+        self.trimmed_to(self.as_str().trim_start())
+    }
+
+    /// Returns a copy of this string with trailing whitespace removed, like
+    /// [`str::trim_end`].
+    ///
+    /// # Examples
+    /// ```
+    /// use cold_string::ColdString;
+    ///
+    /// assert_eq!(ColdString::new("\t cold \n").trim_end_cold(), "\t cold");
+    /// ```
+    #[inline]
+    pub fn trim_end_cold(&self) -> Self {
+        self.trimmed_to(self.as_str().trim_end())
+    }
+
+    /// Returns a copy of this string with all leading and trailing `c`s removed, like
+    /// [`str::trim_matches`].
+    ///
+    /// # Examples
+    /// ```
+    /// use cold_string::ColdString;
+    ///
+    /// assert_eq!(ColdString::new("\"quoted\"").trim_matches_cold('"'), "quoted");
+    /// ```
+    #[inline]
+    pub fn trim_matches_cold(&self, c: char) -> Self {
+        self.trimmed_to(self.as_str().trim_matches(c))
+    }
+
+    /// Copies `trimmed`, a substring of this string, or clones this string if nothing was
+    /// trimmed.
+    #[inline]
+    fn trimmed_to(&self, trimmed: &str) -> Self {
+        if trimmed.len() == self.len() {
+            self.clone()
+        } else {
+            Self::new(trimmed)
+        }
+    }
+
+    /// Splits this string at byte index `mid` into two new [`ColdString`]s, like
+    /// [`str::split_at`].
+    ///
+    /// Each half is copied once, and is inline or on the heap depending on its own length.
+    ///
+    /// # Panics
+    /// Panics if `mid` is past the end of the string or not on a char boundary.
+    ///
+    /// # Examples
+    /// ```
+    /// use cold_string::ColdString;
+    ///
+    /// let s = ColdString::new("key=a value long enough for the heap");
+    /// let (key, value) = s.split_at_cold(3);
+    /// assert_eq!((key.as_str(), value.as_str()), ("key", "=a value long enough for the heap"));
+    /// assert!(key.is_inline());
+    /// ```
+    #[inline]
+    pub fn split_at_cold(&self, mid: usize) -> (Self, Self) {
+        let (left, right) = self.as_str().split_at(mid);
+        (Self::new(left), Self::new(right))
+    }
+
+    /// Splits this string at byte index `mid` into two new [`ColdString`]s, or returns `None`
+    /// if `mid` is past the end of the string or not on a char boundary.
+    ///
+    /// # Examples
+    /// ```
+    /// use cold_string::ColdString;
+    ///
+    /// let s = ColdString::new("🦀cold");
+    /// assert_eq!(s.try_split_at_cold(4), Some((ColdString::new("🦀"), ColdString::new("cold"))));
+    /// assert_eq!(s.try_split_at_cold(2), None);
+    /// assert_eq!(s.try_split_at_cold(9), None);
+    /// ```
+    #[inline]
+    pub fn try_split_at_cold(&self, mid: usize) -> Option<(Self, Self)> {
+        let s = self.as_str();
+        if !s.is_char_boundary(mid) {
+            return None;
+        }
+        let (left, right) = s.split_at(mid);
+        Some((Self::new(left), Self::new(right)))
+    }
+
+    /// Returns an iterator over the chars of this string, like [`str::chars`].
+    ///
+    /// # Examples
+    /// ```
+    /// use cold_string::ColdString;
+    ///
+    /// let s = ColdString::new("né");
+    /// assert!(s.chars().eq(['n', 'é']));
+    /// ```
+    #[inline]
+    pub fn chars(&self) -> Chars<'_> {
+        self.as_str().chars()
+    }
+
+    /// Returns an iterator over the chars of this string and their byte indices, like
+    /// [`str::char_indices`].
+    ///
+    /// # Examples
+    /// ```
+    /// use cold_string::ColdString;
+    ///
+    /// let s = ColdString::new("né!");
+    /// assert!(s.char_indices().eq([(0, 'n'), (1, 'é'), (3, '!')]));
+    /// ```
+    #[inline]
+    pub fn char_indices(&self) -> CharIndices<'_> {
+        self.as_str().char_indices()
+    }
+
+    /// Returns an iterator over the bytes of this string, like [`str::bytes`].
+    ///
+    /// # Examples
+    /// ```
+    /// use cold_string::ColdString;
+    ///
+    /// let s = ColdString::new("né");
+    /// assert!(s.bytes().eq([b'n', 0xc3, 0xa9]));
+    /// ```
+    #[inline]
+    pub fn bytes(&self) -> Bytes<'_> {
+        self.as_str().bytes()
+    }
+
+    #[inline]
+    fn to_range<R: RangeBounds<usize>>(range: R, len: usize) -> Option<Range<usize>> {
+        let start = match range.start_bound() {
+            Bound::Included(&start) => start,
+            Bound::Excluded(&start) => start.checked_add(1)?,
+            Bound::Unbounded => 0,
+        };
+        let end = match range.end_bound() {
+            Bound::Included(&end) => end.checked_add(1)?,
+            Bound::Excluded(&end) => end,
+            Bound::Unbounded => len,
+        };
+        Some(start..end)
+    }
+
+    /// Collects the lines of this string into a `Vec` of [`ColdString`]s, with the same
+    /// semantics as [`str::lines`].
+    ///
+    /// The lines are counted first, so the `Vec` is allocated exactly once.
+    ///
+    /// # Examples
+    /// ```
+    /// use cold_string::ColdString;
+    ///
+    /// let s = ColdString::new("foo\r\nbar\n\nbaz\n");
+    /// assert_eq!(s.collect_lines(), ["foo", "bar", "", "baz"]);
+    /// ```
+    pub fn collect_lines(&self) -> Vec<ColdString> {
+        let s = self.as_str();
+        let newlines = s.bytes().filter(|&b| b == b'\n').count();
+        let count = newlines + (!s.is_empty() && !s.ends_with('\n')) as usize;
+        let mut lines = Vec::with_capacity(count);
+        lines.extend(s.lines().map(ColdString::new));
+        debug_assert_eq!(lines.len(), count);
+        lines
+    }
+
+    /// Collects the substrings of this string, separated by `pat`, into a `Vec` of
+    /// [`ColdString`]s, with the same semantics as [`str::split`].
+    ///
+    /// The separators are counted first, so the `Vec` is allocated exactly once.
+    ///
+    /// # Examples
+    /// ```
+    /// use cold_string::ColdString;
+    ///
+    /// let s = ColdString::new("a,b,,a longer field");
+    /// assert_eq!(s.collect_split(","), ["a", "b", "", "a longer field"]);
+    /// ```
+    pub fn collect_split(&self, pat: &str) -> Vec<ColdString> {
+        let s = self.as_str();
+        let count = s.matches(pat).count() + 1;
+        let mut parts = Vec::with_capacity(count);
+        parts.extend(s.split(pat).map(ColdString::new));
+        debug_assert_eq!(parts.len(), count);
+        parts
+    }
+}
+
+impl Default for ColdString {
+    #[inline]
+    fn default() -> Self {
+        Self::EMPTY
+    }
+}
+
+impl Deref for ColdString {
+    type Target = str;
+    fn deref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+/// Slices a [`ColdString`] like a [`str`](prim@str), with any of the range types.
+///
+/// # Panics
+/// Panics if the range is out of bounds or not on char boundaries, with the same message as
+/// slicing a [`str`](prim@str).
+impl<I: SliceIndex<str>> Index<I> for ColdString {
+    type Output = I::Output;
+
+    #[inline]
+    fn index(&self, index: I) -> &I::Output {
+        self.as_str().index(index)
+    }
+}
+
+/// Iterates over the chars of the string, like [`ColdString::chars`].
+impl<'a> IntoIterator for &'a ColdString {
+    type Item = char;
+    type IntoIter = Chars<'a>;
+
+    #[inline]
+    fn into_iter(self) -> Chars<'a> {
+        self.chars()
+    }
+}
+
+impl ColdString {
+    /// Frees the heap block. Kept out of line, so dropping an inline string is a single check.
+    ///
+    /// SAFETY: the string must own a heap block, and must not be used afterwards.
+    #[cold]
+    #[inline(never)]
+    unsafe fn drop_heap(&mut self) {
+        let ptr = self.heap_ptr();
+        let (len, _) = Header::read(ptr);
+        // SAFETY: the block was allocated by alloc_heap() with this layout
+        dealloc(ptr as *mut u8, Self::allocated_layout(len));
+    }
+}
+
+impl Drop for ColdString {
+    #[inline]
+    fn drop(&mut self) {
+        if self.is_heap_allocated() {
+            // SAFETY: the string owns a heap block
+            unsafe { self.drop_heap() }
+        }
+    }
+}
+
+impl Clone for ColdString {
+    fn clone(&self) -> Self {
+        if self.is_inline() || self.is_static() {
+            let ptr = self.ptr();
+            let encoded = unsafe { NonNull::new_unchecked(ptr as *mut _) };
+            Self { encoded }
+        } else {
+            // SAFETY: the string is neither inline nor static
+            unsafe { self.clone_heap_block() }
+        }
+    }
+
+    /// Reuses this string's heap block if `source` is also on the heap and has the same
+    /// length, since the blocks then have the same layout. Otherwise, this is `clone`.
+    fn clone_from(&mut self, source: &Self) {
+        if self.is_heap_allocated() && source.is_heap_allocated() && self.len() == source.len() {
+            let ptr = self.heap_ptr() as *mut u8;
+            // SAFETY: this string owns the block, which holds `len` bytes after the header, and
+            // is overwritten with the same number of UTF-8 bytes
+            unsafe {
+                let (len, header) = Header::read(ptr);
+                ptr::copy_nonoverlapping(source.as_bytes().as_ptr(), ptr.add(header), len);
+            }
+        } else {
+            *self = source.clone();
+        }
+    }
+}
+
+/// Compares byte strings a word at a time, with an overlapping last word instead of a byte
+/// tail. Heap strings are longer than a word, so the loop always runs.
+#[inline]
+fn eq_words(a: &[u8], b: &[u8]) -> bool {
+    let len = a.len();
+    if len != b.len() {
+        return false;
+    }
+    if len < WIDTH {
+        return a == b;
+    }
+    // SAFETY: every word read is within both slices
+    let word =
+        |s: &[u8], i: usize| unsafe { ptr::read_unaligned(s.as_ptr().add(i) as *const usize) };
+    let mut i = 0;
+    while i + WIDTH <= len {
+        if word(a, i) != word(b, i) {
+            return false;
+        }
+        i += WIDTH;
+    }
+    i == len || word(a, len - WIDTH) == word(b, len - WIDTH)
+}
+
+impl PartialEq for ColdString {
+    /// Inline strings are canonical, so they are compared by their encoding. Longer strings
+    /// are either on the heap or static, and are compared by their bytes. Slice equality
+    /// compares the lengths read from the headers first, so no payload byte is read for
+    /// strings of different lengths.
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        match (self.is_inline(), other.is_inline()) {
+            (true, true) => self.ptr() == other.ptr(),
+            (false, false) => unsafe { eq_words(self.decode_heap(), other.decode_heap()) },
+            _ => false,
+        }
+    }
+}
+
+impl Eq for ColdString {}
 
 impl Hash for ColdString {
     fn hash<H: Hasher>(&self, state: &mut H) {
@@ -474,25 +1439,98 @@ impl fmt::Debug for ColdString {
 
 impl fmt::Display for ColdString {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        fmt::Display::fmt(self.as_str(), f)
+        let s = self.as_str();
+        if f.width().is_none() && f.precision().is_none() {
+            return f.write_str(s);
+        }
+        if !s.is_ascii() {
+            // Precision and width count chars, which `str` already handles.
+            return fmt::Display::fmt(s, f);
+        }
+        // ASCII strings have as many chars as bytes, so truncation and padding can be
+        // computed up front, and the padding written in bulk instead of one fill char at a time.
+        let s = match f.precision() {
+            Some(precision) if precision < s.len() => &s[..precision],
+            _ => s,
+        };
+        let pad = f.width().unwrap_or(0).saturating_sub(s.len());
+        let (pre, post) = match f.align() {
+            Some(fmt::Alignment::Right) => (pad, 0),
+            Some(fmt::Alignment::Center) => (pad / 2, (pad + 1) / 2),
+            _ => (0, pad),
+        };
+        write_fill(f, pre)?;
+        f.write_str(s)?;
+        write_fill(f, post)
     }
 }
 
+/// Writes `n` copies of the formatter's fill char, in as few `write_str` calls as possible.
+fn write_fill(f: &mut fmt::Formatter<'_>, mut n: usize) -> fmt::Result {
+    if n == 0 {
+        return Ok(());
+    }
+    let mut fill = [0u8; 4];
+    let fill = f.fill().encode_utf8(&mut fill).as_bytes();
+    let mut buf = [0u8; 64];
+    for chunk in buf.chunks_exact_mut(fill.len()) {
+        chunk.copy_from_slice(fill);
+    }
+    let per_write = buf.len() / fill.len();
+    while n > 0 {
+        let k = n.min(per_write);
+        // SAFETY: buf holds whole copies of a UTF-8 encoded char
+        f.write_str(unsafe { str::from_utf8_unchecked(&buf[..k * fill.len()]) })?;
+        n -= k;
+    }
+    Ok(())
+}
+
 impl From<&str> for ColdString {
     fn from(s: &str) -> Self {
         Self::new(s)
     }
 }
 
+impl From<&mut str> for ColdString {
+    #[inline]
+    fn from(s: &mut str) -> Self {
+        Self::new(s)
+    }
+}
+
 impl From<String> for ColdString {
+    #[inline]
     fn from(s: String) -> Self {
         Self::new(&s)
     }
 }
 
-impl From<ColdString> for String {
+impl From<&String> for ColdString {
+    #[inline]
+    fn from(s: &String) -> Self {
+        Self::new(s)
+    }
+}
+
+impl From<ColdString> for String {
+    #[inline]
+    fn from(s: ColdString) -> Self {
+        s.into_string()
+    }
+}
+
+impl From<&ColdString> for String {
+    #[inline]
+    fn from(s: &ColdString) -> Self {
+        s.as_str().to_owned()
+    }
+}
+
+impl From<ColdString> for Box<str> {
+    #[inline]
     fn from(s: ColdString) -> Self {
-        s.as_str().to_owned()
+        s.into_boxed_str()
     }
 }
 
@@ -519,6 +1557,10 @@ impl<'a> From<Cow<'a, str>> for ColdString {
     }
 }
 
+/// Copies the string out of the box, which is then freed.
+///
+/// The heap block of a [`ColdString`] starts with its length header, so the box's
+/// allocation can't be reused.
 impl From<Box<str>> for ColdString {
     #[inline]
     #[track_caller]
@@ -527,10 +1569,389 @@ impl From<Box<str>> for ColdString {
     }
 }
 
+impl From<Arc<str>> for ColdString {
+    #[inline]
+    fn from(s: Arc<str>) -> Self {
+        Self::new(&*s)
+    }
+}
+
+impl From<&Arc<str>> for ColdString {
+    #[inline]
+    fn from(s: &Arc<str>) -> Self {
+        Self::new(&**s)
+    }
+}
+
+impl From<Rc<str>> for ColdString {
+    #[inline]
+    fn from(s: Rc<str>) -> Self {
+        Self::new(&*s)
+    }
+}
+
+impl From<&Rc<str>> for ColdString {
+    #[inline]
+    fn from(s: &Rc<str>) -> Self {
+        Self::new(&**s)
+    }
+}
+
+impl From<char> for ColdString {
+    #[inline]
+    fn from(c: char) -> Self {
+        Self::from_char(c)
+    }
+}
+
+/// Collects chars into a [`ColdString`].
+///
+/// Chars are encoded into an inline buffer while they fit, so short results don't allocate.
+/// Longer results spill into a [`String`], which is copied into the heap block once at the end.
 impl FromIterator<char> for ColdString {
     fn from_iter<I: IntoIterator<Item = char>>(iter: I) -> Self {
-        let s: String = iter.into_iter().collect();
-        ColdString::new(&s)
+        let mut buf = [0u8; WIDTH];
+        let mut len = 0;
+        let mut iter = iter.into_iter();
+        while let Some(c) = iter.next() {
+            if c.len_utf8() <= WIDTH - len {
+                len += c.encode_utf8(&mut buf[len..]).len();
+                continue;
+            }
+            // Each char is 1 to 4 bytes, so `upper` is a good guess for ASCII-heavy iterators
+            // such as `str::Chars`, while `lower * 4` bounds the waste for the rest.
+            let (lower, upper) = iter.size_hint();
+            let hint = upper.unwrap_or(lower).min(lower.saturating_mul(4));
+            let mut s = String::with_capacity(len + c.len_utf8() + hint);
+            // SAFETY: the bytes are encoded chars
+            s.push_str(unsafe { str::from_utf8_unchecked(&buf[..len]) });
+            s.push(c);
+            s.extend(iter);
+            return Self::new(&s);
+        }
+        // SAFETY: the bytes are encoded chars
+        Self::new(unsafe { str::from_utf8_unchecked(&buf[..len]) })
+    }
+}
+
+impl ColdString {
+    /// Concatenates `parts` into a [`ColdString`], allocating at most once.
+    ///
+    /// The parts are iterated twice, once to sum their lengths and once to copy them into the
+    /// inline buffer or an exactly sized heap block, so the iterator must be [`Clone`], like
+    /// that of a slice. To concatenate parts from any iterator, collect them instead.
+    ///
+    /// # Panics
+    /// Panics if the total length overflows `usize`.
+    ///
+    /// # Examples
+    /// ```
+    /// use cold_string::ColdString;
+    ///
+    /// let (prefix, id) = ("user", "1234");
+    /// assert_eq!(ColdString::concat(&[prefix, ":", id]), "user:1234");
+    /// assert_eq!(ColdString::concat(Vec::<String>::new()), "");
+    /// ```
+    pub fn concat<S, I>(parts: I) -> Self
+    where
+        S: AsRef<str>,
+        I: IntoIterator<Item = S>,
+        I::IntoIter: Clone,
+    {
+        let parts = parts.into_iter();
+        let len = parts
+            .clone()
+            .try_fold(0usize, |len, part| len.checked_add(part.as_ref().len()))
+            .expect("capacity overflow");
+        // SAFETY: every byte is written below
+        let mut uninit = unsafe { Self::with_len_uninit(len) };
+        let writer = uninit.writer_uninit();
+        let mut pos = 0;
+        for part in parts {
+            let bytes = part.as_ref().as_bytes();
+            write_slice(&mut writer[pos..pos + bytes.len()], bytes);
+            pos += bytes.len();
+        }
+        write_zeros(&mut writer[pos..]);
+        // SAFETY: the bytes are a concatenation of `str`s, followed by zeros if the second
+        // pass yielded fewer bytes
+        unsafe { uninit.finish_unchecked() }
+    }
+
+    /// Joins `parts` into a [`ColdString`], placing `sep` between each pair of parts and
+    /// allocating at most once.
+    ///
+    /// Like [`ColdString::concat`], the parts are iterated twice, so the iterator must be
+    /// [`Clone`].
+    ///
+    /// # Panics
+    /// Panics if the total length overflows `usize`.
+    ///
+    /// # Examples
+    /// ```
+    /// use cold_string::ColdString;
+    ///
+    /// assert_eq!(ColdString::join(", ", &["a", "b", "c"]), "a, b, c");
+    /// assert_eq!(ColdString::join("🦀", &["solo"]), "solo");
+    /// assert_eq!(ColdString::join("/", Vec::<String>::new()), "");
+    /// ```
+    pub fn join<S, I>(sep: &str, parts: I) -> Self
+    where
+        S: AsRef<str>,
+        I: IntoIterator<Item = S>,
+        I::IntoIter: Clone,
+    {
+        let mut parts = parts.into_iter();
+        let len = parts
+            .clone()
+            .enumerate()
+            .try_fold(0usize, |len, (i, part)| {
+                let sep_len = if i == 0 { 0 } else { sep.len() };
+                len.checked_add(sep_len)?.checked_add(part.as_ref().len())
+            })
+            .expect("capacity overflow");
+        // SAFETY: every byte is written below
+        let mut uninit = unsafe { Self::with_len_uninit(len) };
+        let writer = uninit.writer_uninit();
+        let mut pos = 0;
+        let mut write = |bytes: &[u8]| {
+            write_slice(&mut writer[pos..pos + bytes.len()], bytes);
+            pos += bytes.len();
+        };
+        if let Some(first) = parts.next() {
+            write(first.as_ref().as_bytes());
+            for part in parts {
+                write(sep.as_bytes());
+                write(part.as_ref().as_bytes());
+            }
+        }
+        write_zeros(&mut writer[pos..]);
+        // SAFETY: the bytes are `str`s and separators, followed by zeros if the second pass
+        // yielded fewer bytes
+        unsafe { uninit.finish_unchecked() }
+    }
+
+    /// Creates a [`ColdString`] by repeating this string `n` times, like [`str::repeat`].
+    ///
+    /// # Panics
+    /// Panics if the resulting length overflows `usize`.
+    ///
+    /// # Examples
+    /// ```
+    /// use cold_string::ColdString;
+    ///
+    /// assert_eq!(ColdString::new("ab").repeat(3), "ababab");
+    /// assert!(ColdString::new("ab").repeat(4).is_inline());
+    /// assert_eq!(ColdString::new("🦀").repeat(0), "");
+    /// ```
+    pub fn repeat(&self, n: usize) -> Self {
+        let s = self.as_bytes();
+        let len = s.len().checked_mul(n).expect("capacity overflow");
+        // SAFETY: every byte is written below
+        let mut uninit = unsafe { Self::with_len_uninit(len) };
+        if len > 0 {
+            let writer = uninit.writer_uninit();
+            write_slice(&mut writer[..s.len()], s);
+            let mut filled = s.len();
+            while filled < len {
+                let count = filled.min(len - filled);
+                writer.copy_within(..count, filled);
+                filled += count;
+            }
+        }
+        // SAFETY: the bytes are copies of a `str`
+        unsafe { uninit.finish_unchecked() }
+    }
+
+    /// Returns this string with its chars in reverse order, like collecting
+    /// `self.chars().rev()`.
+    ///
+    /// This reverses chars, not grapheme clusters, so combining marks end up before the char
+    /// they modified. The length doesn't change, so the chars are written back to front into
+    /// the final inline buffer or heap block in one pass.
+    ///
+    /// # Examples
+    /// ```
+    /// use cold_string::ColdString;
+    ///
+    /// assert_eq!(ColdString::new("cold🦀").reversed(), "🦀dloc");
+    /// // "e" followed by a combining acute accent.
+    /// assert_eq!(ColdString::new("e\u{301}").reversed(), "\u{301}e");
+    /// ```
+    pub fn reversed(&self) -> Self {
+        let s = self.as_str();
+        // SAFETY: every byte is written below
+        let mut uninit = unsafe { Self::with_len_uninit(s.len()) };
+        let writer = uninit.writer_uninit();
+        let mut end = writer.len();
+        for c in s.chars() {
+            let start = end - c.len_utf8();
+            write_slice(
+                &mut writer[start..end],
+                c.encode_utf8(&mut [0; 4]).as_bytes(),
+            );
+            end = start;
+        }
+        // SAFETY: the bytes are the encoded chars of a `str`
+        unsafe { uninit.finish_unchecked() }
+    }
+
+    /// Concatenates `parts` into a [`ColdString`].
+    ///
+    /// Parts are copied into an inline buffer while they fit. Once they don't, the remaining
+    /// parts are held onto so the total length is known, and everything is then copied once
+    /// into the final heap block.
+    fn collect_parts<S: AsRef<str>, I: IntoIterator<Item = S>>(parts: I) -> Self {
+        let mut buf = [0u8; WIDTH];
+        let mut len = 0;
+        let mut parts = parts.into_iter();
+        while let Some(part) = parts.next() {
+            let bytes = part.as_ref().as_bytes();
+            if bytes.len() <= WIDTH - len {
+                buf[len..len + bytes.len()].copy_from_slice(bytes);
+                len += bytes.len();
+                continue;
+            }
+            let mut rest = Vec::with_capacity(1 + parts.size_hint().0);
+            rest.push(part);
+            rest.extend(parts);
+            let total = rest.iter().fold(len, |total, part| {
+                total
+                    .checked_add(part.as_ref().len())
+                    .expect("capacity overflow")
+            });
+            // SAFETY: every byte is written below
+            let mut uninit = unsafe { Self::with_len_uninit(total) };
+            let writer = uninit.writer_uninit();
+            write_slice(&mut writer[..len], &buf[..len]);
+            for part in &rest {
+                let bytes = part.as_ref().as_bytes();
+                write_slice(&mut writer[len..len + bytes.len()], bytes);
+                len += bytes.len();
+            }
+            write_zeros(&mut writer[len..]);
+            // SAFETY: the bytes are a concatenation of `str`s, followed by zeros if a part
+            // yielded fewer bytes the second time
+            return unsafe { uninit.finish_unchecked() };
+        }
+        // SAFETY: the bytes are a concatenation of `str`s
+        Self::new(unsafe { str::from_utf8_unchecked(&buf[..len]) })
+    }
+}
+
+impl<'a> FromIterator<&'a str> for ColdString {
+    fn from_iter<I: IntoIterator<Item = &'a str>>(iter: I) -> Self {
+        Self::collect_parts(iter)
+    }
+}
+
+impl FromIterator<String> for ColdString {
+    fn from_iter<I: IntoIterator<Item = String>>(iter: I) -> Self {
+        Self::collect_parts(iter)
+    }
+}
+
+impl<'a> FromIterator<Cow<'a, str>> for ColdString {
+    fn from_iter<I: IntoIterator<Item = Cow<'a, str>>>(iter: I) -> Self {
+        Self::collect_parts(iter)
+    }
+}
+
+impl FromIterator<ColdString> for ColdString {
+    fn from_iter<I: IntoIterator<Item = ColdString>>(iter: I) -> Self {
+        Self::collect_parts(iter)
+    }
+}
+
+impl<'a> FromIterator<&'a ColdString> for ColdString {
+    fn from_iter<I: IntoIterator<Item = &'a ColdString>>(iter: I) -> Self {
+        Self::collect_parts(iter)
+    }
+}
+
+/// Concatenates [`ColdString`]s into a [`String`].
+///
+/// # Examples
+/// ```
+/// use cold_string::ColdString;
+///
+/// let parts = [ColdString::new("cold"), ColdString::new("-"), ColdString::new("string")];
+/// let s: String = parts.iter().collect();
+/// assert_eq!(s, "cold-string");
+/// ```
+impl FromIterator<ColdString> for String {
+    fn from_iter<I: IntoIterator<Item = ColdString>>(iter: I) -> Self {
+        let mut s = String::new();
+        for part in iter {
+            s.push_str(&part);
+        }
+        s
+    }
+}
+
+impl<'a> FromIterator<&'a ColdString> for String {
+    fn from_iter<I: IntoIterator<Item = &'a ColdString>>(iter: I) -> Self {
+        iter.into_iter().map(ColdString::as_str).collect()
+    }
+}
+
+impl ColdString {
+    fn concat_pair(left: &str, right: &str) -> Self {
+        let len = left
+            .len()
+            .checked_add(right.len())
+            .expect("capacity overflow");
+        // SAFETY: every byte is written below
+        let mut uninit = unsafe { Self::with_len_uninit(len) };
+        let writer = uninit.writer_uninit();
+        write_slice(&mut writer[..left.len()], left.as_bytes());
+        write_slice(&mut writer[left.len()..], right.as_bytes());
+        // SAFETY: the bytes are a concatenation of two `str`s
+        unsafe { uninit.finish_unchecked() }
+    }
+}
+
+/// Concatenates two strings into a new [`ColdString`].
+///
+/// [`ColdString`] is immutable, so every `+` copies both sides into a new string, allocating
+/// if the result is too long to be inline. To join many parts, use [`ColdString::concat`],
+/// which copies each part once.
+///
+/// # Examples
+/// ```
+/// use cold_string::ColdString;
+///
+/// let dir = ColdString::new("usr");
+/// let file = ColdString::new("local");
+/// assert_eq!(&dir + "/" + &file, "usr/local");
+///
+/// let joined = ColdString::concat(&["usr", "/", "local", "/", "bin"]);
+/// assert_eq!(joined, "usr/local/bin");
+/// ```
+impl Add<&str> for ColdString {
+    type Output = ColdString;
+
+    #[inline]
+    fn add(self, rhs: &str) -> ColdString {
+        Self::concat_pair(self.as_str(), rhs)
+    }
+}
+
+impl Add<&ColdString> for ColdString {
+    type Output = ColdString;
+
+    #[inline]
+    fn add(self, rhs: &ColdString) -> ColdString {
+        Self::concat_pair(self.as_str(), rhs.as_str())
+    }
+}
+
+impl Add<&str> for &ColdString {
+    type Output = ColdString;
+
+    #[inline]
+    fn add(self, rhs: &str) -> ColdString {
+        ColdString::concat_pair(self.as_str(), rhs)
     }
 }
 
@@ -544,11 +1965,14 @@ impl core::borrow::Borrow<str> for ColdString {
 }
 
 impl PartialEq<str> for ColdString {
+    /// Only strings longer than `INLINE_CAPACITY` are on the heap, so a heap string is never
+    /// equal to a short `str`, and its block isn't read.
+    #[inline]
     fn eq(&self, other: &str) -> bool {
-        if self.is_inline() {
-            unsafe { self.decode_inline() == other.as_bytes() }
-        } else {
-            unsafe { self.decode_heap() == other.as_bytes() }
+        match (self.is_inline(), other.len() <= WIDTH) {
+            (true, true) => unsafe { self.decode_inline() == other.as_bytes() },
+            (false, false) => unsafe { eq_words(self.decode_heap(), other.as_bytes()) },
+            _ => false,
         }
     }
 }
@@ -571,6 +1995,108 @@ impl PartialEq<ColdString> for &str {
     }
 }
 
+impl PartialEq<String> for ColdString {
+    fn eq(&self, other: &String) -> bool {
+        self.eq(other.as_str())
+    }
+}
+
+impl PartialEq<ColdString> for String {
+    fn eq(&self, other: &ColdString) -> bool {
+        other.eq(self.as_str())
+    }
+}
+
+impl PartialEq<Cow<'_, str>> for ColdString {
+    fn eq(&self, other: &Cow<'_, str>) -> bool {
+        self.eq(&**other)
+    }
+}
+
+impl PartialEq<ColdString> for Cow<'_, str> {
+    fn eq(&self, other: &ColdString) -> bool {
+        other.eq(&**self)
+    }
+}
+
+impl PartialEq<[u8]> for ColdString {
+    #[inline]
+    fn eq(&self, other: &[u8]) -> bool {
+        self.as_bytes() == other
+    }
+}
+
+impl PartialEq<ColdString> for [u8] {
+    #[inline]
+    fn eq(&self, other: &ColdString) -> bool {
+        other.eq(self)
+    }
+}
+
+impl PartialEq<&[u8]> for ColdString {
+    #[inline]
+    fn eq(&self, other: &&[u8]) -> bool {
+        self.eq(*other)
+    }
+}
+
+impl PartialEq<ColdString> for &[u8] {
+    #[inline]
+    fn eq(&self, other: &ColdString) -> bool {
+        other.eq(*self)
+    }
+}
+
+impl<const N: usize> PartialEq<[u8; N]> for ColdString {
+    #[inline]
+    fn eq(&self, other: &[u8; N]) -> bool {
+        self.eq(&other[..])
+    }
+}
+
+impl<const N: usize> PartialEq<ColdString> for [u8; N] {
+    #[inline]
+    fn eq(&self, other: &ColdString) -> bool {
+        other.eq(&self[..])
+    }
+}
+
+impl PartialEq<Box<str>> for ColdString {
+    fn eq(&self, other: &Box<str>) -> bool {
+        self.eq(&**other)
+    }
+}
+
+impl PartialEq<ColdString> for Box<str> {
+    fn eq(&self, other: &ColdString) -> bool {
+        other.eq(&**self)
+    }
+}
+
+impl PartialEq<Rc<str>> for ColdString {
+    fn eq(&self, other: &Rc<str>) -> bool {
+        self.eq(&**other)
+    }
+}
+
+impl PartialEq<ColdString> for Rc<str> {
+    fn eq(&self, other: &ColdString) -> bool {
+        other.eq(&**self)
+    }
+}
+
+impl PartialEq<Arc<str>> for ColdString {
+    fn eq(&self, other: &Arc<str>) -> bool {
+        self.eq(&**other)
+    }
+}
+
+impl PartialEq<ColdString> for Arc<str> {
+    fn eq(&self, other: &ColdString) -> bool {
+        other.eq(&**self)
+    }
+}
+
 impl AsRef<str> for ColdString {
     #[inline]
     fn as_ref(&self) -> &str {
@@ -586,13 +2112,55 @@ impl AsRef<[u8]> for ColdString {
 }
 
 impl Ord for ColdString {
+    /// Two inline strings are compared by their words, without reading any bytes. Otherwise,
+    /// the bytes are compared with `memcmp`, then the lengths.
+    #[inline]
     fn cmp(&self, other: &Self) -> Ordering {
-        self.as_str().cmp(other.as_str())
+        match (self.is_inline(), other.is_inline()) {
+            (true, true) => self.inline_key().cmp(&other.inline_key()),
+            _ => self.as_bytes().cmp(other.as_bytes()),
+        }
     }
 }
 
 impl PartialOrd for ColdString {
     fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl PartialOrd<str> for ColdString {
+    fn partial_cmp(&self, other: &str) -> Option<Ordering> {
+        self.as_str().partial_cmp(other)
+    }
+}
+
+impl PartialOrd<ColdString> for str {
+    fn partial_cmp(&self, other: &ColdString) -> Option<Ordering> {
+        self.partial_cmp(other.as_str())
+    }
+}
+
+impl PartialOrd<&str> for ColdString {
+    fn partial_cmp(&self, other: &&str) -> Option<Ordering> {
+        self.as_str().partial_cmp(*other)
+    }
+}
+
+impl PartialOrd<ColdString> for &str {
+    fn partial_cmp(&self, other: &ColdString) -> Option<Ordering> {
+        (*self).partial_cmp(other.as_str())
+    }
+}
+
+impl PartialOrd<String> for ColdString {
+    fn partial_cmp(&self, other: &String) -> Option<Ordering> {
+        self.as_str().partial_cmp(other.as_str())
+    }
+}
+
+impl PartialOrd<ColdString> for String {
+    fn partial_cmp(&self, other: &ColdString) -> Option<Ordering> {
         self.as_str().partial_cmp(other.as_str())
     }
 }
@@ -602,20 +2170,46 @@ impl alloc::str::FromStr for ColdString {
     fn from_str(s: &str) -> Result<ColdString, Self::Err> {
         Ok(ColdString::new(s))
     }
-}
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for ColdString {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for ColdString {
+    fn deserialize<D: serde::Deserializer<'de>>(d: D) -> Result<Self, D::Error> {
+        d.deserialize_str(ColdStringVisitor)
+    }
+}
+
+/// Builds the [`ColdString`] straight from the deserializer's `&str`, without going through a
+/// `String`, unless the format hands over an owned one.
+#[cfg(feature = "serde")]
+struct ColdStringVisitor;
+
+#[cfg(feature = "serde")]
+impl<'de> serde::de::Visitor<'de> for ColdStringVisitor {
+    type Value = ColdString;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("a string")
+    }
+
+    fn visit_str<E: serde::de::Error>(self, s: &str) -> Result<Self::Value, E> {
+        Ok(ColdString::new(s))
+    }
 
-#[cfg(feature = "serde")]
-impl serde::Serialize for ColdString {
-    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
-        serializer.serialize_str(self.as_str())
+    fn visit_string<E: serde::de::Error>(self, s: String) -> Result<Self::Value, E> {
+        Ok(ColdString::from(s))
     }
-}
 
-#[cfg(feature = "serde")]
-impl<'de> serde::Deserialize<'de> for ColdString {
-    fn deserialize<D: serde::Deserializer<'de>>(d: D) -> Result<Self, D::Error> {
-        let s = String::deserialize(d)?;
-        Ok(ColdString::new(&s))
+    fn visit_bytes<E: serde::de::Error>(self, b: &[u8]) -> Result<Self::Value, E> {
+        ColdString::from_utf8(b)
+            .map_err(|_| E::invalid_value(serde::de::Unexpected::Bytes(b), &self))
     }
 }
 
@@ -676,7 +2270,7 @@ mod tests {
         assert_eq!(*s, cs);
         let opt_s = Some(cs.clone());
         assert_eq!(opt_s, Some(ColdString::new(s)));
-        assert!(opt_s != None);
+        assert!(opt_s.is_some());
     }
 
     #[test]
@@ -779,6 +2373,333 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_partial_ord_str() {
+        let strs = [
+            "",
+            "\0",
+            "a",
+            "ab",
+            "abc",
+            "abcdefg",
+            "abcdefgh",
+            "abcdefghi",
+            "é",
+            "ée",
+            "🦀",
+            "🦀💯",
+            "longer test",
+            "longer test!",
+        ];
+        for a in strs {
+            let cold = ColdString::new(a);
+            for b in strs {
+                let expected = a.partial_cmp(b);
+                assert_eq!(cold.partial_cmp(b), expected);
+                assert_eq!(cold.partial_cmp(&b), expected);
+                assert_eq!(cold.partial_cmp(&String::from(b)), expected);
+                assert_eq!(b.partial_cmp(&cold), b.partial_cmp(a));
+                assert_eq!((*b).partial_cmp(&cold), b.partial_cmp(a));
+                assert_eq!(String::from(b).partial_cmp(&cold), b.partial_cmp(a));
+            }
+        }
+        assert!(ColdString::new("abc") < "abcd");
+        assert!("abcdefghi" > ColdString::new("abcdefgh"));
+    }
+
+    #[test]
+    fn test_collect_lines() {
+        for s in [
+            "",
+            "\n",
+            "\n\n",
+            "one line",
+            "foo\nbar",
+            "foo\nbar\n",
+            "foo\r\nbar\r\n",
+            "lone\rcarriage\r",
+            "\nleading and trailing\n\n",
+            "short\na much longer line than fits inline\n🦀💯",
+        ] {
+            let cold = ColdString::new(s);
+            let lines = cold.collect_lines();
+            let expected: Vec<ColdString> = s.lines().map(ColdString::new).collect();
+            assert_eq!(lines, expected);
+            assert_eq!(lines.capacity(), lines.len());
+        }
+    }
+
+    #[test]
+    fn test_collect_split() {
+        for s in [
+            "",
+            ",",
+            "a,b",
+            ",a,,b,",
+            "no separator here",
+            "🦀,💯,longer field",
+        ] {
+            let cold = ColdString::new(s);
+            for pat in [",", ",,", "", "🦀", "x"] {
+                let parts = cold.collect_split(pat);
+                let expected: Vec<ColdString> = s.split(pat).map(ColdString::new).collect();
+                assert_eq!(parts, expected);
+                assert_eq!(parts.capacity(), parts.len());
+            }
+        }
+    }
+
+    #[test]
+    fn test_eq_string_and_cow() {
+        for s in [
+            "",
+            "cold",
+            "12345678",
+            "123456789",
+            "this is a longer cold string",
+        ] {
+            let cold = ColdString::new(s);
+            let owned: Cow<'_, str> = Cow::Owned(String::from(s));
+            let borrowed: Cow<'_, str> = Cow::Borrowed(s);
+            assert_eq!(cold, String::from(s));
+            assert_eq!(String::from(s), cold);
+            assert_eq!(cold, owned);
+            assert_eq!(owned, cold);
+            assert_eq!(cold, borrowed);
+            assert_eq!(borrowed, cold);
+
+            let other = alloc::format!("{}!", s);
+            assert_ne!(cold, other);
+            assert_ne!(other, cold);
+            assert_ne!(cold, Cow::Borrowed(other.as_str()));
+            assert_ne!(Cow::<str>::Owned(other.clone()), cold);
+        }
+    }
+
+    #[test]
+    fn test_from_owned_and_borrowed() {
+        fn cold<T: Into<ColdString>>(t: T) -> ColdString {
+            t.into()
+        }
+
+        for s in ["", "cold", "12345678", "🦀💯", "a longer cold string"] {
+            let expected = ColdString::new(s);
+            let mut string = String::from(s);
+            assert_eq!(cold(&string), expected);
+            assert_eq!(cold(string.as_mut_str()), expected);
+            assert_eq!(cold(Box::<str>::from(s)), expected);
+            assert_eq!(cold(string), expected);
+            assert_eq!(cold(s), expected);
+        }
+    }
+
+    #[test]
+    fn test_from_shared() {
+        let strs = ["", "cold", "12345678", "🦀💯", "a longer cold string"];
+        let arcs: Vec<Arc<str>> = strs.iter().map(|&s| Arc::from(s)).collect();
+        let rcs: Vec<Rc<str>> = strs.iter().map(|&s| Rc::from(s)).collect();
+        assert_eq!(arcs.iter().map(ColdString::from).collect::<Vec<_>>(), strs);
+        assert_eq!(rcs.iter().map(ColdString::from).collect::<Vec<_>>(), strs);
+        assert_eq!(
+            arcs.into_iter().map(ColdString::from).collect::<Vec<_>>(),
+            strs
+        );
+        assert_eq!(
+            rcs.into_iter().map(ColdString::from).collect::<Vec<_>>(),
+            strs
+        );
+    }
+
+    #[test]
+    fn test_cow_roundtrip() {
+        fn into_static_cow<T: Into<Cow<'static, str>>>(s: T) -> Cow<'static, str> {
+            s.into()
+        }
+
+        for s in ["", "cold", "12345678", "🦀💯", "a longer cold string"] {
+            let cold = ColdString::from(Cow::Borrowed(s));
+            assert_eq!(cold, s);
+            assert_eq!(ColdString::from(Cow::Owned(String::from(s))), cold);
+
+            let borrowed: Cow<'_, str> = Cow::from(&cold);
+            assert!(matches!(borrowed, Cow::Borrowed(b) if b == s));
+            let owned = into_static_cow(cold.clone());
+            assert!(matches!(&owned, Cow::Owned(o) if o == s));
+            assert_eq!(ColdString::from(owned), cold);
+        }
+    }
+
+    #[test]
+    fn test_eq_smart_pointers() {
+        for s in [
+            "",
+            "cold",
+            "1234567",
+            "12345678",
+            "123456789",
+            "🦀💯",
+            "this is a longer cold string",
+        ] {
+            let cold = ColdString::new(s);
+            assert_eq!(cold, Box::<str>::from(s));
+            assert_eq!(Box::<str>::from(s), cold);
+            assert_eq!(cold, Rc::<str>::from(s));
+            assert_eq!(Rc::<str>::from(s), cold);
+            assert_eq!(cold, Arc::<str>::from(s));
+            assert_eq!(Arc::<str>::from(s), cold);
+
+            let other = alloc::format!("{}?", s);
+            assert_ne!(cold, Box::<str>::from(other.as_str()));
+            assert_ne!(Rc::<str>::from(other.as_str()), cold);
+            assert_ne!(cold, Arc::<str>::from(other.as_str()));
+            assert_ne!(Arc::<str>::from(other.as_str()), cold);
+        }
+    }
+
+    #[test]
+    fn test_eq_bytes() {
+        for s in [
+            "",
+            "a",
+            "cold",
+            "1234567",
+            "12345678",
+            "123456789",
+            "🦀💯",
+            "a longer cold string",
+        ] {
+            let cold = ColdString::new(s);
+            let bytes = s.as_bytes();
+            assert_eq!(cold, *bytes);
+            assert_eq!(*bytes, cold);
+            assert_eq!(cold, bytes);
+            assert_eq!(bytes, cold);
+            if let Some((_, prefix)) = bytes.split_last() {
+                assert_ne!(cold, prefix);
+                assert_ne!(prefix, cold);
+                assert_ne!(cold, *prefix);
+            } else {
+                assert_ne!(cold, &b"x"[..]);
+            }
+            assert_ne!(cold, [bytes, b"!"].concat().as_slice());
+        }
+
+        let width: [u8; WIDTH] = [b'w'; WIDTH];
+        let cold = ColdString::from_utf8(width).unwrap();
+        assert_eq!(cold, width);
+        assert_eq!(width, cold);
+        assert_ne!(cold, [b'w'; WIDTH - 1]);
+        assert_ne!([b'w'; WIDTH + 1], cold);
+        assert_eq!(ColdString::new(""), []);
+        assert_eq!(
+            ColdString::new(str::from_utf8(&EIGHT_NUL).unwrap()),
+            EIGHT_NUL
+        );
+        assert_eq!(ColdString::new("hello"), *b"hello");
+        assert_ne!(ColdString::new("hello"), *b"help!");
+    }
+
+    #[test]
+    fn test_from_char() {
+        for c in [
+            '\0',
+            'a',
+            '~',
+            '\u{7f}',
+            'é',
+            'ƒ',
+            '\u{7ff}',
+            '\u{800}',
+            '€',
+            '\u{ffff}',
+            '🦀',
+            '\u{10ffff}',
+        ] {
+            let mut buf = [0u8; 4];
+            let expected = c.encode_utf8(&mut buf);
+            let cold = ColdString::from_char(c);
+            assert!(cold.is_inline());
+            assert_eq!(cold, *expected);
+            assert_eq!(cold.len(), c.len_utf8());
+            assert_eq!(ColdString::from(c), cold);
+            assert_eq!(ColdString::new(&*expected), cold);
+        }
+    }
+
+    #[test]
+    fn test_into_string() {
+        for s in [
+            "",
+            "cold",
+            "12345678",
+            "123456789",
+            "🦀💯",
+            "a longer cold string",
+        ] {
+            let cold = ColdString::new(s);
+            let string = String::from(&cold);
+            assert_eq!(string, s);
+            assert_eq!(string.capacity(), string.len());
+            let string = String::from(cold.clone());
+            assert_eq!(string, s);
+            assert_eq!(string.capacity(), string.len());
+            let string = cold.into_string();
+            assert_eq!(string, s);
+            assert_eq!(string.capacity(), string.len());
+        }
+    }
+
+    #[test]
+    fn test_into_boxed_str() {
+        let width = str::from_utf8(&[b'w'; WIDTH]).unwrap();
+        let nul = str::from_utf8(&EIGHT_NUL).unwrap();
+        for s in ["", "cold", width, nul, "🦀💯", "a longer cold string"] {
+            let cold = ColdString::new(s);
+            assert_eq!(&*Box::<str>::from(cold.clone()), s);
+            assert_eq!(&*cold.into_boxed_str(), s);
+        }
+    }
+
+    #[test]
+    fn test_fmt_precision_and_debug() {
+        use alloc::format;
+
+        for s in [
+            "",
+            "cold",
+            "12345678",
+            "ƒoo\tbar",
+            "🦀💯 \"quoted\"\n",
+            "a longer cold string",
+        ] {
+            let cold = ColdString::new(s);
+            assert_eq!(format!("{:?}", cold), format!("{:?}", s));
+            assert_eq!(format!("{:#?}", cold), format!("{:#?}", s));
+            assert_eq!(format!("{:?}", Some(&cold)), format!("{:?}", Some(s)));
+            assert_eq!(format!("{:#?}", [&cold]), format!("{:#?}", [s]));
+            assert_eq!(format!("{:12?}", cold), format!("{:12?}", s));
+            for precision in 0..12 {
+                assert_eq!(
+                    format!("{:.*}", precision, cold),
+                    format!("{:.*}", precision, s)
+                );
+                assert_eq!(
+                    format!("{:>10.*}", precision, cold),
+                    format!("{:>10.*}", precision, s)
+                );
+                assert_eq!(
+                    format!("{:_^10.*}", precision, cold),
+                    format!("{:_^10.*}", precision, s)
+                );
+            }
+        }
+        assert_eq!(format!("{:.3}", ColdString::new("🦀💯🦀💯")), "🦀💯🦀");
+        assert_eq!(
+            format!("{:.10}", ColdString::new("truncated string")),
+            "truncated "
+        );
+    }
+
     #[test]
     fn ensure_zero_repr() {
         assert!(str::from_utf8(&ColdString::EIGHT_NUL_MAP.to_ne_bytes()).is_err());
@@ -798,4 +2719,457 @@ mod tests {
             (&EIGHT_NUL) as *const u8
         );
     }
+
+    #[test]
+    fn index_ranges() {
+        for s in ["", "cold", "🦀é", "a string long enough for the heap, 🦀"] {
+            let cold = ColdString::new(s);
+            let len = s.len();
+            for i in (0..=len).filter(|&i| s.is_char_boundary(i)) {
+                assert_eq!(&cold[i..], &s[i..]);
+                assert_eq!(&cold[..i], &s[..i]);
+                for j in (i..=len).filter(|&j| s.is_char_boundary(j)) {
+                    assert_eq!(&cold[i..j], &s[i..j]);
+                    if j > i {
+                        assert_eq!(&cold[i..=j - 1], &s[i..j]);
+                    }
+                    if j > 0 {
+                        assert_eq!(&cold[..=j - 1], &s[..j]);
+                    }
+                }
+            }
+            assert_eq!(&cold[..], s);
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "byte index 1 is not a char boundary")]
+    fn index_inside_char() {
+        let _ = &ColdString::new("🦀")[1..];
+    }
+
+    #[test]
+    #[should_panic(expected = "is not a char boundary")]
+    fn index_inside_char_heap() {
+        let _ = &ColdString::new("a string long enough for the heap 🦀")[..35];
+    }
+
+    #[test]
+    #[should_panic(expected = "out of bounds")]
+    fn index_out_of_bounds() {
+        let _ = &ColdString::new("cold")[..=4];
+    }
+
+    #[test]
+    fn collect_fragments() {
+        let parts = ["", "co", "ld", "", " string", "", " on the heap", "🦀"];
+        for n in 0..=parts.len() {
+            let expected = parts[..n].concat();
+            let cold: ColdString = parts[..n].iter().copied().collect();
+            assert_eq!(cold, expected.as_str());
+            assert_eq!(cold.is_inline(), expected.len() <= WIDTH);
+            let cold: ColdString = parts[..n].iter().map(|s| String::from(*s)).collect();
+            assert_eq!(cold, expected.as_str());
+            let cold: ColdString = parts[..n]
+                .iter()
+                .enumerate()
+                .map(|(i, s)| match i % 2 {
+                    0 => Cow::Borrowed(*s),
+                    _ => Cow::Owned(String::from(*s)),
+                })
+                .collect();
+            assert_eq!(cold, expected.as_str());
+        }
+        // Exactly filling the inline buffer, then crossing it by one byte.
+        let full = "x".repeat(WIDTH);
+        let cold: ColdString = [full.as_str(), ""].iter().copied().collect();
+        assert!(cold.is_inline());
+        let cold: ColdString = [full.as_str(), "y"].iter().copied().collect();
+        assert_eq!(cold.len(), WIDTH + 1);
+        assert!(!cold.is_inline());
+        let cold: ColdString = core::iter::repeat("ab").take(WIDTH).collect();
+        assert_eq!(cold, "ab".repeat(WIDTH).as_str());
+    }
+
+    #[test]
+    fn collect_cold_strings() {
+        let parts: Vec<ColdString> = ["co", "ld", "", " string on the heap", "🦀"]
+            .iter()
+            .map(ColdString::new)
+            .collect();
+        for n in 0..=parts.len() {
+            let expected: String = parts[..n].iter().map(ColdString::as_str).collect();
+            let cold: ColdString = parts[..n].iter().collect();
+            assert_eq!(cold, expected.as_str());
+            assert_eq!(cold.is_inline(), expected.len() <= WIDTH);
+            let cold: ColdString = parts[..n].iter().cloned().collect();
+            assert_eq!(cold, expected.as_str());
+            assert_eq!(parts[..n].iter().collect::<String>(), expected);
+            assert_eq!(parts[..n].iter().cloned().collect::<String>(), expected);
+        }
+        let empty: ColdString = core::iter::empty::<ColdString>().collect();
+        assert_eq!(empty, ColdString::EMPTY);
+        assert!(empty.is_inline());
+    }
+
+    #[test]
+    fn add() {
+        let strs = [
+            "",
+            "a",
+            "cold",
+            "1234567",
+            "a string long enough for the heap",
+        ];
+        for left in strs {
+            for right in strs {
+                let expected = [left, right].concat();
+                let sum = ColdString::new(left) + right;
+                assert_eq!(sum, expected.as_str());
+                assert_eq!(sum.is_inline(), expected.len() <= WIDTH);
+                assert_eq!(ColdString::new(left) + &ColdString::new(right), sum);
+                assert_eq!(&ColdString::new(left) + right, sum);
+            }
+        }
+        let (a, b) = (ColdString::new("usr"), ColdString::new("local"));
+        assert_eq!(&a + "/" + &b + "/" + "bin", "usr/local/bin");
+    }
+
+    #[test]
+    fn iterators() {
+        fn count<I: IntoIterator<Item = char>>(iter: I) -> usize {
+            iter.into_iter().count()
+        }
+
+        for s in ["", "cold", "né🦀", "a string long enough for the heap, 🦀"] {
+            let cold = ColdString::new(s);
+            assert!(cold.chars().eq(s.chars()));
+            assert!(cold.chars().rev().eq(s.chars().rev()));
+            assert!(cold.char_indices().eq(s.char_indices()));
+            assert!(cold.bytes().eq(s.bytes()));
+            assert!((&cold).into_iter().eq(s.chars()));
+            assert_eq!(count(&cold), s.chars().count());
+            let mut chars = Vec::new();
+            for c in &cold {
+                chars.push(c);
+            }
+            assert!(chars.into_iter().eq(s.chars()));
+        }
+    }
+
+    #[test]
+    fn concat() {
+        let parts = ["", "co", "ld", "", " string", "🦀", " on the heap"];
+        for n in 0..=parts.len() {
+            let expected = parts[..n].concat();
+            let cold = ColdString::concat(&parts[..n]);
+            assert_eq!(cold, expected.as_str());
+            assert_eq!(cold.is_inline(), expected.len() <= WIDTH);
+            let owned: Vec<String> = parts[..n].iter().map(|s| String::from(*s)).collect();
+            assert_eq!(ColdString::concat(&owned), expected.as_str());
+            assert_eq!(ColdString::concat(owned), expected.as_str());
+        }
+        assert_eq!(
+            ColdString::concat(core::iter::empty::<&str>()),
+            ColdString::EMPTY
+        );
+        let full = "x".repeat(WIDTH);
+        let cold = ColdString::concat([&full[..1], &full[1..]].iter());
+        assert!(cold.is_inline());
+        assert_eq!(cold, ColdString::new(&full));
+        assert_eq!(ColdString::concat(["🦀", "!"].iter()).len(), 5);
+    }
+
+    #[test]
+    fn join() {
+        let parts = ["", "co", "ld", "", "string", "🦀", "on the heap"];
+        for sep in ["", "-", ", ", "🦀", "a long separator"] {
+            for n in 0..=parts.len() {
+                let expected = parts[..n].join(sep);
+                let cold = ColdString::join(sep, &parts[..n]);
+                assert_eq!(cold, expected.as_str());
+                assert_eq!(cold.is_inline(), expected.len() <= WIDTH);
+                let owned: Vec<String> = parts[..n].iter().map(|s| String::from(*s)).collect();
+                assert_eq!(ColdString::join(sep, owned), expected.as_str());
+            }
+        }
+        assert_eq!(ColdString::join("-", ["x"].iter()), "x");
+        assert_eq!(ColdString::join("-", ["", ""].iter()), "-");
+    }
+
+    /// A part that is measured as 20 bytes, then written as 10.
+    struct Shrinking(core::cell::Cell<bool>);
+
+    impl AsRef<str> for Shrinking {
+        fn as_ref(&self) -> &str {
+            let s = "01234567890123456789";
+            match self.0.replace(true) {
+                false => s,
+                true => &s[..10],
+            }
+        }
+    }
+
+    #[test]
+    fn parts_shorter_the_second_time() {
+        use core::cell::Cell;
+
+        let shrinking = || [Shrinking(Cell::new(false)), Shrinking(Cell::new(false))];
+        let expected = "0123456789".repeat(2) + &"\0".repeat(20);
+        assert_eq!(ColdString::concat(&shrinking()), expected.as_str());
+        let parts = shrinking();
+        assert_eq!(
+            ColdString::join("-", &parts),
+            "0123456789-0123456789\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0"
+        );
+        // The first part is measured once more, after it no longer fits inline.
+        assert_eq!(
+            ColdString::collect_parts(shrinking()),
+            &expected[..expected.len() - 10]
+        );
+    }
+
+    #[test]
+    fn repeat() {
+        for s in [
+            "",
+            "a",
+            "ab",
+            "é",
+            "🦀",
+            "abc🦀",
+            "a string long enough for the heap",
+        ] {
+            let cold = ColdString::new(s);
+            for n in [0, 1, 2, 3, 4, 7, 8, 9, 33] {
+                let expected = s.repeat(n);
+                let repeated = cold.repeat(n);
+                assert_eq!(repeated, expected.as_str());
+                assert_eq!(repeated.is_inline(), expected.len() <= WIDTH);
+            }
+        }
+        assert_eq!(ColdString::EMPTY.repeat(usize::MAX), ColdString::EMPTY);
+    }
+
+    #[test]
+    #[should_panic(expected = "capacity overflow")]
+    fn repeat_overflow() {
+        ColdString::new("ab").repeat(usize::MAX / 2 + 1);
+    }
+
+    #[test]
+    fn leak() {
+        /// Frees a leaked string to keep Miri's leak checker quiet.
+        unsafe fn reclaim(s: &'static str) {
+            if s.len() <= WIDTH {
+                drop(Box::from_raw(s as *const str as *mut str));
+            } else {
+                let header = Header::write(s.len()).unwrap().0;
+                drop(ColdString::from_heap_ptr(s.as_ptr().sub(header) as *mut u8));
+            }
+        }
+
+        for s in [
+            "",
+            "cold",
+            "12345678",
+            "🦀🦀",
+            "a string that is long enough for the heap",
+        ] {
+            let cold = ColdString::new(s);
+            let ptr = cold.as_str().as_ptr();
+            let leaked = cold.leak();
+            assert_eq!(leaked, s);
+            assert_eq!(leaked.as_ptr() == ptr, s.len() > WIDTH);
+            // The string is still readable after other allocations.
+            let other = ColdString::new(alloc::format!("{leaked} and more"));
+            assert_eq!(leaked, s);
+            drop(other);
+            unsafe { reclaim(leaked) };
+        }
+
+        static LONG: StaticStr = StaticStr::new("a static string that is never copied");
+        let leaked = ColdString::from_static(&LONG).leak();
+        assert_eq!(leaked.as_ptr(), LONG.as_str().as_ptr());
+    }
+
+    #[test]
+    fn slice() {
+        let s = "🦀 a string long enough for the heap";
+        let cold = ColdString::new(s);
+        for (start, end) in [
+            (0, 0),
+            (0, 4),
+            (4, 12),
+            (5, 5 + WIDTH),
+            (4, s.len()),
+            (0, s.len()),
+        ] {
+            let slice = cold.slice(start..end);
+            assert_eq!(slice, &s[start..end]);
+            assert_eq!(slice.is_inline(), end - start <= WIDTH);
+            assert_eq!(cold.try_slice(start..end), Some(slice));
+        }
+        assert_eq!(cold.slice(..), cold);
+        assert_eq!(cold.slice(4..=5), " a");
+        assert_eq!(cold.try_slice(1..), None);
+        assert_eq!(cold.try_slice(..s.len() + 1), None);
+        assert_eq!(cold.try_slice(..=usize::MAX), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "byte index 1 is not a char boundary")]
+    fn slice_off_char_boundary() {
+        ColdString::new("🦀 a string long enough for the heap").slice(1..);
+    }
+
+    #[test]
+    fn split_at_cold() {
+        let s = "🦀 a string long enough for the heap";
+        let cold = ColdString::new(s);
+        for mid in (0..=s.len()).filter(|&mid| s.is_char_boundary(mid)) {
+            let (left, right) = cold.split_at_cold(mid);
+            assert_eq!((left.as_str(), right.as_str()), s.split_at(mid));
+            assert_eq!(left.is_inline(), mid <= WIDTH);
+            assert_eq!(right.is_inline(), s.len() - mid <= WIDTH);
+            assert_eq!(cold.try_split_at_cold(mid), Some((left, right)));
+        }
+        for mid in [1, 2, 3, s.len() + 1, usize::MAX] {
+            assert_eq!(cold.try_split_at_cold(mid), None);
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn split_at_cold_off_char_boundary() {
+        ColdString::new("🦀 a string long enough for the heap").split_at_cold(2);
+    }
+
+    #[test]
+    fn trim() {
+        for s in [
+            "",
+            " ",
+            "cold",
+            "  cold  ",
+            "\u{3000}\u{a0}cold\u{2029}",
+            "xxcoldxx",
+            "   a field long enough for the heap   ",
+            "a field long enough for the heap",
+        ] {
+            let cold = ColdString::new(s);
+            for (trimmed, expected) in [
+                (cold.trimmed(), s.trim()),
+                (cold.trim_start_cold(), s.trim_start()),
+                (cold.trim_end_cold(), s.trim_end()),
+                (cold.trim_matches_cold('x'), s.trim_matches('x')),
+            ] {
+                assert_eq!(trimmed, expected);
+                assert_eq!(trimmed.is_inline(), expected.len() <= WIDTH);
+            }
+        }
+    }
+
+    #[test]
+    fn reversed() {
+        for s in [
+            "",
+            "a",
+            "cold",
+            "12345678",
+            "é🦀",
+            "e\u{301}a\u{308}",
+            "a string long enough for the heap, with 🦀 and ünïcödé",
+        ] {
+            let reversed = ColdString::new(s).reversed();
+            let expected: String = s.chars().rev().collect();
+            assert_eq!(reversed, expected.as_str());
+            assert_eq!(reversed.is_inline(), s.len() <= WIDTH);
+            assert_eq!(reversed.reversed(), s);
+        }
+    }
+
+    #[test]
+    fn modify() {
+        let mut cold = ColdString::new("cold");
+        cold.modify(|s| {
+            assert_eq!(s.capacity(), 4);
+            s.push_str(" and now long enough for the heap");
+        });
+        assert_eq!(cold, "cold and now long enough for the heap");
+        assert!(!cold.is_inline());
+        cold.modify(|s| s.retain(|c| c != ' '));
+        assert_eq!(cold, "coldandnowlongenoughfortheheap");
+        cold.modify(|s| s.truncate(WIDTH));
+        assert_eq!(cold, "coldandn");
+        assert!(cold.is_inline());
+        cold.modify(String::clear);
+        assert_eq!(cold, ColdString::EMPTY);
+
+        let mapped = ColdString::new("a string on the heap").map_str(|s| s.replace(' ', ""));
+        assert_eq!(mapped, "astringontheheap");
+    }
+
+    #[test]
+    fn modify_panic_keeps_old_value() {
+        use std::panic::{catch_unwind, AssertUnwindSafe};
+
+        for old in ["cold", "a string long enough for the heap"] {
+            let mut cold = ColdString::new(old);
+            let result = catch_unwind(AssertUnwindSafe(|| {
+                cold.modify(|s| {
+                    s.push_str(" edited");
+                    panic!("edit failed");
+                })
+            }));
+            assert!(result.is_err());
+            assert_eq!(cold, old);
+        }
+    }
+
+    #[test]
+    fn clone_from() {
+        static LONG: StaticStr = StaticStr::new("a static string long enough for the heap");
+        let strs = [
+            ColdString::new(""),
+            ColdString::new("cold"),
+            ColdString::new("12345678"),
+            ColdString::new("a string long enough for the heap"),
+            ColdString::new("another string, long enough for the heap"),
+            ColdString::new("a different string long enough for heap"),
+            ColdString::from_static(&LONG),
+        ];
+        for target in &strs {
+            for source in &strs {
+                let mut s = target.clone();
+                let block = s.as_ptr();
+                s.clone_from(source);
+                assert_eq!(s, *source);
+                assert_eq!(s.is_inline(), source.is_inline());
+                assert_eq!(s.is_heap_allocated(), source.is_heap_allocated());
+                if target.is_heap_allocated()
+                    && source.is_heap_allocated()
+                    && target.len() == source.len()
+                {
+                    assert_eq!(s.as_ptr(), block);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn eq_words_every_position() {
+        for len in 0..=4 * WIDTH + 1 {
+            let a: Vec<u8> = (0..len as u8).collect();
+            assert!(eq_words(&a, &a.clone()));
+            for i in 0..len {
+                let mut b = a.clone();
+                b[i] ^= 0x80;
+                assert!(!eq_words(&a, &b));
+            }
+            if len > 0 {
+                assert!(!eq_words(&a, &a[..len - 1]));
+            }
+        }
+    }
 }
@@ -0,0 +1,80 @@
+#![cfg_attr(docsrs, doc(cfg(feature = "log-kv")))]
+
+use crate::ColdString;
+
+use log::kv::{ToValue, Value};
+
+impl ToValue for ColdString {
+    #[inline]
+    fn to_value(&self) -> Value<'_> {
+        Value::from(self.as_str())
+    }
+}
+
+impl<'v> From<&'v ColdString> for Value<'v> {
+    #[inline]
+    fn from(s: &'v ColdString) -> Self {
+        Value::from(s.as_str())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+
+    use super::*;
+    use alloc::{string::String, vec::Vec};
+    use log::{
+        kv::{self, Key, VisitSource},
+        Log, Metadata, Record,
+    };
+    use std::sync::Mutex;
+
+    struct CaptureLogger {
+        captured: Mutex<Vec<(String, Option<String>)>>,
+    }
+
+    struct Capture<'a>(&'a Mutex<Vec<(String, Option<String>)>>);
+
+    impl<'kvs> VisitSource<'kvs> for Capture<'_> {
+        fn visit_pair(&mut self, key: Key<'kvs>, value: Value<'kvs>) -> Result<(), kv::Error> {
+            let value = value.to_borrowed_str().map(String::from);
+            self.0.lock().unwrap().push((key.as_str().into(), value));
+            Ok(())
+        }
+    }
+
+    impl Log for CaptureLogger {
+        fn enabled(&self, _: &Metadata<'_>) -> bool {
+            true
+        }
+
+        fn log(&self, record: &Record<'_>) {
+            record
+                .key_values()
+                .visit(&mut Capture(&self.captured))
+                .unwrap();
+        }
+
+        fn flush(&self) {}
+    }
+
+    #[test]
+    fn log_kv_borrows_str() {
+        log::set_max_level(log::LevelFilter::Trace);
+        for s in ["", "cold", "12345678", "this is a longer cold string"] {
+            let logger = CaptureLogger {
+                captured: Mutex::new(Vec::new()),
+            };
+            let cold = ColdString::new(s);
+            log::info!(logger: &logger, key = cold; "msg");
+            let value: Value<'_> = (&cold).into();
+            assert_eq!(value.to_borrowed_str(), Some(s));
+
+            let captured = logger.captured.into_inner().unwrap();
+            assert_eq!(captured.len(), 1);
+            assert_eq!(captured[0].0, "key");
+            assert_eq!(captured[0].1.as_deref(), Some(s));
+        }
+    }
+}
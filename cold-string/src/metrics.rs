@@ -0,0 +1,68 @@
+#![cfg_attr(docsrs, doc(cfg(feature = "metrics")))]
+
+use crate::ColdString;
+
+use alloc::string::String;
+use metrics::SharedString;
+
+impl From<ColdString> for SharedString {
+    #[inline]
+    fn from(s: ColdString) -> Self {
+        SharedString::from_owned(String::from(s.as_str()))
+    }
+}
+
+/// Lets a `&ColdString` be used wherever `metrics` takes an `Into<SharedString>`: as a label
+/// value in the macros, in [`Label::new`](metrics::Label::new) and the tuple conversions to
+/// `Label`, and as a [`KeyName`](metrics::KeyName). Those are all generic over
+/// `Into<SharedString>`, so they need no impls of their own.
+impl From<&ColdString> for SharedString {
+    #[inline]
+    fn from(s: &ColdString) -> Self {
+        SharedString::from_owned(String::from(s.as_str()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec::Vec;
+    use metrics::{counter, with_local_recorder, KeyName, Label};
+    use metrics_util::debugging::DebuggingRecorder;
+
+    #[test]
+    fn metrics_labels() {
+        for s in ["", "acme", "12345678", "this is a longer cold string"] {
+            let recorder = DebuggingRecorder::new();
+            let snapshotter = recorder.snapshotter();
+            let cold = ColdString::new(s);
+            with_local_recorder(&recorder, || {
+                counter!("owned", "tenant" => cold.clone()).increment(1);
+                counter!("borrowed", "tenant" => &cold).increment(1);
+            });
+
+            let snapshot = snapshotter.snapshot().into_vec();
+            assert_eq!(snapshot.len(), 2);
+            for (key, _, _, _) in snapshot {
+                let labels: Vec<&Label> = key.key().labels().collect();
+                assert_eq!(labels.len(), 1);
+                assert_eq!(labels[0].key(), "tenant");
+                assert_eq!(labels[0].value(), s);
+            }
+        }
+    }
+
+    #[test]
+    fn metrics_label_helpers() {
+        let cold = ColdString::new("this is a longer cold string");
+        for label in [
+            Label::new("tenant", &cold),
+            Label::new(&cold, cold.clone()),
+            Label::from(&("tenant", cold.clone())),
+            Label::from((&"tenant", &cold)),
+        ] {
+            assert_eq!(label.value(), cold);
+        }
+        assert_eq!(KeyName::from(&cold).as_str(), cold);
+    }
+}
@@ -0,0 +1,161 @@
+//! Natural ordering, in which runs of ASCII digits compare by their value, so `"file2"` sorts
+//! before `"file10"`.
+
+use crate::ColdString;
+
+use core::cmp::Ordering;
+
+/// Returns the end of the leading zeros and the end of the run of ASCII digits at `start`.
+#[inline]
+fn digit_run(s: &[u8], start: usize) -> (usize, usize) {
+    let zeros = start + s[start..].iter().take_while(|&&b| b == b'0').count();
+    let end = zeros + s[zeros..].iter().take_while(|b| b.is_ascii_digit()).count();
+    (zeros, end)
+}
+
+fn cmp_natural(a: &[u8], b: &[u8]) -> Ordering {
+    let (mut i, mut j) = (0, 0);
+    // The first difference in leading zeros, which only decides between equal values.
+    let mut tie = Ordering::Equal;
+    while i < a.len() && j < b.len() {
+        if a[i].is_ascii_digit() && b[j].is_ascii_digit() {
+            let ((a_zeros, a_end), (b_zeros, b_end)) = (digit_run(a, i), digit_run(b, j));
+            // Without leading zeros, a longer run is a greater value.
+            let value = (a_end - a_zeros)
+                .cmp(&(b_end - b_zeros))
+                .then_with(|| a[a_zeros..a_end].cmp(&b[b_zeros..b_end]));
+            if value != Ordering::Equal {
+                return value;
+            }
+            if tie == Ordering::Equal {
+                tie = (a_zeros - i).cmp(&(b_zeros - j));
+            }
+            i = a_end;
+            j = b_end;
+        } else if a[i] == b[j] {
+            i += 1;
+            j += 1;
+        } else {
+            return a[i].cmp(&b[j]);
+        }
+    }
+    (a.len() - i).cmp(&(b.len() - j)).then(tie)
+}
+
+impl ColdString {
+    /// Compares this string with `other` in natural order, without allocating.
+    ///
+    /// Runs of ASCII digits compare by their value, however long they are, and other bytes
+    /// compare as in [`str::cmp`]. Between strings that only differ in the leading zeros of
+    /// their numbers, the first number with fewer leading zeros sorts first, so this is
+    /// [`Ordering::Equal`] only if the strings are equal.
+    ///
+    /// # Examples
+    /// ```
+    /// use cold_string::ColdString;
+    /// use core::cmp::Ordering;
+    ///
+    /// let s = ColdString::new("file2.txt");
+    /// assert_eq!(s.cmp_natural("file10.txt"), Ordering::Less);
+    /// assert_eq!(s.cmp_natural("file02.txt"), Ordering::Less);
+    /// assert_eq!(s.cmp_natural("file1.txt"), Ordering::Greater);
+    /// ```
+    #[inline]
+    pub fn cmp_natural(&self, other: &str) -> Ordering {
+        cmp_natural(self.as_bytes(), other.as_bytes())
+    }
+
+    /// Compares this string with another [`ColdString`] in natural order, like
+    /// [`ColdString::cmp_natural`].
+    ///
+    /// # Examples
+    /// ```
+    /// use cold_string::ColdString;
+    /// use core::cmp::Ordering;
+    ///
+    /// let a = ColdString::new("v1.9");
+    /// assert_eq!(a.cmp_natural_cold(&ColdString::new("v1.10")), Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn cmp_natural_cold(&self, other: &ColdString) -> Ordering {
+        cmp_natural(self.as_bytes(), other.as_bytes())
+    }
+}
+
+/// Sorts `slice` in natural order, as defined by [`ColdString::cmp_natural`].
+///
+/// Only equal strings compare equal, so the sort does not need to be stable and does not
+/// allocate.
+///
+/// # Examples
+/// ```
+/// use cold_string::ColdString;
+///
+/// let mut files = ["file10", "file2", "file1", "file02"].map(ColdString::new);
+/// cold_string::sort_natural(&mut files);
+/// assert_eq!(files, ["file1", "file2", "file02", "file10"]);
+/// ```
+pub fn sort_natural(slice: &mut [ColdString]) {
+    slice.sort_unstable_by(ColdString::cmp_natural_cold);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use alloc::vec::Vec;
+
+    fn assert_sorted(sorted: &[&str]) {
+        for (i, a) in sorted.iter().enumerate() {
+            let cold = ColdString::new(a);
+            for (j, b) in sorted.iter().enumerate() {
+                assert_eq!(cold.cmp_natural(b), i.cmp(&j), "{:?} {:?}", a, b);
+                assert_eq!(cold.cmp_natural_cold(&ColdString::new(b)), i.cmp(&j));
+            }
+        }
+        let mut shuffled: Vec<ColdString> = sorted.iter().rev().map(ColdString::new).collect();
+        shuffled.rotate_left(sorted.len() / 3);
+        sort_natural(&mut shuffled);
+        assert_eq!(shuffled, sorted);
+    }
+
+    #[test]
+    fn numbers_by_value() {
+        assert_sorted(&["", "0", "1", "2", "9", "10", "11", "99", "100", "a"]);
+        assert_sorted(&["a", "a2", "a10", "a10b", "a10b2", "a10b10", "ab"]);
+        assert_sorted(&[
+            "file1.txt",
+            "file1.txt1",
+            "file2.txt",
+            "file10.txt",
+            "file10a",
+        ]);
+        assert_sorted(&["x 2", "x-1", "x1", "x2"]);
+    }
+
+    #[test]
+    fn leading_zeros() {
+        assert_sorted(&["0", "00", "000", "1", "01", "001", "2"]);
+        assert_sorted(&["a1b1", "a01b1", "a1b2", "a1b02", "a01b2", "a2"]);
+        assert_sorted(&["v1", "v01.0", "v1.1", "v1.01", "v01.1", "v1.2"]);
+    }
+
+    #[test]
+    fn runs_longer_than_u64() {
+        assert_sorted(&[
+            "id18446744073709551615",
+            "id18446744073709551616",
+            "id0000000000000000000000000000000000000000000000000000001000000000000000000000",
+            "id99999999999999999999999999999999999999999",
+            "id100000000000000000000000000000000000000000",
+            "id0100000000000000000000000000000000000000000",
+        ]);
+    }
+
+    #[test]
+    fn non_ascii() {
+        assert_sorted(&["é1", "é2", "é10", "é٣", "🦀"]);
+        // Non-ASCII digits are not numbers.
+        assert_eq!(ColdString::new("٢").cmp_natural("١٠"), Ordering::Greater);
+    }
+}
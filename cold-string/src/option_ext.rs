@@ -0,0 +1,144 @@
+//! Helpers for `Option<ColdString>` fields where `None` and `""` mean the same thing.
+//!
+//! # Examples
+//! ```
+//! use cold_string::{option_ext::OptionColdStringExt, ColdString};
+//!
+//! let name: Option<ColdString> = None;
+//! assert_eq!(name.as_str_or_empty(), "");
+//! assert_eq!(Some(ColdString::new("")).none_if_empty(), None);
+//! ```
+
+use crate::ColdString;
+
+impl ColdString {
+    /// Returns `None` if this string is empty, or `Some(self)` otherwise.
+    ///
+    /// # Examples
+    /// ```
+    /// use cold_string::ColdString;
+    ///
+    /// assert_eq!(ColdString::new("").none_if_empty(), None);
+    /// assert_eq!(ColdString::new("cold").none_if_empty().unwrap(), "cold");
+    /// ```
+    #[inline]
+    pub fn none_if_empty(self) -> Option<ColdString> {
+        if self.is_empty() {
+            None
+        } else {
+            Some(self)
+        }
+    }
+
+    /// Creates a new [`ColdString`] from an optional value, using the empty string for `None`.
+    ///
+    /// # Examples
+    /// ```
+    /// use cold_string::ColdString;
+    ///
+    /// assert_eq!(ColdString::from_opt(Some("cold")), "cold");
+    /// assert_eq!(ColdString::from_opt(None::<String>), "");
+    /// ```
+    #[inline]
+    pub fn from_opt<T: Into<ColdString>>(opt: Option<T>) -> Self {
+        opt.map_or_else(ColdString::default, Into::into)
+    }
+}
+
+/// Returns the string in `opt`, or the empty string if it is `None`.
+///
+/// # Examples
+/// ```
+/// use cold_string::{or_empty, ColdString};
+///
+/// assert_eq!(or_empty(Some(ColdString::new("cold"))), "cold");
+/// assert_eq!(or_empty(None), "");
+/// ```
+#[inline]
+pub fn or_empty(opt: Option<ColdString>) -> ColdString {
+    opt.unwrap_or_default()
+}
+
+/// Extension methods for `Option<ColdString>` that treat `None` and the empty string alike.
+pub trait OptionColdStringExt {
+    /// Returns the string, or the empty string if it is `None`.
+    fn unwrap_or_empty(self) -> ColdString;
+
+    /// Maps `Some("")` to `None`, so there is only one way to represent "no string".
+    fn none_if_empty(self) -> Option<ColdString>;
+
+    /// Returns the string slice, or `""` if it is `None`.
+    fn as_str_or_empty(&self) -> &str;
+
+    /// Returns `true` if it is `None` or the empty string.
+    fn is_none_or_empty(&self) -> bool;
+}
+
+impl OptionColdStringExt for Option<ColdString> {
+    #[inline]
+    fn unwrap_or_empty(self) -> ColdString {
+        or_empty(self)
+    }
+
+    #[inline]
+    fn none_if_empty(self) -> Option<ColdString> {
+        self.and_then(ColdString::none_if_empty)
+    }
+
+    #[inline]
+    fn as_str_or_empty(&self) -> &str {
+        self.as_ref().map_or("", ColdString::as_str)
+    }
+
+    #[inline]
+    fn is_none_or_empty(&self) -> bool {
+        self.as_ref().map_or(true, ColdString::is_empty)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::string::String;
+
+    #[test]
+    fn option_helpers() {
+        let empty: Option<ColdString> = Some(ColdString::default());
+        let none: Option<ColdString> = None;
+        for opt in [empty, none] {
+            assert!(opt.is_none_or_empty());
+            assert_eq!(opt.as_str_or_empty(), "");
+            assert_eq!(opt.clone().none_if_empty(), None);
+            assert_eq!(opt.clone().unwrap_or_empty(), ColdString::default());
+            assert_eq!(or_empty(opt), "");
+        }
+        for s in ["cold", "a longer cold string"] {
+            let opt = Some(ColdString::new(s));
+            assert!(!opt.is_none_or_empty());
+            assert_eq!(opt.as_str_or_empty(), s);
+            assert_eq!(opt.clone().none_if_empty(), opt);
+            assert_eq!(opt.clone().unwrap_or_empty(), s);
+            assert_eq!(ColdString::new(s).none_if_empty(), opt);
+        }
+    }
+
+    #[test]
+    fn from_opt() {
+        assert_eq!(ColdString::from_opt(None::<&str>), ColdString::default());
+        assert_eq!(ColdString::from_opt(Some("")), ColdString::default());
+        assert_eq!(ColdString::from_opt(Some(String::from("owned"))), "owned");
+        assert_eq!(ColdString::from_opt(Some('c')), "c");
+        assert_eq!(
+            ColdString::from_opt(Some("cold")).none_if_empty().unwrap(),
+            "cold"
+        );
+    }
+
+    #[test]
+    fn empty_is_default() {
+        assert_eq!(ColdString::EMPTY, ColdString::default());
+        assert!(ColdString::EMPTY.is_empty());
+        assert!(ColdString::EMPTY.is_inline());
+        assert_eq!(ColdString::EMPTY.none_if_empty(), None);
+    }
+}
@@ -0,0 +1,161 @@
+#![cfg_attr(docsrs, doc(cfg(feature = "std")))]
+
+use crate::ColdString;
+
+use core::{convert::TryFrom, fmt};
+use std::{
+    ffi::{OsStr, OsString},
+    path::Path,
+};
+
+/// Borrows the string as a [`Path`] without allocating, so a [`ColdString`] can be passed
+/// straight to `File::open`, `Path::join`, `PathBuf::from`, etc.
+impl AsRef<Path> for ColdString {
+    #[inline]
+    fn as_ref(&self) -> &Path {
+        Path::new(self.as_str())
+    }
+}
+
+/// Borrows the string as an [`OsStr`] without allocating.
+impl AsRef<OsStr> for ColdString {
+    #[inline]
+    fn as_ref(&self) -> &OsStr {
+        OsStr::new(self.as_str())
+    }
+}
+
+/// The error returned when converting an [`OsStr`] or [`Path`] that isn't valid UTF-8 into a
+/// [`ColdString`]. It holds a copy of the original data.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct NonUtf8Error {
+    inner: OsString,
+}
+
+impl NonUtf8Error {
+    /// Returns the data that couldn't be converted.
+    pub fn as_os_str(&self) -> &OsStr {
+        &self.inner
+    }
+
+    /// Returns the data that couldn't be converted, consuming the error.
+    pub fn into_os_string(self) -> OsString {
+        self.inner
+    }
+}
+
+impl fmt::Display for NonUtf8Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid UTF-8 in {:?}", self.inner)
+    }
+}
+
+impl std::error::Error for NonUtf8Error {}
+
+/// Copies the string into a [`ColdString`] if it is valid UTF-8.
+impl TryFrom<&OsStr> for ColdString {
+    type Error = NonUtf8Error;
+
+    #[inline]
+    fn try_from(s: &OsStr) -> Result<Self, Self::Error> {
+        match s.to_str() {
+            Some(s) => Ok(ColdString::new(s)),
+            None => Err(NonUtf8Error {
+                inner: s.to_os_string(),
+            }),
+        }
+    }
+}
+
+/// Copies the path into a [`ColdString`] if it is valid UTF-8.
+impl TryFrom<&Path> for ColdString {
+    type Error = NonUtf8Error;
+
+    #[inline]
+    fn try_from(p: &Path) -> Result<Self, Self::Error> {
+        ColdString::try_from(p.as_os_str())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::{fs, io::Read, path::PathBuf, string::ToString};
+
+    #[test]
+    fn try_from_os_str_and_path() {
+        for s in [
+            "",
+            "a.txt",
+            "12345678",
+            "some/longer/nested/path.rs",
+            "🦀/💯",
+        ] {
+            let cold = ColdString::try_from(OsStr::new(s)).unwrap();
+            assert_eq!(cold, s);
+            assert_eq!(cold.is_inline(), s.len() <= core::mem::size_of::<usize>());
+            assert_eq!(ColdString::try_from(Path::new(s)).unwrap(), s);
+        }
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn try_from_non_utf8() {
+        use std::os::unix::ffi::OsStrExt;
+
+        for bytes in [
+            &b"\xff"[..],
+            b"dir/na\xefve",
+            b"a much longer file name \xc3(",
+        ] {
+            let os_str = OsStr::from_bytes(bytes);
+            let err = ColdString::try_from(os_str).unwrap_err();
+            assert_eq!(err.as_os_str(), os_str);
+            assert_eq!(ColdString::try_from(Path::new(os_str)).unwrap_err(), err);
+            assert_eq!(err.into_os_string(), os_str);
+        }
+    }
+
+    #[test]
+    fn path_and_os_str() {
+        for s in [
+            "",
+            "a.txt",
+            "12345678",
+            "some/longer/nested/path.rs",
+            "🦀/💯",
+        ] {
+            let cold = ColdString::new(s);
+            let path: &Path = cold.as_ref();
+            assert_eq!(path, Path::new(s));
+            let os_str: &OsStr = cold.as_ref();
+            assert_eq!(os_str, OsStr::new(s));
+            assert_eq!(PathBuf::from(&cold), PathBuf::from(s));
+            assert_eq!(Path::new("root").join(&cold), Path::new("root").join(s));
+        }
+    }
+
+    #[test]
+    fn open_file() {
+        let dir = std::env::temp_dir().join(std::format!("cold-string-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        for name in ["a.txt", "a file name long enough for the heap.txt"] {
+            let path = ColdString::new(dir.join(name).to_str().unwrap());
+            fs::write(&path, name).unwrap();
+            let mut contents = std::string::String::new();
+            fs::File::open(&path)
+                .unwrap()
+                .read_to_string(&mut contents)
+                .unwrap();
+            assert_eq!(contents, name);
+
+            let name = ColdString::new(name);
+            assert!(dir.join(&name).exists());
+            assert_eq!(
+                fs::read_to_string(dir.join(&name)).unwrap(),
+                name.to_string()
+            );
+        }
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
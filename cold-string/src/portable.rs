@@ -0,0 +1,424 @@
+//! An endian-independent form of the [`ColdString`] word, for persisting handles next to a
+//! blob that holds their heap blocks.
+//!
+//! Inline strings are stored as their bytes, which are already laid out the same on every
+//! target. Heap strings are stored as the offset of their heap block from a caller-provided
+//! base pointer instead of an absolute address, so the blob can be copied or mapped at a
+//! different address and the handles rebased onto it.
+//!
+//! A heap block is the string's length as a LEB128 varint followed by its UTF-8 bytes, and
+//! must start at a multiple of 4 bytes from a 4-byte aligned base. With the `fixed-header`
+//! feature, the length is instead a little-endian `u32` holding `len << 1 | 1`. With the
+//! `nul-terminated` feature, the bytes must be followed by a NUL byte.
+
+#[rustversion::before(1.84)]
+use sptr::Strict;
+
+use crate::{header::Header, ColdString, HEAP_ALIGN, NUL_PAD, WIDTH};
+
+use core::{fmt, marker::PhantomData, mem::ManuallyDrop, ops::Deref, str};
+
+const PORTABLE_PTR_TAG: u8 = 0b1000_0000;
+
+/// The error returned by [`ColdString::validate_portable_bits`] for bits that don't describe a
+/// valid string.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct InvalidBits {
+    _private: (),
+}
+
+impl fmt::Display for InvalidBits {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("invalid portable ColdString bits")
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for InvalidBits {}
+
+const INVALID: InvalidBits = InvalidBits { _private: () };
+
+/// A [`ColdString`] recreated by [`ColdString::from_portable_bits`], whose heap block is in a
+/// blob borrowed for `'blob`.
+///
+/// It derefs to the string, but can't be mutated or dropped as one, since the block belongs to
+/// the blob rather than the global allocator. [`ColdString::clone`] makes an independently
+/// owned copy.
+///
+/// A `PortableRef` can't outlive its blob:
+/// ```compile_fail
+/// use cold_string::ColdString;
+///
+/// let s = {
+///     let blob = vec![0u8; 4];
+///     let bits = ColdString::new("cold").to_portable_bits(core::ptr::null());
+///     unsafe { ColdString::from_portable_bits(bits, &blob) }
+/// };
+/// assert_eq!(*s, "cold");
+/// ```
+#[repr(transparent)]
+pub struct PortableRef<'blob> {
+    /// Either inline, or a heap string whose block is in the blob, so it is never dropped.
+    inner: ManuallyDrop<ColdString>,
+    blob: PhantomData<&'blob [u8]>,
+}
+
+impl Deref for PortableRef<'_> {
+    type Target = ColdString;
+
+    #[inline]
+    fn deref(&self) -> &ColdString {
+        &self.inner
+    }
+}
+
+impl AsRef<str> for PortableRef<'_> {
+    #[inline]
+    fn as_ref(&self) -> &str {
+        self.inner.as_str()
+    }
+}
+
+impl Clone for PortableRef<'_> {
+    /// Copies the handle. The heap block stays in the blob.
+    #[inline]
+    fn clone(&self) -> Self {
+        Self {
+            inner: ManuallyDrop::new(ColdString {
+                encoded: self.inner.encoded,
+            }),
+            blob: PhantomData,
+        }
+    }
+}
+
+impl fmt::Debug for PortableRef<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(self.inner.as_str(), f)
+    }
+}
+
+impl ColdString {
+    /// Returns the portable form of this string's handle. Heap strings are stored as the offset
+    /// of their heap block from `base`. See the [`portable`](crate::portable) module.
+    ///
+    /// # Panics
+    /// Panics if this is a heap string whose block is before `base`, isn't a multiple of 4
+    /// bytes after it, or is too far from it to be encoded, or if this string borrows a
+    /// [`StaticStr`](crate::StaticStr).
+    ///
+    /// # Examples
+    /// ```
+    /// use cold_string::ColdString;
+    ///
+    /// let s = ColdString::new("cold");
+    /// let bits = s.to_portable_bits(core::ptr::null());
+    /// assert_eq!(ColdString::validate_portable_bits(bits, &[]), Ok(()));
+    /// let s2 = unsafe { ColdString::from_portable_bits(bits, &[]) };
+    /// assert_eq!(*s2, s);
+    /// ```
+    pub fn to_portable_bits(&self, base: *const u8) -> [u8; WIDTH] {
+        if self.is_inline() {
+            return self.addr().to_ne_bytes();
+        }
+        assert!(!self.is_static(), "static strings have no heap block");
+        // `addr` comes from `sptr::Strict` before 1.84.
+        #[allow(clippy::incompatible_msrv)]
+        let offset = self
+            .heap_ptr()
+            .addr()
+            .checked_sub(base.addr())
+            .expect("heap block is before `base`");
+        assert!(
+            offset % HEAP_ALIGN == 0,
+            "heap block is misaligned from `base`"
+        );
+        let offset = (offset / HEAP_ALIGN) as u64;
+        assert!(
+            offset >> (8 * (WIDTH - 1)) == 0,
+            "heap block is too far from `base`"
+        );
+        let mut bits = [0u8; WIDTH];
+        bits[0] = PORTABLE_PTR_TAG;
+        bits[1..].copy_from_slice(&offset.to_le_bytes()[..WIDTH - 1]);
+        bits
+    }
+
+    /// Recreates a handle from its portable form, with heap blocks relative to the start of
+    /// `blob`.
+    ///
+    /// The heap block belongs to `blob` rather than the global allocator, so the result is a
+    /// [`PortableRef`] that borrows it. Clones of the string it derefs to are ordinary,
+    /// independently owned [`ColdString`]s.
+    ///
+    /// # Safety
+    /// [`ColdString::validate_portable_bits`] must succeed for `bits` and `blob`.
+    pub unsafe fn from_portable_bits(bits: [u8; WIDTH], blob: &[u8]) -> PortableRef<'_> {
+        let inner = if bits[0] & 0b1100_0000 != PORTABLE_PTR_TAG {
+            Self::from_inline_buf(bits)
+        } else {
+            // The block is only read, and never freed.
+            let block = blob.as_ptr().wrapping_add(Self::portable_offset(bits));
+            Self::from_heap_ptr(block as *mut u8)
+        };
+        PortableRef {
+            inner: ManuallyDrop::new(inner),
+            blob: PhantomData,
+        }
+    }
+
+    /// Checks that `bits` is the portable form of a valid string, with any heap block inside
+    /// `blob`.
+    ///
+    /// # Errors
+    /// Returns [`InvalidBits`] if an inline string is not valid UTF-8 or not in canonical form,
+    /// or a heap block is misaligned, out of bounds, not valid UTF-8, or short enough that it
+    /// should have been inline.
+    pub fn validate_portable_bits(bits: [u8; WIDTH], blob: &[u8]) -> Result<(), InvalidBits> {
+        let tag = bits[0];
+        if tag & 0b1100_0000 == PORTABLE_PTR_TAG {
+            return Self::validate_portable_heap(bits, blob);
+        }
+        if bits == [0xff; WIDTH] {
+            // The all-NUL string.
+            return Ok(());
+        }
+        if usize::from_ne_bytes(bits) & Self::INLINE_TAG == Self::INLINE_TAG {
+            let len = (tag & 0b111) as usize;
+            // On 32-bit targets the 3 length bits can exceed the 3 bytes after the tag.
+            if len >= WIDTH {
+                return Err(INVALID);
+            }
+            let (s, padding) = bits[1..].split_at(len);
+            if padding.iter().any(|&b| b != 0) || str::from_utf8(s).is_err() {
+                return Err(INVALID);
+            }
+            return Ok(());
+        }
+        if bits == [0; WIDTH] || str::from_utf8(&bits).is_err() {
+            return Err(INVALID);
+        }
+        Ok(())
+    }
+
+    fn validate_portable_heap(bits: [u8; WIDTH], blob: &[u8]) -> Result<(), InvalidBits> {
+        // `addr` comes from `sptr::Strict` before 1.84.
+        #[allow(clippy::incompatible_msrv)]
+        let blob_addr = blob.as_ptr().addr();
+        if bits[0] != PORTABLE_PTR_TAG || blob_addr % HEAP_ALIGN != 0 {
+            return Err(INVALID);
+        }
+        let block = blob.get(Self::portable_offset(bits)..).ok_or(INVALID)?;
+        let (len, header) = Header::read_checked(block).ok_or(INVALID)?;
+        let s = block
+            .get(header..)
+            .and_then(|rest| rest.get(..len.checked_add(NUL_PAD)?))
+            .ok_or(INVALID)?;
+        let (s, nul) = s.split_at(len);
+        if len <= WIDTH || str::from_utf8(s).is_err() || nul.iter().any(|&b| b != 0) {
+            return Err(INVALID);
+        }
+        Ok(())
+    }
+
+    #[inline]
+    fn portable_offset(bits: [u8; WIDTH]) -> usize {
+        let mut offset = [0u8; 8];
+        offset[..WIDTH - 1].copy_from_slice(&bits[1..]);
+        u64::from_le_bytes(offset) as usize * HEAP_ALIGN
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec::Vec;
+    use core::ptr;
+
+    /// A blob of heap blocks and the portable handles pointing into it.
+    struct Arena {
+        words: Vec<u32>,
+        handles: Vec<[u8; WIDTH]>,
+    }
+
+    impl Arena {
+        fn new(strs: &[&str]) -> Self {
+            let mut bytes = Vec::new();
+            let mut handles = Vec::new();
+            for s in strs {
+                let cold = ColdString::new(s);
+                if cold.is_inline() {
+                    handles.push(cold.to_portable_bits(ptr::null()));
+                    continue;
+                }
+                let offset = bytes.len();
+                let (vint_len, len_buf) = Header::write(s.len()).unwrap();
+                bytes.extend_from_slice(&len_buf[..vint_len]);
+                bytes.extend_from_slice(s.as_bytes());
+                bytes.resize(bytes.len() + NUL_PAD, 0);
+                while bytes.len() % HEAP_ALIGN != 0 {
+                    bytes.push(0xAA);
+                }
+                let mut bits = [0u8; WIDTH];
+                bits[0] = PORTABLE_PTR_TAG;
+                bits[1..]
+                    .copy_from_slice(&((offset / HEAP_ALIGN) as u64).to_le_bytes()[..WIDTH - 1]);
+                handles.push(bits);
+            }
+            let mut words = alloc::vec![0u32; bytes.len() / 4];
+            for (w, chunk) in words.iter_mut().zip(bytes.chunks(4)) {
+                *w = u32::from_ne_bytes([chunk[0], chunk[1], chunk[2], chunk[3]]);
+            }
+            Self { words, handles }
+        }
+
+        fn blob(&mut self) -> &mut [u8] {
+            let len = self.words.len() * 4;
+            unsafe { core::slice::from_raw_parts_mut(self.words.as_mut_ptr() as *mut u8, len) }
+        }
+
+        fn check(&mut self, strs: &[&str]) {
+            let handles = self.handles.clone();
+            let blob = self.blob();
+            let base = blob.as_ptr();
+            for (bits, s) in handles.iter().zip(strs) {
+                assert_eq!(ColdString::validate_portable_bits(*bits, blob), Ok(()));
+                let cold = unsafe { ColdString::from_portable_bits(*bits, blob) };
+                assert_eq!(*cold, *s);
+                assert_eq!(cold.len(), s.len());
+                assert_eq!(&*cold, &ColdString::new(s));
+                assert_eq!(cold.to_portable_bits(base), *bits);
+                let owned = ColdString::clone(&cold);
+                assert_eq!(owned, *s);
+                let copy = cold.clone();
+                assert_eq!(copy.as_ref(), *s);
+                assert_eq!(alloc::format!("{:?}", copy), alloc::format!("{:?}", s));
+            }
+        }
+    }
+
+    const STRS: [&str; 9] = [
+        "",
+        "inline",
+        "\0\0\0\0\0\0\0\0",
+        "12345678",
+        "a string long enough for the heap",
+        "🦀💯🦀💯🦀💯",
+        "another heap string, at a later offset",
+        "x",
+        "a string that needs a two byte length header, a string that needs a two byte length header, a string that needs a two byte length header",
+    ];
+
+    #[test]
+    fn roundtrip_and_relocate() {
+        let mut arena = Arena::new(&STRS);
+        arena.check(&STRS);
+        // Copy the blob elsewhere and rebase the same handles onto it.
+        let mut moved = Arena {
+            words: arena.words.clone(),
+            handles: arena.handles.clone(),
+        };
+        drop(arena);
+        moved.check(&STRS);
+    }
+
+    #[test]
+    fn inline_bits_are_bytes() {
+        let bits = ColdString::new("ab").to_portable_bits(ptr::null());
+        let mut expected = [0u8; WIDTH];
+        expected[..3].copy_from_slice(&[0b1111_1010, b'a', b'b']);
+        assert_eq!(bits, expected);
+    }
+
+    #[test]
+    fn inline_length_bits() {
+        // On 32-bit targets, lengths 4..=7 don't fit in the 3 bytes after the tag.
+        for len in 0..8 {
+            let mut bits = [0u8; WIDTH];
+            bits[0] = 0b1111_1000 | len as u8;
+            for b in bits[1..].iter_mut().take(len) {
+                *b = b'a';
+            }
+            let expected = if len < WIDTH { Ok(()) } else { Err(INVALID) };
+            assert_eq!(
+                ColdString::validate_portable_bits(bits, &[]),
+                expected,
+                "{}",
+                len
+            );
+        }
+    }
+
+    #[test]
+    fn rejects_corrupt_bits() {
+        let mut arena = Arena::new(&STRS[4..5]);
+        let good = arena.handles[0];
+        let blob = arena.blob();
+        assert_eq!(ColdString::validate_portable_bits(good, blob), Ok(()));
+
+        let mut bad = Vec::new();
+        // Heap tag with stray low bits.
+        let mut b = good;
+        b[0] |= 1;
+        bad.push(b);
+        // Offset past the end of the blob.
+        let mut b = good;
+        b[1] = 0xff;
+        bad.push(b);
+        // Inline tag with non-zero padding.
+        let mut b = ColdString::new("a").to_portable_bits(ptr::null());
+        b[WIDTH - 1] = b'x';
+        bad.push(b);
+        // Invalid UTF-8, inline and full width.
+        let mut b = ColdString::new("ab").to_portable_bits(ptr::null());
+        b[1] = 0xc3;
+        bad.push(b);
+        bad.push([0xc3; WIDTH]);
+        // All zeros.
+        bad.push([0; WIDTH]);
+        for b in bad {
+            assert_eq!(
+                ColdString::validate_portable_bits(b, blob),
+                Err(INVALID),
+                "{:?}",
+                b
+            );
+        }
+
+        // A corrupted heap block.
+        blob[2] = 0xff;
+        assert_eq!(ColdString::validate_portable_bits(good, blob), Err(INVALID));
+        // A truncated blob.
+        let len = blob.len();
+        assert_eq!(
+            ColdString::validate_portable_bits(good, &blob[..len - 8]),
+            Err(INVALID)
+        );
+        // A header claiming the largest length.
+        #[cfg(all(target_pointer_width = "64", not(feature = "fixed-header")))]
+        {
+            let mut huge = Arena::new(&[]);
+            let mut bytes = [0xff; 12];
+            bytes[Header::MAX - 1] = (1 << (usize::BITS as usize - 7 * (Header::MAX - 1))) - 1;
+            huge.words = bytes
+                .chunks(4)
+                .map(|w| u32::from_ne_bytes([w[0], w[1], w[2], w[3]]))
+                .collect();
+            assert_eq!(
+                Header::read_checked(huge.blob()),
+                Some((usize::MAX, Header::MAX))
+            );
+            assert_eq!(
+                ColdString::validate_portable_bits(good, huge.blob()),
+                Err(INVALID)
+            );
+        }
+        // A short string stored on the heap.
+        let mut short = Arena::new(&[]);
+        short.words = alloc::vec![u32::from_ne_bytes([1, b'a', 0, 0])];
+        assert_eq!(
+            ColdString::validate_portable_bits(good, short.blob()),
+            Err(INVALID)
+        );
+    }
+}
@@ -0,0 +1,113 @@
+//! Sorting by `u64` prefix keys, which are compared without following heap pointers.
+
+use crate::{ColdString, WIDTH};
+
+use alloc::vec::Vec;
+use core::mem;
+
+impl ColdString {
+    /// Returns the first 8 bytes of this string as a big-endian `u64`, zero padded if the
+    /// string is shorter.
+    ///
+    /// Keys order like the strings: if `a.prefix_key() < b.prefix_key()`, then `a < b`. Equal
+    /// keys need a full comparison. The key of an inline string is computed from its word,
+    /// without reading any other memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use cold_string::ColdString;
+    ///
+    /// let a = ColdString::new("apple");
+    /// assert_eq!(a.prefix_key(), u64::from_be_bytes(*b"apple\0\0\0"));
+    /// let b = ColdString::new("apple pie, a string on the heap");
+    /// assert_eq!(b.prefix_key(), u64::from_be_bytes(*b"apple pi"));
+    /// assert!(a.prefix_key() < b.prefix_key());
+    /// ```
+    #[inline]
+    pub fn prefix_key(&self) -> u64 {
+        if self.is_inline() {
+            let (word, _) = self.inline_key();
+            (word as u64) << (64 - 8 * WIDTH)
+        } else {
+            bytes_key(self.as_bytes())
+        }
+    }
+}
+
+/// Returns the [`ColdString::prefix_key`] of a string with these bytes.
+#[inline]
+pub(crate) fn bytes_key(bytes: &[u8]) -> u64 {
+    let mut key = [0u8; 8];
+    let n = bytes.len().min(8);
+    key[..n].copy_from_slice(&bytes[..n]);
+    u64::from_be_bytes(key)
+}
+
+/// Sorts `slice` like `sort_unstable`, comparing [`ColdString::prefix_key`]s first.
+///
+/// The keys are computed once, so most comparisons don't follow a heap pointer. Only strings
+/// with equal keys are compared in full. This allocates a buffer of one key and one string per
+/// entry.
+///
+/// # Examples
+/// ```
+/// use cold_string::ColdString;
+///
+/// let mut names = ["banana", "apple pie, a string on the heap", "apple", "cherry"]
+///     .map(ColdString::new);
+/// cold_string::sort_by_prefix(&mut names);
+/// assert_eq!(names, ["apple", "apple pie, a string on the heap", "banana", "cherry"]);
+/// ```
+pub fn sort_by_prefix(slice: &mut [ColdString]) {
+    let mut keyed: Vec<(u64, ColdString)> = slice
+        .iter_mut()
+        .map(|s| (s.prefix_key(), mem::take(s)))
+        .collect();
+    keyed.sort_unstable_by(|(a_key, a), (b_key, b)| a_key.cmp(b_key).then_with(|| a.cmp(b)));
+    for (dst, (_, s)) in slice.iter_mut().zip(keyed) {
+        *dst = s;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::StaticStr;
+
+    use alloc::vec;
+
+    #[test]
+    fn keys_order_like_strings() {
+        static LONG: StaticStr = StaticStr::new("a static string long enough for the heap");
+        let strs = vec![
+            ColdString::new(""),
+            ColdString::new("\0"),
+            ColdString::new("\0\0\0\0\0\0\0\0"),
+            ColdString::new("a"),
+            ColdString::new("a\0"),
+            ColdString::new("ab"),
+            ColdString::new("abcdefgh"),
+            ColdString::new("abcdefghi, on the heap"),
+            ColdString::from_static(&LONG),
+            ColdString::new("é"),
+            ColdString::new("\u{ffff}\u{ffff}\u{ffff}"),
+        ];
+        for a in &strs {
+            let bytes = a.as_bytes();
+            let mut expected = [0u8; 8];
+            expected[..bytes.len().min(8)].copy_from_slice(&bytes[..bytes.len().min(8)]);
+            assert_eq!(a.prefix_key(), u64::from_be_bytes(expected), "{:?}", a);
+            for b in &strs {
+                if a.prefix_key() < b.prefix_key() {
+                    assert!(a < b);
+                }
+            }
+        }
+        let mut sorted = strs.clone();
+        sorted.sort_unstable();
+        let mut by_prefix = strs;
+        by_prefix.reverse();
+        sort_by_prefix(&mut by_prefix);
+        assert_eq!(by_prefix, sorted);
+    }
+}
@@ -0,0 +1,175 @@
+#![cfg_attr(docsrs, doc(cfg(feature = "std")))]
+
+use crate::{ColdString, WIDTH};
+
+use alloc::vec::Vec;
+use core::str::Utf8Error;
+use std::io::{self, ErrorKind, Read};
+
+impl ColdString {
+    /// Reads all of `reader` into a [`ColdString`].
+    ///
+    /// Streams of at most `INLINE_CAPACITY` bytes don't allocate. Longer streams are buffered
+    /// before being copied into the heap block, so use [`ColdString::from_reader_sized`] if
+    /// their length is known.
+    ///
+    /// # Errors
+    /// Returns the first error from `reader` other than [`ErrorKind::Interrupted`], or an
+    /// [`ErrorKind::InvalidData`] error holding the [`Utf8Error`] if the bytes are not UTF-8.
+    ///
+    /// # Examples
+    /// ```
+    /// use cold_string::ColdString;
+    ///
+    /// let s = ColdString::from_reader(&b"cold"[..]).unwrap();
+    /// assert_eq!(s, "cold");
+    /// ```
+    pub fn from_reader<R: Read>(reader: R) -> io::Result<Self> {
+        Self::from_reader_sized(reader, WIDTH)
+    }
+
+    /// Reads all of `reader` into a [`ColdString`], expecting `size_hint` bytes.
+    ///
+    /// If the stream is exactly `size_hint` bytes long, it is read straight into the final heap
+    /// block, after its length header, so at most one allocation is made. If it is shorter, the
+    /// bytes read are copied into an exactly sized block. If it is longer, the rest is buffered
+    /// and everything is copied into the final block.
+    ///
+    /// # Errors
+    /// Returns the first error from `reader` other than [`ErrorKind::Interrupted`], or an
+    /// [`ErrorKind::InvalidData`] error holding the [`Utf8Error`] if the bytes are not UTF-8.
+    ///
+    /// # Examples
+    /// ```
+    /// use cold_string::ColdString;
+    ///
+    /// let text = "a text asset loaded into a cold cache";
+    /// let s = ColdString::from_reader_sized(text.as_bytes(), text.len()).unwrap();
+    /// assert_eq!(s, text);
+    ///
+    /// let err = ColdString::from_reader(&[0xff, 0xfe][..]).unwrap_err();
+    /// assert_eq!(err.kind(), std::io::ErrorKind::InvalidData);
+    /// ```
+    pub fn from_reader_sized<R: Read>(mut reader: R, size_hint: usize) -> io::Result<Self> {
+        let mut uninit = Self::with_len(size_hint);
+        let filled = read_full(&mut reader, uninit.writer())?;
+        if filled < size_hint {
+            return Self::from_utf8(&uninit.writer()[..filled]).map_err(invalid_data);
+        }
+        let mut probe = [0u8; 1];
+        if read_full(&mut reader, &mut probe)? == 0 {
+            return uninit.finish().map_err(invalid_data);
+        }
+        let mut buf = Vec::with_capacity(size_hint.saturating_mul(2).max(2 * WIDTH));
+        buf.extend_from_slice(uninit.writer());
+        buf.push(probe[0]);
+        drop(uninit);
+        reader.read_to_end(&mut buf)?;
+        Self::from_utf8(&buf).map_err(invalid_data)
+    }
+}
+
+/// Reads until `buf` is full or the stream ends, returning the number of bytes read.
+fn read_full<R: Read>(reader: &mut R, buf: &mut [u8]) -> io::Result<usize> {
+    let mut filled = 0;
+    while filled < buf.len() {
+        match reader.read(&mut buf[filled..]) {
+            Ok(0) => break,
+            Ok(n) => filled += n,
+            Err(e) if e.kind() == ErrorKind::Interrupted => {}
+            Err(e) => return Err(e),
+        }
+    }
+    Ok(filled)
+}
+
+fn invalid_data(err: Utf8Error) -> io::Error {
+    io::Error::new(ErrorKind::InvalidData, err)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use alloc::string::String;
+
+    /// Returns at most `chunk` bytes per read, and an `Interrupted` error before every read.
+    struct Chunked<'a> {
+        bytes: &'a [u8],
+        chunk: usize,
+        interrupt: bool,
+    }
+
+    impl Read for Chunked<'_> {
+        fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+            self.interrupt = !self.interrupt;
+            if self.interrupt {
+                return Err(ErrorKind::Interrupted.into());
+            }
+            let n = self.chunk.min(buf.len()).min(self.bytes.len());
+            buf[..n].copy_from_slice(&self.bytes[..n]);
+            self.bytes = &self.bytes[n..];
+            Ok(n)
+        }
+    }
+
+    const STRS: [&str; 6] = [
+        "",
+        "cold",
+        "12345678",
+        "🦀🦀🦀",
+        "a string long enough for the heap",
+        "multi-byte chars 🦀 é ü split across reads 💯",
+    ];
+
+    #[test]
+    fn reads_whole_stream() {
+        for s in STRS {
+            for chunk in [1, 2, 3, 64] {
+                let reader = || Chunked {
+                    bytes: s.as_bytes(),
+                    chunk,
+                    interrupt: false,
+                };
+                assert_eq!(ColdString::from_reader(reader()).unwrap(), s);
+                for hint in [0, 1, s.len() / 2, s.len(), s.len() + 1, 4 * s.len() + 100] {
+                    let cold = ColdString::from_reader_sized(reader(), hint).unwrap();
+                    assert_eq!(cold, s);
+                    assert_eq!(cold.is_inline(), s.len() <= WIDTH);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn empty_reader() {
+        assert_eq!(ColdString::from_reader(io::empty()).unwrap(), "");
+        let cold = ColdString::from_reader_sized(io::empty(), 1000).unwrap();
+        assert_eq!(cold, ColdString::EMPTY);
+    }
+
+    #[test]
+    fn invalid_utf8() {
+        let mut truncated = String::from("a string ending in half a crab 🦀").into_bytes();
+        truncated.pop();
+        for bytes in [&[0xff][..], &[b'a', 0xc3], &truncated] {
+            for hint in [0, bytes.len(), 100] {
+                let err = ColdString::from_reader_sized(bytes, hint).unwrap_err();
+                assert_eq!(err.kind(), ErrorKind::InvalidData);
+                assert!(err.get_ref().unwrap().is::<Utf8Error>());
+            }
+        }
+    }
+
+    #[test]
+    fn propagates_errors() {
+        struct Failing;
+        impl Read for Failing {
+            fn read(&mut self, _: &mut [u8]) -> io::Result<usize> {
+                Err(ErrorKind::BrokenPipe.into())
+            }
+        }
+        let err = ColdString::from_reader(Failing).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::BrokenPipe);
+    }
+}
@@ -0,0 +1,186 @@
+use crate::ColdString;
+
+use alloc::string::String;
+use core::str;
+
+/// A pattern for [`ColdString::replace`], [`ColdString::split_cold`] and related methods: a
+/// `&str`, a `&String`, a `&ColdString` or a `char`.
+#[derive(Clone, Copy, Debug)]
+pub struct ReplacePattern<'a>(Repr<'a>);
+
+#[derive(Clone, Copy, Debug)]
+enum Repr<'a> {
+    Str(&'a str),
+    Char { c: char, buf: [u8; 4], len: usize },
+}
+
+impl<'a> ReplacePattern<'a> {
+    #[inline]
+    fn as_str(&self) -> &str {
+        match &self.0 {
+            Repr::Str(s) => s,
+            // SAFETY: the bytes were encoded from a `char`
+            Repr::Char { buf, len, .. } => unsafe { str::from_utf8_unchecked(&buf[..*len]) },
+        }
+    }
+
+    /// Returns the pattern as either a borrowed `&str` or a `char`, for APIs whose result
+    /// borrows the pattern.
+    #[inline]
+    pub(crate) fn into_either(self) -> (Option<&'a str>, Option<char>) {
+        match self.0 {
+            Repr::Str(s) => (Some(s), None),
+            Repr::Char { c, .. } => (None, Some(c)),
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ReplacePattern<'a> {
+    #[inline]
+    fn from(s: &'a str) -> Self {
+        Self(Repr::Str(s))
+    }
+}
+
+impl<'a> From<&'a String> for ReplacePattern<'a> {
+    #[inline]
+    fn from(s: &'a String) -> Self {
+        Self(Repr::Str(s))
+    }
+}
+
+impl<'a> From<&'a ColdString> for ReplacePattern<'a> {
+    #[inline]
+    fn from(s: &'a ColdString) -> Self {
+        Self(Repr::Str(s))
+    }
+}
+
+impl From<char> for ReplacePattern<'_> {
+    #[inline]
+    fn from(c: char) -> Self {
+        let mut buf = [0u8; 4];
+        let len = c.encode_utf8(&mut buf).len();
+        Self(Repr::Char { c, buf, len })
+    }
+}
+
+impl ColdString {
+    /// Replaces all matches of a pattern with another string, like [`str::replace`].
+    ///
+    /// The matches are counted first, so the result is written once into an inline buffer or
+    /// an exactly sized heap block. If nothing matches, this is a clone.
+    ///
+    /// # Examples
+    /// ```
+    /// use cold_string::ColdString;
+    ///
+    /// let s = ColdString::new("my-cold-identifier");
+    /// assert_eq!(s.replace('-', "_"), "my_cold_identifier");
+    /// assert_eq!(s.replace("cold", ""), "my--identifier");
+    /// assert_eq!(ColdString::new("ab").replace("", "."), ".a.b.");
+    /// ```
+    #[inline]
+    pub fn replace<'a, P: Into<ReplacePattern<'a>>>(&self, from: P, to: &str) -> Self {
+        self.replacen(from, to, usize::MAX)
+    }
+
+    /// Replaces the first `count` matches of a pattern with another string, like
+    /// [`str::replacen`].
+    ///
+    /// # Panics
+    /// Panics if the length of the result overflows `usize`.
+    ///
+    /// # Examples
+    /// ```
+    /// use cold_string::ColdString;
+    ///
+    /// let s = ColdString::new("a-b-c-d");
+    /// assert_eq!(s.replacen('-', "", 2), "abc-d");
+    /// assert_eq!(s.replacen("-", "+", 0), s);
+    /// ```
+    pub fn replacen<'a, P: Into<ReplacePattern<'a>>>(
+        &self,
+        from: P,
+        to: &str,
+        count: usize,
+    ) -> Self {
+        let from = from.into();
+        let (s, from) = (self.as_str(), from.as_str());
+        let matches = s.match_indices(from).take(count).count();
+        if matches == 0 {
+            return self.clone();
+        }
+        let len = (s.len() - matches * from.len())
+            .checked_add(matches.checked_mul(to.len()).expect("capacity overflow"))
+            .expect("capacity overflow");
+        let mut uninit = Self::with_len(len);
+        let writer = uninit.writer();
+        let (mut pos, mut last) = (0, 0);
+        let mut write = |bytes: &[u8]| {
+            writer[pos..pos + bytes.len()].copy_from_slice(bytes);
+            pos += bytes.len();
+        };
+        let bytes = s.as_bytes();
+        for (start, _) in s.match_indices(from).take(matches) {
+            write(&bytes[last..start]);
+            write(to.as_bytes());
+            last = start + from.len();
+        }
+        write(&bytes[last..]);
+        // SAFETY: the bytes are substrings of `str`s between matches, and copies of `to`
+        unsafe { uninit.finish_unchecked() }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::WIDTH;
+
+    const STRS: [&str; 6] = [
+        "",
+        "aaaa",
+        "a-b",
+        "🦀-🦀",
+        "my-cold-identifier-long-enough-for-the-heap",
+        "no matches in this heap string",
+    ];
+
+    #[test]
+    fn matches_std() {
+        for s in STRS {
+            let cold = ColdString::new(s);
+            for from in ["", "-", "a", "aa", "🦀", "heap", "-cold-"] {
+                for to in ["", "_", "bb", "a replacement long enough for the heap"] {
+                    let expected = s.replace(from, to);
+                    let replaced = cold.replace(from, to);
+                    assert_eq!(replaced, expected.as_str());
+                    assert_eq!(replaced.is_inline(), expected.len() <= WIDTH);
+                    for count in [0, 1, 2, 5] {
+                        assert_eq!(cold.replacen(from, to, count), s.replacen(from, to, count));
+                    }
+                }
+            }
+            for from in ['-', 'a', '🦀', 'x'] {
+                assert_eq!(cold.replace(from, "__"), s.replace(from, "__"));
+                assert_eq!(cold.replacen(from, "", 1), s.replacen(from, "", 1));
+            }
+        }
+    }
+
+    #[test]
+    fn adjacent_and_shrinking() {
+        assert_eq!(ColdString::new("aaaaa").replace("aa", "b"), "bba");
+        let long = ColdString::new("--------a--------");
+        let shrunk = long.replace('-', "");
+        assert_eq!(shrunk, "a");
+        assert!(shrunk.is_inline());
+        let from = String::from("--");
+        assert_eq!(long.replace(&from, "-"), "----a----");
+        assert_eq!(
+            long.replace(&ColdString::new("-a-"), "+"),
+            "-------+-------"
+        );
+    }
+}
@@ -0,0 +1,274 @@
+use crate::{uninit::write_slice, ColdString, WIDTH};
+
+#[cfg(doc)]
+use alloc::string::String;
+use core::{mem, str};
+
+/// Options for [`ColdString::sanitize`]. Every option is off by default.
+///
+/// The options are applied in this order, without an intermediate buffer:
+/// 1. Invalid UTF-8 is replaced with U+FFFD if [`lossy`](Self::lossy) is set, or dropped
+///    otherwise, as if it wasn't in the input.
+/// 2. `\r\n` and lone `\r` become `\n` if [`normalize_newlines`](Self::normalize_newlines) is set.
+/// 3. Leading and trailing ASCII whitespace is removed if [`trim`](Self::trim) is set.
+/// 4. The result is truncated to at most [`max_len`](Self::max_len) bytes, at a char boundary.
+///
+/// Since truncation comes last, it may leave whitespace at the end of the string.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct SanitizeOptions {
+    lossy: bool,
+    normalize_newlines: bool,
+    trim: bool,
+    max_len: Option<usize>,
+}
+
+impl SanitizeOptions {
+    /// Returns the default options, which keep valid UTF-8 input as is and drop invalid bytes.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Replaces each invalid UTF-8 sequence with U+FFFD, like [`String::from_utf8_lossy`],
+    /// instead of dropping it.
+    pub fn lossy(mut self, lossy: bool) -> Self {
+        self.lossy = lossy;
+        self
+    }
+
+    /// Converts `\r\n` and lone `\r` to `\n`.
+    pub fn normalize_newlines(mut self, normalize_newlines: bool) -> Self {
+        self.normalize_newlines = normalize_newlines;
+        self
+    }
+
+    /// Removes leading and trailing ASCII whitespace, as defined by [`u8::is_ascii_whitespace`].
+    pub fn trim(mut self, trim: bool) -> Self {
+        self.trim = trim;
+        self
+    }
+
+    /// Truncates the string to at most `max_len` bytes, without splitting a char.
+    pub fn max_len(mut self, max_len: Option<usize>) -> Self {
+        self.max_len = max_len;
+        self
+    }
+}
+
+/// Calls `f` with each char of the output before its end is trimmed and it is truncated,
+/// until `f` returns `false`.
+fn for_each_char(bytes: &[u8], opts: SanitizeOptions, mut f: impl FnMut(char) -> bool) {
+    let mut after_cr = false;
+    let mut started = !opts.trim;
+    let mut push = |c: char| {
+        let c = if opts.normalize_newlines {
+            match (c, mem::replace(&mut after_cr, c == '\r')) {
+                ('\n', true) => return true,
+                ('\r', _) => '\n',
+                (c, _) => c,
+            }
+        } else {
+            c
+        };
+        if !started {
+            if c.is_ascii_whitespace() {
+                return true;
+            }
+            started = true;
+        }
+        f(c)
+    };
+    let mut rest = bytes;
+    while !rest.is_empty() {
+        match str::from_utf8(rest) {
+            Ok(s) => {
+                s.chars().all(&mut push);
+                break;
+            }
+            Err(e) => {
+                let (valid, invalid) = rest.split_at(e.valid_up_to());
+                // SAFETY: `valid_up_to` is the length of the valid prefix
+                if !unsafe { str::from_utf8_unchecked(valid) }
+                    .chars()
+                    .all(&mut push)
+                {
+                    break;
+                }
+                if opts.lossy && !push(char::REPLACEMENT_CHARACTER) {
+                    break;
+                }
+                rest = &invalid[e.error_len().unwrap_or(invalid.len())..];
+            }
+        }
+    }
+}
+
+/// Returns the length of the sanitized string, in bytes.
+fn sanitized_len(bytes: &[u8], opts: SanitizeOptions) -> usize {
+    // `end` is the length without trailing whitespace, and `boundary` the longest length up to
+    // `max_len` that ends on a char boundary.
+    let (mut len, mut end, mut boundary) = (0, 0, 0);
+    for_each_char(bytes, opts, |c| {
+        len += c.len_utf8();
+        let is_space = opts.trim && c.is_ascii_whitespace();
+        if !is_space {
+            end = len;
+        }
+        match opts.max_len {
+            Some(max) if len <= max => {
+                boundary = len;
+                true
+            }
+            // A char that won't be trimmed past the limit means the limit decides the end.
+            Some(_) => is_space,
+            None => true,
+        }
+    });
+    let len = if opts.trim { end } else { len };
+    match opts.max_len {
+        Some(max) if len > max => boundary,
+        _ => len,
+    }
+}
+
+/// Passes the first `len` bytes of the sanitized string to `write`, a char at a time, with
+/// the offset of each char.
+fn write_prefix(
+    bytes: &[u8],
+    opts: SanitizeOptions,
+    len: usize,
+    mut write: impl FnMut(usize, &[u8]),
+) {
+    let mut at = 0;
+    for_each_char(bytes, opts, |c| {
+        if at == len {
+            return false;
+        }
+        write(at, c.encode_utf8(&mut [0; 4]).as_bytes());
+        at += c.len_utf8();
+        true
+    });
+    debug_assert_eq!(at, len);
+}
+
+impl ColdString {
+    /// Creates a new [`ColdString`] from arbitrary bytes, cleaning them up as configured by
+    /// `opts`. See [`SanitizeOptions`] for the order the options apply in.
+    ///
+    /// The input is read twice: once to measure the result, and once to write it directly
+    /// into the new string. Input past the truncation point is not read.
+    ///
+    /// # Examples
+    /// ```
+    /// use cold_string::{ColdString, SanitizeOptions};
+    ///
+    /// let opts = SanitizeOptions::new()
+    ///     .lossy(true)
+    ///     .normalize_newlines(true)
+    ///     .trim(true)
+    ///     .max_len(Some(16));
+    /// let s = ColdString::sanitize(b"  line one\r\nline\xfftwo\r  ", opts);
+    /// assert_eq!(s, "line one\nline\u{fffd}");
+    /// ```
+    pub fn sanitize(bytes: &[u8], opts: SanitizeOptions) -> Self {
+        let len = sanitized_len(bytes, opts);
+        if len <= WIDTH {
+            let mut buf = [0u8; WIDTH];
+            write_prefix(bytes, opts, len, |at, b| {
+                buf[at..at + b.len()].copy_from_slice(b)
+            });
+            // SAFETY: `write_prefix` wrote `len` bytes of chars
+            return Self::new_inline(unsafe { str::from_utf8_unchecked(&buf[..len]) });
+        }
+        // SAFETY: `write_prefix` writes all `len` bytes
+        let mut out = unsafe { Self::with_len_uninit(len) };
+        let dst = out.writer_uninit();
+        write_prefix(bytes, opts, len, |at, b| {
+            write_slice(&mut dst[at..at + b.len()], b)
+        });
+        // SAFETY: `write_prefix` wrote `len` bytes of chars
+        unsafe { out.finish_unchecked() }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::{
+        borrow::ToOwned,
+        string::{String, ToString},
+    };
+
+    /// The multi-pass pipeline that `sanitize` replaces.
+    fn reference(bytes: &[u8], opts: SanitizeOptions) -> String {
+        let mut s = String::from_utf8_lossy(bytes).into_owned();
+        if !opts.lossy {
+            s = s.replace('\u{fffd}', "");
+        }
+        if opts.normalize_newlines {
+            s = s.replace("\r\n", "\n").replace('\r', "\n");
+        }
+        if opts.trim {
+            s = s.trim_matches(|c: char| c.is_ascii_whitespace()).to_owned();
+        }
+        if let Some(max) = opts.max_len {
+            let mut len = max.min(s.len());
+            while !s.is_char_boundary(len) {
+                len -= 1;
+            }
+            s.truncate(len);
+        }
+        s
+    }
+
+    #[test]
+    fn sanitize_every_combination() {
+        let inputs: [&[u8]; 14] = [
+            b"",
+            b"plain",
+            b"\r",
+            b"ends with a lone CR\r",
+            b"\r\n\r\n",
+            b"\r\r\n\n\r",
+            b"  \t padded \n ",
+            b"   ",
+            b"\xff",
+            b"CR then invalid\r\xff\n",
+            b"abc\xe2\x82",
+            b"abcdefg\xffhij",
+            "🦀💯 crab\r\n 🦀".as_bytes(),
+            b"\xf0\x9f\xa6truncated \xe2\x82\xac\xe2\x82\xac",
+        ];
+        for input in inputs {
+            for bits in 0..16 {
+                for max_len in [None, Some(0), Some(1), Some(3), Some(7), Some(8), Some(9)] {
+                    let opts = SanitizeOptions::new()
+                        .lossy(bits & 1 != 0)
+                        .normalize_newlines(bits & 2 != 0)
+                        .trim(bits & 4 != 0)
+                        .max_len(if bits & 8 != 0 { max_len } else { None });
+                    let cold = ColdString::sanitize(input, opts);
+                    assert_eq!(
+                        cold.to_string(),
+                        reference(input, opts),
+                        "{:?} {:?}",
+                        input,
+                        opts
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn sanitize_truncation_at_invalid_bytes() {
+        let opts = SanitizeOptions::new().lossy(true).max_len(Some(5));
+        // The replacement char is 3 bytes and doesn't fit after "abc".
+        assert_eq!(ColdString::sanitize(b"abc\xffdef", opts), "abc");
+        assert_eq!(ColdString::sanitize(b"ab\xffdef", opts), "ab\u{fffd}");
+        let opts = opts.lossy(false);
+        assert_eq!(ColdString::sanitize(b"abc\xffdef", opts), "abcde");
+        let opts = opts.trim(true);
+        assert_eq!(ColdString::sanitize(b"  a \xff b  ", opts), "a  b");
+        assert_eq!(ColdString::sanitize(b"a    \xff    ", opts), "a");
+    }
+}
@@ -0,0 +1,131 @@
+//! Searching sorted slices of [`ColdString`]s by `&str`.
+//!
+//! Each probe first compares the entry's [`ColdString::prefix_key`] with the needle's, which
+//! for inline entries doesn't read any memory. Only entries with an equal key compare their
+//! bytes, without any intermediate `String`.
+
+use crate::{prefix_key::bytes_key, ColdString};
+
+use core::cmp::Ordering;
+
+#[inline]
+fn cmp_str(probe: &ColdString, needle: &str, key: u64) -> Ordering {
+    probe
+        .prefix_key()
+        .cmp(&key)
+        .then_with(|| probe.as_bytes().cmp(needle.as_bytes()))
+}
+
+/// Binary searches `sorted` for `needle`, with the same semantics as [`slice::binary_search`].
+///
+/// `sorted` must be sorted by [`ColdString`]'s [`Ord`], e.g. with `sort` or `sort_unstable`.
+///
+/// # Examples
+/// ```
+/// use cold_string::ColdString;
+///
+/// let sorted = ["apple", "banana", "cherry"].map(ColdString::new);
+/// assert_eq!(cold_string::binary_search(&sorted, "banana"), Ok(1));
+/// assert_eq!(cold_string::binary_search(&sorted, "blueberry"), Err(2));
+/// ```
+pub fn binary_search(sorted: &[ColdString], needle: &str) -> Result<usize, usize> {
+    let key = bytes_key(needle.as_bytes());
+    sorted.binary_search_by(|probe| cmp_str(probe, needle, key))
+}
+
+/// Returns the index of the first entry of `sorted` that is not less than `prefix`, which is
+/// where the entries starting with `prefix` begin, if there are any.
+///
+/// `sorted` must be sorted by [`ColdString`]'s [`Ord`].
+///
+/// # Examples
+/// ```
+/// use cold_string::ColdString;
+///
+/// let sorted = ["apple", "banana", "bandana", "cherry"].map(ColdString::new);
+/// assert_eq!(cold_string::partition_point_prefix(&sorted, "ban"), 1);
+/// assert_eq!(cold_string::partition_point_prefix(&sorted, "z"), 4);
+/// ```
+pub fn partition_point_prefix(sorted: &[ColdString], prefix: &str) -> usize {
+    let key = bytes_key(prefix.as_bytes());
+    sorted.partition_point(|probe| cmp_str(probe, prefix, key) == Ordering::Less)
+}
+
+/// Returns the subslice of `sorted` whose entries start with `prefix`.
+///
+/// `sorted` must be sorted by [`ColdString`]'s [`Ord`]. The matching entries are then
+/// contiguous, and are found with two binary searches.
+///
+/// # Examples
+/// ```
+/// use cold_string::ColdString;
+///
+/// let sorted = ["apple", "banana", "bandana", "cherry"].map(ColdString::new);
+/// assert_eq!(cold_string::equal_range_by_prefix(&sorted, "ban"), ["banana", "bandana"]);
+/// assert!(cold_string::equal_range_by_prefix(&sorted, "d").is_empty());
+/// ```
+pub fn equal_range_by_prefix<'a>(sorted: &'a [ColdString], prefix: &str) -> &'a [ColdString] {
+    let start = partition_point_prefix(sorted, prefix);
+    let rest = &sorted[start..];
+    let len = rest.partition_point(|probe| probe.as_bytes().starts_with(prefix.as_bytes()));
+    &rest[..len]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec::Vec;
+
+    fn sorted(strs: &[&str]) -> Vec<ColdString> {
+        let mut v: Vec<ColdString> = strs.iter().map(ColdString::new).collect();
+        v.sort();
+        v
+    }
+
+    #[test]
+    fn search_empty() {
+        assert_eq!(binary_search(&[], "a"), Err(0));
+        assert_eq!(partition_point_prefix(&[], "a"), 0);
+        assert!(equal_range_by_prefix(&[], "").is_empty());
+    }
+
+    #[test]
+    fn search_mixed_inline_and_heap() {
+        let v = sorted(&[
+            "",
+            "a",
+            "abcdefgh",
+            "abcdefghi",
+            "abcdefghij and more",
+            "abd",
+            "b",
+            "é",
+            "🦀",
+        ]);
+        for (i, s) in v.iter().enumerate() {
+            assert_eq!(binary_search(&v, s), Ok(i));
+        }
+        assert_eq!(binary_search(&v, "abcdefghia"), Err(4));
+        assert_eq!(binary_search(&v, "zzz"), Err(7));
+        assert_eq!(
+            equal_range_by_prefix(&v, "abcdefgh"),
+            ["abcdefgh", "abcdefghi", "abcdefghij and more"]
+        );
+        assert_eq!(equal_range_by_prefix(&v, "ab").len(), 4);
+        assert_eq!(equal_range_by_prefix(&v, "").len(), v.len());
+        assert_eq!(equal_range_by_prefix(&v, "é"), ["é"]);
+        assert_eq!(partition_point_prefix(&v, "abd"), 5);
+        assert!(equal_range_by_prefix(&v, "c").is_empty());
+    }
+
+    #[test]
+    fn search_equal_keys() {
+        let v = sorted(&["a", "a\0", "a\0\0\0\0\0\0\0", "a\0\0\0\0\0\0\0 on the heap"]);
+        for (i, s) in v.iter().enumerate() {
+            assert_eq!(binary_search(&v, s), Ok(i));
+        }
+        assert_eq!(binary_search(&v, "a\0\0"), Err(2));
+        assert_eq!(partition_point_prefix(&v, "a\0"), 1);
+        assert_eq!(equal_range_by_prefix(&v, "a\0\0").len(), 2);
+    }
+}
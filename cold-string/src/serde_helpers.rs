@@ -0,0 +1,123 @@
+#![cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+//! Helpers for `#[serde(with = ...)]` fields holding [`ColdString`]s.
+
+use crate::ColdString;
+
+/// Serializes and deserializes a `Vec<ColdString>` field.
+///
+/// The `Vec` is allocated once from the format's length hint, rather than serde's default
+/// of growing it past a conservative cap, and each element is built straight from the
+/// deserialized `&str` without an intermediate `String`. The hint is trusted up to
+/// [`MAX_PREALLOC`](vec_cold::MAX_PREALLOC) elements, so a bogus length can't trigger a
+/// huge allocation.
+///
+/// # Examples
+/// ```ignore
+/// #[derive(serde::Serialize, serde::Deserialize)]
+/// struct Manifest {
+///     #[serde(with = "cold_string::serde_helpers::vec_cold")]
+///     files: Vec<ColdString>,
+/// }
+/// ```
+/// Or called directly:
+/// ```
+/// use cold_string::{serde_helpers::vec_cold, ColdString};
+///
+/// let mut de = serde_json::Deserializer::from_str(r#"["a", "a longer string"]"#);
+/// let v = vec_cold::deserialize(&mut de).unwrap();
+/// assert_eq!(v, ["a", "a longer string"]);
+/// ```
+pub mod vec_cold {
+    use super::*;
+
+    use alloc::vec::Vec;
+    use core::{fmt, marker::PhantomData, mem};
+    use serde::{
+        de::{SeqAccess, Visitor},
+        Deserializer, Serialize, Serializer,
+    };
+
+    /// The largest number of elements preallocated from a length hint (64 MiB of `ColdString`s).
+    pub const MAX_PREALLOC: usize = (64 << 20) / mem::size_of::<ColdString>();
+
+    /// Serializes the strings as a sequence, like `Vec<ColdString>`'s own `Serialize`.
+    pub fn serialize<S: Serializer>(v: &[ColdString], serializer: S) -> Result<S::Ok, S::Error> {
+        v.serialize(serializer)
+    }
+
+    /// Deserializes a sequence of strings, allocating the `Vec` once if the length is known.
+    pub fn deserialize<'de, D: Deserializer<'de>>(d: D) -> Result<Vec<ColdString>, D::Error> {
+        d.deserialize_seq(VecVisitor(PhantomData))
+    }
+
+    struct VecVisitor<'de>(PhantomData<&'de ()>);
+
+    impl<'de> Visitor<'de> for VecVisitor<'de> {
+        type Value = Vec<ColdString>;
+
+        fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.write_str("a sequence of strings")
+        }
+
+        fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+            let capacity = seq.size_hint().unwrap_or(0).min(MAX_PREALLOC);
+            let mut v = Vec::with_capacity(capacity);
+            while let Some(s) = seq.next_element::<ColdString>()? {
+                v.push(s);
+            }
+            Ok(v)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::{format, string::String, vec::Vec};
+
+    fn strings(n: usize) -> Vec<ColdString> {
+        (0..n)
+            .map(|i| ColdString::new(format!("{}{}", "x".repeat(i % 20), i)))
+            .collect()
+    }
+
+    #[test]
+    fn vec_cold_json() {
+        for n in [0, 1, 100] {
+            let v = strings(n);
+            let mut json = Vec::new();
+            vec_cold::serialize(&v, &mut serde_json::Serializer::new(&mut json)).unwrap();
+            assert_eq!(json, serde_json::to_vec(&v).unwrap());
+            let mut de = serde_json::Deserializer::from_slice(&json);
+            assert_eq!(vec_cold::deserialize(&mut de).unwrap(), v);
+        }
+        let mut de = serde_json::Deserializer::from_str(r#"["ok", 1]"#);
+        assert!(vec_cold::deserialize(&mut de).is_err());
+        let mut de = serde_json::Deserializer::from_str(r#""not a sequence""#);
+        assert!(vec_cold::deserialize(&mut de).is_err());
+    }
+
+    #[test]
+    fn vec_cold_msgpack() {
+        for n in [0, 1, 100] {
+            let v = strings(n);
+            let mut buf = Vec::new();
+            vec_cold::serialize(&v, &mut rmp_serde::Serializer::new(&mut buf)).unwrap();
+            let mut de = rmp_serde::Deserializer::new(&buf[..]);
+            let de_v = vec_cold::deserialize(&mut de).unwrap();
+            assert_eq!(de_v, v);
+            assert_eq!(de_v.capacity(), n);
+            let naive: Vec<ColdString> = rmp_serde::from_slice(&buf).unwrap();
+            assert_eq!(naive, de_v);
+        }
+    }
+
+    #[test]
+    fn escaped_json_strings() {
+        let json = r#"["tab\tnewline\n", "é🦀", "plain"]"#;
+        let mut de = serde_json::Deserializer::from_str(json);
+        let v = vec_cold::deserialize(&mut de).unwrap();
+        let expected: Vec<String> = serde_json::from_str(json).unwrap();
+        assert_eq!(v, expected);
+    }
+}
@@ -0,0 +1,122 @@
+#![cfg_attr(docsrs, doc(cfg(feature = "slog")))]
+
+use crate::ColdString;
+
+use slog::{Key, Record, Serializer, Value};
+
+/// Emits the string with [`Serializer::emit_str`].
+///
+/// [`ColdString`] is `Send + Sync + 'static`, so it can be moved straight into `o!()` key-value
+/// lists. Keys are `&'static str` unless slog's `dynamic-keys` feature is enabled, in which case
+/// `String::from(cold)` converts into a `slog::Key`.
+impl Value for ColdString {
+    #[inline]
+    fn serialize(
+        &self,
+        _record: &Record<'_>,
+        key: Key,
+        serializer: &mut dyn Serializer,
+    ) -> slog::Result {
+        serializer.emit_str(key, self.as_str())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl slog::SerdeValue for ColdString {
+    #[inline]
+    fn serialize_fallback(&self, key: Key, serializer: &mut dyn Serializer) -> slog::Result {
+        serializer.emit_str(key, self.as_str())
+    }
+
+    #[inline]
+    fn as_serde(&self) -> &dyn erased_serde::Serialize {
+        self
+    }
+
+    fn to_sendable(&self) -> alloc::boxed::Box<dyn slog::SerdeValue + Send + 'static> {
+        alloc::boxed::Box::new(self.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::{
+        string::{String, ToString},
+        sync::Arc,
+        vec::Vec,
+    };
+    use core::fmt;
+    use slog::{o, Drain, Logger, Never, OwnedKVList, KV};
+    use std::sync::Mutex;
+
+    type Captured = Arc<Mutex<Vec<(String, &'static str, String)>>>;
+
+    struct CaptureSerializer(Vec<(String, &'static str, String)>);
+
+    impl Serializer for CaptureSerializer {
+        fn emit_arguments(&mut self, key: Key, val: &fmt::Arguments<'_>) -> slog::Result {
+            self.0.push((key.to_string(), "arguments", val.to_string()));
+            Ok(())
+        }
+
+        fn emit_str(&mut self, key: Key, val: &str) -> slog::Result {
+            self.0.push((key.to_string(), "str", val.to_string()));
+            Ok(())
+        }
+    }
+
+    struct CaptureDrain(Captured);
+
+    impl Drain for CaptureDrain {
+        type Ok = ();
+        type Err = Never;
+
+        fn log(&self, record: &Record<'_>, values: &OwnedKVList) -> Result<(), Never> {
+            let mut serializer = CaptureSerializer(Vec::new());
+            record.kv().serialize(record, &mut serializer).unwrap();
+            values.serialize(record, &mut serializer).unwrap();
+            self.0.lock().unwrap().extend(serializer.0);
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn slog_emits_str() {
+        for s in ["", "cold", "12345678", "this is a longer cold string"] {
+            let captured = Captured::default();
+            let logger = Logger::root(
+                CaptureDrain(captured.clone()),
+                o!("owned" => ColdString::new(s)),
+            );
+            let cold = ColdString::new(s);
+            slog::info!(logger, "msg"; "borrowed" => &cold);
+
+            let captured = captured.lock().unwrap();
+            assert_eq!(
+                *captured,
+                [
+                    ("borrowed".to_string(), "str", s.to_string()),
+                    ("owned".to_string(), "str", s.to_string()),
+                ]
+            );
+        }
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn slog_serde_value() {
+        use serde_test::{assert_ser_tokens, Token};
+        use slog::SerdeValue;
+
+        let cold = ColdString::new("this is a longer cold string");
+        assert_ser_tokens(
+            &cold.as_serde(),
+            &[Token::Str("this is a longer cold string")],
+        );
+        assert_ser_tokens(
+            &cold.to_sendable().as_serde(),
+            &[Token::Str("this is a longer cold string")],
+        );
+    }
+}
@@ -0,0 +1,180 @@
+use crate::{ColdString, ReplacePattern};
+
+impl ColdString {
+    /// Returns an iterator over the substrings of this string separated by `delimiter`, as
+    /// owned [`ColdString`]s, with the same semantics as [`str::split`].
+    ///
+    /// Each piece is inline or on the heap depending on its own length. The delimiter can be
+    /// a `&str` or a `char`.
+    ///
+    /// # Examples
+    /// ```
+    /// use cold_string::ColdString;
+    ///
+    /// let s = ColdString::new("a,b,,a field long enough for the heap");
+    /// let pieces: Vec<ColdString> = s.split_cold(',').collect();
+    /// assert_eq!(pieces, ["a", "b", "", "a field long enough for the heap"]);
+    /// assert!(pieces[0].is_inline());
+    /// assert!(ColdString::new("ab").split_cold("").eq(["", "a", "b", ""]));
+    /// ```
+    pub fn split_cold<'a, P: Into<ReplacePattern<'a>>>(
+        &'a self,
+        delimiter: P,
+    ) -> impl Iterator<Item = ColdString> + 'a {
+        let s = self.as_str();
+        let (by_str, by_char) = delimiter.into().into_either();
+        pieces(by_str.map(|p| s.split(p)), by_char.map(|c| s.split(c)))
+    }
+
+    /// Returns an iterator over at most `n` substrings of this string separated by
+    /// `delimiter`, as owned [`ColdString`]s, with the same semantics as [`str::splitn`].
+    ///
+    /// # Examples
+    /// ```
+    /// use cold_string::ColdString;
+    ///
+    /// let s = ColdString::new("key=value=more");
+    /// assert!(s.splitn_cold(2, '=').eq(["key", "value=more"]));
+    /// ```
+    pub fn splitn_cold<'a, P: Into<ReplacePattern<'a>>>(
+        &'a self,
+        n: usize,
+        delimiter: P,
+    ) -> impl Iterator<Item = ColdString> + 'a {
+        let s = self.as_str();
+        let (by_str, by_char) = delimiter.into().into_either();
+        pieces(
+            by_str.map(|p| s.splitn(n, p)),
+            by_char.map(|c| s.splitn(n, c)),
+        )
+    }
+
+    /// Returns an iterator over the substrings of this string separated by `delimiter`, as
+    /// owned [`ColdString`]s, in reverse order, with the same semantics as [`str::rsplit`].
+    ///
+    /// # Examples
+    /// ```
+    /// use cold_string::ColdString;
+    ///
+    /// let s = ColdString::new("usr/local/bin");
+    /// assert!(s.rsplit_cold("/").eq(["bin", "local", "usr"]));
+    /// ```
+    pub fn rsplit_cold<'a, P: Into<ReplacePattern<'a>>>(
+        &'a self,
+        delimiter: P,
+    ) -> impl Iterator<Item = ColdString> + 'a {
+        let s = self.as_str();
+        let (by_str, by_char) = delimiter.into().into_either();
+        pieces(by_str.map(|p| s.rsplit(p)), by_char.map(|c| s.rsplit(c)))
+    }
+
+    /// Returns an iterator over the words of this string separated by Unicode whitespace, as
+    /// owned [`ColdString`]s, with the same semantics as [`str::split_whitespace`].
+    ///
+    /// # Examples
+    /// ```
+    /// use cold_string::ColdString;
+    ///
+    /// let s = ColdString::new(" the\u{a0}quick\t\u{3000}brown  fox ");
+    /// assert!(s.split_whitespace_cold().eq(["the", "quick", "brown", "fox"]));
+    /// ```
+    pub fn split_whitespace_cold(&self) -> impl Iterator<Item = ColdString> + '_ {
+        self.as_str().split_whitespace().map(ColdString::new)
+    }
+
+    /// Returns an iterator over the words of this string separated by ASCII whitespace, as
+    /// owned [`ColdString`]s, with the same semantics as [`str::split_ascii_whitespace`].
+    ///
+    /// # Examples
+    /// ```
+    /// use cold_string::ColdString;
+    ///
+    /// let s = ColdString::new(" the\u{a0}quick\tbrown  fox ");
+    /// assert!(s.split_ascii_whitespace_cold().eq(["the\u{a0}quick", "brown", "fox"]));
+    /// ```
+    pub fn split_ascii_whitespace_cold(&self) -> impl Iterator<Item = ColdString> + '_ {
+        self.as_str().split_ascii_whitespace().map(ColdString::new)
+    }
+}
+
+/// Copies the pieces of whichever split was made, since a `&str` and a `char` delimiter give
+/// different iterator types.
+#[inline]
+fn pieces<'a, A, B>(by_str: Option<A>, by_char: Option<B>) -> impl Iterator<Item = ColdString> + 'a
+where
+    A: Iterator<Item = &'a str> + 'a,
+    B: Iterator<Item = &'a str> + 'a,
+{
+    by_str
+        .into_iter()
+        .flatten()
+        .chain(by_char.into_iter().flatten())
+        .map(ColdString::new)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::WIDTH;
+
+    use alloc::{string::String, vec::Vec};
+
+    const STRS: [&str; 7] = [
+        "",
+        ",",
+        "a,b",
+        ",a,,b,",
+        "🦀,🦀",
+        "a field long enough for the heap,x,another heap-sized field",
+        "no delimiters in this heap string",
+    ];
+
+    #[test]
+    fn matches_str_split() {
+        for s in STRS {
+            let cold = ColdString::new(s);
+            for delimiter in ["", ",", ",,", "🦀", "heap"] {
+                assert!(cold.split_cold(delimiter).eq(s.split(delimiter)));
+                assert!(cold.rsplit_cold(delimiter).eq(s.rsplit(delimiter)));
+                for n in 0..4 {
+                    assert!(cold.splitn_cold(n, delimiter).eq(s.splitn(n, delimiter)));
+                }
+            }
+            for delimiter in [',', '🦀', 'x'] {
+                assert!(cold.split_cold(delimiter).eq(s.split(delimiter)));
+                assert!(cold.rsplit_cold(delimiter).eq(s.rsplit(delimiter)));
+                assert!(cold.splitn_cold(2, delimiter).eq(s.splitn(2, delimiter)));
+            }
+            let delimiter = String::from(",");
+            assert!(cold.split_cold(&delimiter).eq(s.split(',')));
+        }
+    }
+
+    #[test]
+    fn split_whitespace() {
+        for s in [
+            "",
+            "   ",
+            "word",
+            " two  words ",
+            "\u{a0}nbsp\u{3000}ideographic\u{2028}line\u{85}next",
+            "\tmixed ascii\r\nwhitespace\x0cwith a word long enough for the heap",
+        ] {
+            let cold = ColdString::new(s);
+            assert!(cold.split_whitespace_cold().eq(s.split_whitespace()));
+            assert!(cold
+                .split_ascii_whitespace_cold()
+                .eq(s.split_ascii_whitespace()));
+        }
+    }
+
+    #[test]
+    fn pieces_choose_representation() {
+        let cold = ColdString::new(STRS[5]);
+        let pieces: Vec<ColdString> = cold.split_cold(',').collect();
+        for piece in &pieces {
+            assert_eq!(piece.is_inline(), piece.len() <= WIDTH);
+        }
+        assert_eq!(pieces.iter().filter(|p| p.is_inline()).count(), 1);
+    }
+}
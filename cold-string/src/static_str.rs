@@ -0,0 +1,201 @@
+use crate::{ColdString, WIDTH};
+
+/// A `'static` string that [`ColdString::from_static`] borrows instead of copying.
+///
+/// A [`ColdString`] is one word, which can't hold both the address and the length of a
+/// `&'static str`. A `StaticStr` holds them instead, and the [`ColdString`] points to it like it
+/// would to a heap block. Declare it in a `static`, or use the [`cold!`](crate::cold) macro.
+///
+/// # Examples
+/// ```
+/// use cold_string::{ColdString, StaticStr};
+///
+/// static CONTENT_TYPE: StaticStr = StaticStr::new("content-type");
+///
+/// let s = ColdString::from_static(&CONTENT_TYPE);
+/// assert_eq!(s, "content-type");
+/// ```
+// The first byte of a heap block is its length header, which is never 0 because heap strings
+// are longer than `WIDTH`, or because of the marker bit of a fixed header. A 0 `marker` at the
+// same offset tells the two apart.
+#[repr(C, align(4))]
+pub struct StaticStr {
+    marker: u8,
+    s: &'static str,
+}
+
+/// Creates a [`ColdString`] from a string literal without allocating.
+///
+/// Literals of up to `core::mem::size_of::<usize>()` bytes are inline, and longer literals
+/// borrow a hidden [`StaticStr`], like [`ColdString::from_static`].
+///
+/// Long literals can't be encoded at compile time, so `cold!` can't initialize a `const` or
+/// `static`. For those, `cold!(const "...")` creates an inline string at compile time with
+/// [`ColdString::new_inline_const`], and fails to compile if the literal is too long. This
+/// form needs Rust 1.61.
+///
+/// # Examples
+/// ```
+/// use cold_string::{cold, ColdString};
+///
+/// const SHORT: ColdString = cold!(const "cold");
+///
+/// let long = cold!("a literal that is too long to be inline");
+/// assert_eq!(SHORT, "cold");
+/// assert_eq!(long, "a literal that is too long to be inline");
+/// ```
+///
+/// Only literals are accepted:
+/// ```compile_fail
+/// let name = "cold";
+/// let s = cold_string::cold!(name);
+/// ```
+#[macro_export]
+macro_rules! cold {
+    (const $lit:literal) => {
+        $crate::ColdString::new_inline_const($lit)
+    };
+    ($lit:literal) => {{
+        static STATIC_STR: $crate::StaticStr = $crate::StaticStr::new($lit);
+        $crate::ColdString::from_static(&STATIC_STR)
+    }};
+}
+
+impl StaticStr {
+    /// Wraps a `'static` string.
+    #[inline]
+    pub const fn new(s: &'static str) -> Self {
+        Self { marker: 0, s }
+    }
+
+    /// Returns the wrapped string.
+    #[inline]
+    pub const fn as_str(&self) -> &'static str {
+        self.s
+    }
+}
+
+impl ColdString {
+    /// Creates a [`ColdString`] that borrows a `'static` string instead of copying it, so it
+    /// never allocates.
+    ///
+    /// Strings of up to `core::mem::size_of::<usize>()` bytes are inline as usual. Longer
+    /// strings point to `s`, and cloning or dropping them is free.
+    ///
+    /// # Examples
+    /// ```
+    /// use cold_string::{ColdString, StaticStr};
+    ///
+    /// static GREETING: StaticStr = StaticStr::new("hello from a static string");
+    ///
+    /// let s = ColdString::from_static(&GREETING);
+    /// assert_eq!(s, ColdString::new("hello from a static string"));
+    /// assert_eq!(s.clone(), s);
+    /// ```
+    ///
+    /// This isn't a `const fn`, because the string encodes the address of `s`, which a const
+    /// context can't inspect. Only inline strings can be built in a const context, with
+    /// [`ColdString::new_inline_const`]:
+    /// ```compile_fail
+    /// use cold_string::{ColdString, StaticStr};
+    ///
+    /// static GREETING: StaticStr = StaticStr::new("hello from a static string");
+    /// const S: ColdString = ColdString::from_static(&GREETING);
+    /// ```
+    #[inline]
+    pub fn from_static(s: &'static StaticStr) -> Self {
+        if s.s.len() <= WIDTH {
+            return Self::new_inline(s.s);
+        }
+        // SAFETY: `StaticStr` is aligned like a heap block, starts with a byte that no heap
+        // block starts with, and lives forever
+        unsafe { Self::from_heap_ptr(s as *const StaticStr as *mut u8) }
+    }
+
+    /// Returns `true` if this string borrows a [`StaticStr`].
+    #[rustversion::attr(since(1.61), const)]
+    #[inline]
+    pub(crate) fn is_static(&self) -> bool {
+        // SAFETY: non-inline strings point to a heap block or a `StaticStr`, which both have
+        // at least one byte
+        !self.is_inline() && unsafe { Self::is_static_ptr(self.heap_ptr()) }
+    }
+
+    /// SAFETY: `ptr` must be the pointer of a non-inline string.
+    #[rustversion::attr(since(1.61), const)]
+    #[inline]
+    pub(crate) unsafe fn is_static_ptr(ptr: *const u8) -> bool {
+        *ptr == 0
+    }
+
+    /// SAFETY: `ptr` must be the pointer of a static string.
+    #[rustversion::attr(since(1.61), const)]
+    #[inline]
+    pub(crate) unsafe fn static_str<'a>(ptr: *const u8) -> &'a str {
+        (*(ptr as *const StaticStr)).s
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::string::String;
+
+    static SHORT: StaticStr = StaticStr::new("cold");
+    static FULL: StaticStr = StaticStr::new("12345678");
+    static LONG: StaticStr = StaticStr::new("a static string long enough for the heap");
+    static LONGER: StaticStr = StaticStr::new(
+        "a static string with a length header of two bytes if it were on the heap, which is \
+         a string of more than one hundred and twenty seven bytes, like this one",
+    );
+
+    #[test]
+    fn from_static() {
+        for st in [&SHORT, &FULL, &LONG, &LONGER] {
+            let s = st.as_str();
+            let cold = ColdString::from_static(st);
+            assert_eq!(cold.is_static(), s.len() > WIDTH);
+            assert_eq!(cold.is_inline(), s.len() <= WIDTH);
+            assert_eq!(cold.len(), s.len());
+            assert_eq!(cold.as_str(), s);
+            if cold.is_static() {
+                assert_eq!(cold.as_str().as_ptr(), s.as_ptr());
+            }
+
+            let heap = ColdString::new(s);
+            assert!(!heap.is_static());
+            assert_eq!(cold, heap);
+            assert_eq!(heap, cold);
+            assert_eq!(cold.cmp(&heap), core::cmp::Ordering::Equal);
+            assert_eq!(cold, cold.clone());
+            assert_eq!(cold.clone().is_static(), cold.is_static());
+            assert_eq!(String::from(cold.clone()), s);
+            drop(cold);
+        }
+        assert_ne!(
+            ColdString::from_static(&LONG),
+            ColdString::from_static(&LONGER)
+        );
+        assert_ne!(
+            ColdString::from_static(&LONG),
+            ColdString::new(LONGER.as_str())
+        );
+    }
+
+    #[test]
+    fn cold_macro() {
+        let short = cold!("cold");
+        assert!(short.is_inline());
+        assert_eq!(short, "cold");
+        let long = cold!("a literal long enough for the heap");
+        assert!(long.is_static());
+        assert_eq!(long, "a literal long enough for the heap");
+        // Each expansion has its own `StaticStr`, and one expansion can run many times.
+        let strings: alloc::vec::Vec<ColdString> = (0..3)
+            .map(|_| cold!("another literal that is long"))
+            .collect();
+        assert!(strings.iter().all(|s| s == "another literal that is long"));
+        assert_eq!(strings[0].as_str().as_ptr(), strings[2].as_str().as_ptr());
+        assert_eq!(cold!(""), ColdString::EMPTY);
+    }
+}
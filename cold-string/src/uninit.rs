@@ -0,0 +1,282 @@
+use crate::{ColdString, WIDTH};
+
+use alloc::{alloc::dealloc, str::Utf8Error};
+use core::{
+    mem::{self, MaybeUninit},
+    ptr, slice, str,
+};
+
+/// A [`ColdString`] of a known length whose bytes are filled in before it is sealed, e.g. from
+/// several non-contiguous chunks.
+///
+/// Created by [`ColdString::with_len`]. The bytes start out zeroed, so reading back a partly
+/// filled buffer is safe, and [`finish`](Self::finish) validates the UTF-8 only once. Callers
+/// that write every byte can skip the zeroing with [`ColdString::with_len_uninit`].
+///
+/// # Examples
+/// ```
+/// use cold_string::ColdString;
+///
+/// let mut s = ColdString::with_len(11);
+/// s.write_at(6, b"world");
+/// s.write_at(0, b"hello ");
+/// assert_eq!(s.finish().unwrap(), "hello world");
+/// ```
+pub struct UninitColdString {
+    repr: Repr,
+}
+
+enum Repr {
+    Inline {
+        buf: [u8; WIDTH],
+        len: usize,
+    },
+    Heap {
+        ptr: *mut u8,
+        header: usize,
+        len: usize,
+    },
+}
+
+unsafe impl Send for UninitColdString {}
+unsafe impl Sync for UninitColdString {}
+
+impl ColdString {
+    /// Creates an [`UninitColdString`] of `len` zeroed bytes, which becomes a [`ColdString`]
+    /// once filled in and [finished](UninitColdString::finish).
+    ///
+    /// Strings longer than `core::mem::size_of::<usize>()` are allocated right away, so the
+    /// bytes are written directly into their final heap block.
+    pub fn with_len(len: usize) -> UninitColdString {
+        Self::with_len_inner(len, true)
+    }
+
+    /// Like [`ColdString::with_len`], but the bytes of a heap string are left uninitialized,
+    /// for callers that overwrite all of them.
+    ///
+    /// # Safety
+    /// Every byte must be written, with [`UninitColdString::write_at`] or through
+    /// [`UninitColdString::writer_uninit`], before the bytes are read by
+    /// [`UninitColdString::writer`] or by finishing the string.
+    #[inline]
+    pub unsafe fn with_len_uninit(len: usize) -> UninitColdString {
+        Self::with_len_inner(len, false)
+    }
+
+    #[inline]
+    fn with_len_inner(len: usize, zeroed: bool) -> UninitColdString {
+        let repr = if len <= WIDTH {
+            Repr::Inline {
+                buf: [0; WIDTH],
+                len,
+            }
+        } else {
+            let (ptr, header) = ColdString::alloc_heap(len, zeroed);
+            Repr::Heap { ptr, header, len }
+        };
+        UninitColdString { repr }
+    }
+}
+
+impl UninitColdString {
+    /// Returns the length of the string being built, in bytes.
+    #[inline]
+    pub fn len(&self) -> usize {
+        match self.repr {
+            Repr::Inline { len, .. } | Repr::Heap { len, .. } => len,
+        }
+    }
+
+    /// Returns `true` if the string being built is empty.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the bytes of the string being built, for writing. Bytes not written yet are zero.
+    #[inline]
+    pub fn writer(&mut self) -> &mut [u8] {
+        match &mut self.repr {
+            Repr::Inline { buf, len } => &mut buf[..*len],
+            // SAFETY: the block holds `len` zeroed or written bytes after the header
+            Repr::Heap { ptr, header, len } => unsafe {
+                slice::from_raw_parts_mut(ptr.add(*header), *len)
+            },
+        }
+    }
+
+    /// Returns the bytes of the string being built, for writing, without assuming that they
+    /// are initialized.
+    ///
+    /// Only initialized bytes may be written, since an inline string's buffer is a `[u8]`.
+    #[inline]
+    pub fn writer_uninit(&mut self) -> &mut [MaybeUninit<u8>] {
+        let (ptr, len) = match &mut self.repr {
+            Repr::Inline { buf, len } => (buf.as_mut_ptr(), *len),
+            // SAFETY: the block holds `len` bytes after the header
+            Repr::Heap { ptr, header, len } => (unsafe { ptr.add(*header) }, *len),
+        };
+        // SAFETY: `ptr` is valid for `len` bytes, and `MaybeUninit<u8>` has the layout of `u8`
+        unsafe { slice::from_raw_parts_mut(ptr as *mut MaybeUninit<u8>, len) }
+    }
+
+    /// Copies `bytes` into the string being built, starting at byte `offset`. Later writes
+    /// overwrite earlier ones where they overlap.
+    ///
+    /// # Panics
+    /// Panics if `offset + bytes.len()` is greater than the length of the string.
+    #[inline]
+    #[track_caller]
+    pub fn write_at(&mut self, offset: usize, bytes: &[u8]) {
+        let len = self.len();
+        match offset.checked_add(bytes.len()) {
+            Some(end) if end <= len => write_slice(&mut self.writer_uninit()[offset..end], bytes),
+            _ => panic!(
+                "write of {} bytes at offset {} is out of bounds for length {}",
+                bytes.len(),
+                offset,
+                len
+            ),
+        }
+    }
+
+    /// Validates the bytes as UTF-8 and seals them into a [`ColdString`].
+    ///
+    /// # Errors
+    /// Returns the UTF-8 error if the bytes are not valid UTF-8, after freeing the allocation.
+    pub fn finish(mut self) -> Result<ColdString, Utf8Error> {
+        str::from_utf8(self.writer())?;
+        // SAFETY: the bytes were just validated
+        Ok(unsafe { self.finish_unchecked() })
+    }
+
+    /// Seals the bytes into a [`ColdString`] without checking that they are valid UTF-8.
+    ///
+    /// # Safety
+    /// The bytes written must be valid UTF-8.
+    pub unsafe fn finish_unchecked(self) -> ColdString {
+        let this = mem::ManuallyDrop::new(self);
+        match this.repr {
+            Repr::Inline { ref buf, len } => {
+                ColdString::new_inline(str::from_utf8_unchecked(&buf[..len]))
+            }
+            // The allocation is handed over to the `ColdString`.
+            Repr::Heap { ptr, .. } => ColdString::from_heap_ptr(ptr),
+        }
+    }
+}
+
+/// Copies `src` into `dst`, like `<[u8]>::copy_from_slice`.
+///
+/// # Panics
+/// Panics if the slices have different lengths.
+#[inline]
+#[track_caller]
+pub(crate) fn write_slice(dst: &mut [MaybeUninit<u8>], src: &[u8]) {
+    assert_eq!(dst.len(), src.len(), "slices have different lengths");
+    // SAFETY: both are valid for `src.len()` bytes, and `dst` is borrowed mutably, so they
+    // don't overlap
+    unsafe { ptr::copy_nonoverlapping(src.as_ptr(), dst.as_mut_ptr() as *mut u8, src.len()) }
+}
+
+/// Zeroes `dst`, for the tail of a string whose parts turned out shorter than measured.
+#[inline]
+pub(crate) fn write_zeros(dst: &mut [MaybeUninit<u8>]) {
+    for byte in dst {
+        *byte = MaybeUninit::new(0);
+    }
+}
+
+impl Drop for UninitColdString {
+    fn drop(&mut self) {
+        if let Repr::Heap { ptr, len, .. } = self.repr {
+            // SAFETY: ptr was allocated by alloc_heap() with this layout
+            unsafe { dealloc(ptr, ColdString::allocated_layout(len)) };
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fill_in_chunks() {
+        for s in [
+            "",
+            "a",
+            "1234567",
+            "12345678",
+            "123456789",
+            "🦀💯",
+            "a longer string on the heap",
+        ] {
+            let mut uninit = ColdString::with_len(s.len());
+            assert_eq!(uninit.len(), s.len());
+            let mid = s.len() / 2;
+            uninit.write_at(mid, &s.as_bytes()[mid..]);
+            uninit.write_at(0, &s.as_bytes()[..mid]);
+            let cold = uninit.finish().unwrap();
+            assert_eq!(cold, s);
+            assert_eq!(cold.is_inline(), s.len() <= WIDTH);
+        }
+    }
+
+    #[test]
+    fn partial_and_overlapping_writes() {
+        let mut uninit = ColdString::with_len(12);
+        assert_eq!(uninit.writer(), [0; 12]);
+        uninit.write_at(2, b"xxxxx");
+        uninit.write_at(4, b"yy");
+        assert_eq!(uninit.writer(), b"\0\0xxyyx\0\0\0\0\0");
+        uninit.writer()[11] = b'!';
+        assert_eq!(uninit.finish().unwrap(), "\0\0xxyyx\0\0\0\0!");
+
+        let mut uninit = ColdString::with_len(WIDTH);
+        uninit.write_at(WIDTH, b"");
+        assert_eq!(
+            uninit.finish().unwrap(),
+            str::from_utf8(&[0; WIDTH]).unwrap()
+        );
+    }
+
+    #[test]
+    fn fill_uninit() {
+        for s in ["", "1234567", "12345678", "a longer string on the heap"] {
+            // SAFETY: every byte is written below
+            let mut uninit = unsafe { ColdString::with_len_uninit(s.len()) };
+            let mid = s.len() / 2;
+            write_slice(&mut uninit.writer_uninit()[..mid], &s.as_bytes()[..mid]);
+            uninit.write_at(mid, &s.as_bytes()[mid..]);
+            assert_eq!(uninit.finish().unwrap(), s);
+        }
+        // Dropped without finishing, after every byte is written.
+        let mut uninit = unsafe { ColdString::with_len_uninit(20) };
+        uninit.write_at(0, &[b'a'; 20]);
+        drop(uninit);
+    }
+
+    #[test]
+    fn invalid_utf8() {
+        for len in [4, 20] {
+            let mut uninit = ColdString::with_len(len);
+            uninit.write_at(1, &[0xf0, 0x9f, 0xa6]);
+            let err = uninit.finish().unwrap_err();
+            assert_eq!(err.valid_up_to(), 1);
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "out of bounds")]
+    fn write_out_of_bounds() {
+        let mut uninit = ColdString::with_len(20);
+        uninit.write_at(18, b"abc");
+    }
+
+    #[test]
+    #[should_panic(expected = "out of bounds")]
+    fn write_offset_overflow() {
+        let mut uninit = ColdString::with_len(2);
+        uninit.write_at(usize::MAX, b"a");
+    }
+}
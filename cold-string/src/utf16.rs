@@ -0,0 +1,276 @@
+use crate::ColdString;
+
+use core::{char, fmt};
+
+/// The error returned by [`ColdString::from_utf16`] for input containing an unpaired surrogate.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct FromUtf16Error {
+    _private: (),
+}
+
+impl fmt::Display for FromUtf16Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("invalid utf-16: lone surrogate found")
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for FromUtf16Error {}
+
+impl ColdString {
+    /// Decodes native-endian UTF-16 into a [`ColdString`].
+    ///
+    /// The input is decoded twice, once to measure the UTF-8 length and once to write it
+    /// straight into the inline buffer or an exactly sized heap block.
+    ///
+    /// # Errors
+    /// Returns [`FromUtf16Error`] if `v` contains an unpaired surrogate.
+    ///
+    /// # Examples
+    /// ```
+    /// use cold_string::ColdString;
+    ///
+    /// let crab = [0xd83e, 0xdd80, 0x0063];
+    /// assert_eq!(ColdString::from_utf16(&crab).unwrap(), "🦀c");
+    /// assert!(ColdString::from_utf16(&[0xd83e, 0x0063]).is_err());
+    /// ```
+    pub fn from_utf16(v: &[u16]) -> Result<Self, FromUtf16Error> {
+        Self::decode_utf16(v.iter().copied(), false)
+    }
+
+    /// Decodes native-endian UTF-16 into a [`ColdString`], replacing unpaired surrogates with
+    /// [`U+FFFD REPLACEMENT CHARACTER`](char::REPLACEMENT_CHARACTER).
+    ///
+    /// # Examples
+    /// ```
+    /// use cold_string::ColdString;
+    ///
+    /// assert_eq!(ColdString::from_utf16_lossy(&[0xd83e, 0x0063]), "\u{fffd}c");
+    /// ```
+    pub fn from_utf16_lossy(v: &[u16]) -> Self {
+        Self::decode_utf16_lossy(v.iter().copied())
+    }
+
+    /// Decodes little-endian UTF-16 bytes into a [`ColdString`].
+    ///
+    /// A byte order mark is not stripped, and decodes to U+FEFF.
+    ///
+    /// # Errors
+    /// Returns [`FromUtf16Error`] if `v` has an odd length or contains an unpaired surrogate.
+    ///
+    /// # Examples
+    /// ```
+    /// use cold_string::ColdString;
+    ///
+    /// assert_eq!(ColdString::from_utf16le(&[0x3e, 0xd8, 0x80, 0xdd]).unwrap(), "🦀");
+    /// assert!(ColdString::from_utf16le(&[0x63, 0x00, 0x63]).is_err());
+    /// ```
+    pub fn from_utf16le(v: &[u8]) -> Result<Self, FromUtf16Error> {
+        if v.len() % 2 != 0 {
+            return Err(FromUtf16Error { _private: () });
+        }
+        Self::decode_utf16(units(v, u16::from_le_bytes), false)
+    }
+
+    /// Decodes big-endian UTF-16 bytes into a [`ColdString`].
+    ///
+    /// A byte order mark is not stripped, and decodes to U+FEFF.
+    ///
+    /// # Errors
+    /// Returns [`FromUtf16Error`] if `v` has an odd length or contains an unpaired surrogate.
+    ///
+    /// # Examples
+    /// ```
+    /// use cold_string::ColdString;
+    ///
+    /// assert_eq!(ColdString::from_utf16be(&[0xd8, 0x3e, 0xdd, 0x80]).unwrap(), "🦀");
+    /// ```
+    pub fn from_utf16be(v: &[u8]) -> Result<Self, FromUtf16Error> {
+        if v.len() % 2 != 0 {
+            return Err(FromUtf16Error { _private: () });
+        }
+        Self::decode_utf16(units(v, u16::from_be_bytes), false)
+    }
+
+    /// Decodes little-endian UTF-16 bytes into a [`ColdString`], replacing unpaired surrogates
+    /// and an odd trailing byte with
+    /// [`U+FFFD REPLACEMENT CHARACTER`](char::REPLACEMENT_CHARACTER).
+    ///
+    /// # Examples
+    /// ```
+    /// use cold_string::ColdString;
+    ///
+    /// assert_eq!(ColdString::from_utf16le_lossy(&[0x63, 0x00, 0x63]), "c\u{fffd}");
+    /// ```
+    pub fn from_utf16le_lossy(v: &[u8]) -> Self {
+        Self::decode_utf16_lossy(units(v, u16::from_le_bytes).chain(odd_byte(v)))
+    }
+
+    /// Decodes big-endian UTF-16 bytes into a [`ColdString`], replacing unpaired surrogates
+    /// and an odd trailing byte with
+    /// [`U+FFFD REPLACEMENT CHARACTER`](char::REPLACEMENT_CHARACTER).
+    ///
+    /// # Examples
+    /// ```
+    /// use cold_string::ColdString;
+    ///
+    /// assert_eq!(ColdString::from_utf16be_lossy(&[0xd8, 0x3e, 0x00]), "\u{fffd}\u{fffd}");
+    /// ```
+    pub fn from_utf16be_lossy(v: &[u8]) -> Self {
+        Self::decode_utf16_lossy(units(v, u16::from_be_bytes).chain(odd_byte(v)))
+    }
+
+    fn decode_utf16_lossy<I: Iterator<Item = u16> + Clone>(units: I) -> Self {
+        match Self::decode_utf16(units, true) {
+            Ok(s) => s,
+            Err(_) => unreachable!("lossy decoding doesn't fail"),
+        }
+    }
+
+    /// Decodes UTF-16 code units in two passes, sizing the string exactly before writing it.
+    fn decode_utf16<I>(units: I, lossy: bool) -> Result<Self, FromUtf16Error>
+    where
+        I: Iterator<Item = u16> + Clone,
+    {
+        let decode = |c: Result<char, char::DecodeUtf16Error>| match c {
+            Ok(c) => Ok(c),
+            Err(_) if lossy => Ok(char::REPLACEMENT_CHARACTER),
+            Err(_) => Err(FromUtf16Error { _private: () }),
+        };
+        let mut len = 0usize;
+        for c in char::decode_utf16(units.clone()) {
+            len += decode(c)?.len_utf8();
+        }
+        let mut uninit = Self::with_len(len);
+        let writer = uninit.writer();
+        let mut pos = 0;
+        for c in char::decode_utf16(units) {
+            // The first pass already checked every char.
+            let c = decode(c).unwrap_or(char::REPLACEMENT_CHARACTER);
+            pos += c.encode_utf8(&mut writer[pos..]).len();
+        }
+        // SAFETY: the bytes are encoded chars
+        Ok(unsafe { uninit.finish_unchecked() })
+    }
+}
+
+/// Reads the code units of UTF-16 bytes, ignoring an odd trailing byte.
+fn units(v: &[u8], from_bytes: fn([u8; 2]) -> u16) -> impl Iterator<Item = u16> + Clone + '_ {
+    v.chunks_exact(2).map(move |c| from_bytes([c[0], c[1]]))
+}
+
+/// Returns a lone surrogate, which lossy decoding replaces, if `v` has an odd trailing byte.
+fn odd_byte(v: &[u8]) -> Option<u16> {
+    if v.len() % 2 != 0 {
+        Some(0xd800)
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::WIDTH;
+    use alloc::{string::String, vec::Vec};
+
+    #[test]
+    fn valid() {
+        for s in [
+            "",
+            "cold",
+            "12345678",
+            "é🦀",
+            "🦀🦀",
+            "a string long enough for the heap 🦀",
+        ] {
+            let utf16: Vec<u16> = s.encode_utf16().collect();
+            let cold = ColdString::from_utf16(&utf16).unwrap();
+            assert_eq!(cold, s);
+            assert_eq!(cold.is_inline(), s.len() <= WIDTH);
+            assert_eq!(ColdString::from_utf16_lossy(&utf16), s);
+        }
+    }
+
+    #[test]
+    fn unpaired_surrogates() {
+        for v in [
+            &[0xd800][..],
+            &[0xdc00],
+            &[0x61, 0xd83e],
+            &[0xdd80, 0xd83e],
+            &[0xd83e, 0xd83e, 0xdd80],
+        ] {
+            assert_eq!(
+                ColdString::from_utf16(v).unwrap_err(),
+                FromUtf16Error { _private: () }
+            );
+            assert!(String::from_utf16(v).is_err());
+            assert_eq!(
+                ColdString::from_utf16_lossy(v),
+                String::from_utf16_lossy(v).as_str()
+            );
+        }
+        // Replacement chars are 3 bytes, so a few of them spill to the heap.
+        let lossy = ColdString::from_utf16_lossy(&[0xd800, 0xd800, 0xd800]);
+        assert_eq!(lossy, "\u{fffd}\u{fffd}\u{fffd}");
+        assert_eq!(lossy.is_inline(), 9 <= WIDTH);
+    }
+
+    fn to_bytes(s: &str, to_bytes: fn(u16) -> [u8; 2]) -> Vec<u8> {
+        s.encode_utf16().flat_map(to_bytes).collect()
+    }
+
+    #[test]
+    fn endian_explicit() {
+        for s in [
+            "",
+            "c",
+            "\u{feff}cold",
+            "é🦀",
+            "a string long enough for the heap 🦀",
+        ] {
+            let (le, be) = (to_bytes(s, u16::to_le_bytes), to_bytes(s, u16::to_be_bytes));
+            assert_eq!(ColdString::from_utf16le(&le).unwrap(), s);
+            assert_eq!(ColdString::from_utf16be(&be).unwrap(), s);
+            assert_eq!(ColdString::from_utf16le_lossy(&le), s);
+            assert_eq!(ColdString::from_utf16be_lossy(&be), s);
+            if !s.is_empty() && !s.is_ascii() {
+                assert_ne!(ColdString::from_utf16le_lossy(&be), s);
+            }
+        }
+        // The byte order mark is kept.
+        let bom = ColdString::from_utf16le(&[0xff, 0xfe, 0x63, 0x00]).unwrap();
+        assert_eq!(bom, "\u{feff}c");
+        let swapped = ColdString::from_utf16be(&[0xff, 0xfe, 0x00, 0x63]).unwrap();
+        assert_eq!(swapped, "\u{fffe}c");
+    }
+
+    #[test]
+    fn endian_explicit_errors() {
+        let err = Err(FromUtf16Error { _private: () });
+        // Odd length.
+        assert_eq!(ColdString::from_utf16le(&[0x63]), err);
+        assert_eq!(ColdString::from_utf16be(&[0x00, 0x63, 0x00]), err);
+        assert_eq!(ColdString::from_utf16le_lossy(&[0x63]), "\u{fffd}");
+        assert_eq!(
+            ColdString::from_utf16be_lossy(&[0x00, 0x63, 0x00]),
+            "c\u{fffd}"
+        );
+        // A surrogate pair cut off by the end of the input, with and without an odd byte.
+        let crab = to_bytes("🦀", u16::to_le_bytes);
+        assert_eq!(ColdString::from_utf16le(&crab[..2]), err);
+        assert_eq!(ColdString::from_utf16le(&crab[..3]), err);
+        assert_eq!(ColdString::from_utf16le_lossy(&crab[..2]), "\u{fffd}");
+        assert_eq!(
+            ColdString::from_utf16le_lossy(&crab[..3]),
+            "\u{fffd}\u{fffd}"
+        );
+        // A surrogate pair split by a unit in between.
+        let mut split = crab[..2].to_vec();
+        split.extend_from_slice(&[0x63, 0x00]);
+        split.extend_from_slice(&crab[2..]);
+        assert_eq!(ColdString::from_utf16le(&split), err);
+        assert_eq!(ColdString::from_utf16le_lossy(&split), "\u{fffd}c\u{fffd}");
+    }
+}
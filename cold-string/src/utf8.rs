@@ -0,0 +1,181 @@
+use crate::{ColdString, WIDTH};
+
+use alloc::vec::Vec;
+use core::{fmt, str, str::Utf8Error};
+
+/// The error returned by [`ColdString::from_utf8_owned`], which gives back the bytes that
+/// failed to convert.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FromUtf8Error {
+    bytes: Vec<u8>,
+    error: Utf8Error,
+}
+
+impl FromUtf8Error {
+    /// Returns the bytes that were attempted to convert.
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.bytes
+    }
+
+    /// Returns the bytes that were attempted to convert, without copying them.
+    pub fn into_bytes(self) -> Vec<u8> {
+        self.bytes
+    }
+
+    /// Returns the [`Utf8Error`] describing where the conversion failed.
+    pub fn utf8_error(&self) -> Utf8Error {
+        self.error
+    }
+}
+
+impl fmt::Display for FromUtf8Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.error, f)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for FromUtf8Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        Some(&self.error)
+    }
+}
+
+impl ColdString {
+    /// Converts a vector of bytes into a [`ColdString`], giving the vector back on failure.
+    ///
+    /// The bytes are copied straight from `v` into the new string, and `v` is then dropped.
+    ///
+    /// # Errors
+    /// Returns a [`FromUtf8Error`] holding `v` if it is not valid UTF-8.
+    ///
+    /// # Examples
+    /// ```
+    /// use cold_string::ColdString;
+    ///
+    /// assert_eq!(ColdString::from_utf8_owned(b"cold".to_vec()).unwrap(), "cold");
+    ///
+    /// let err = ColdString::from_utf8_owned(vec![b'a', 0xff]).unwrap_err();
+    /// assert_eq!(err.utf8_error().valid_up_to(), 1);
+    /// assert_eq!(err.into_bytes(), [b'a', 0xff]);
+    /// ```
+    pub fn from_utf8_owned(v: Vec<u8>) -> Result<Self, FromUtf8Error> {
+        match str::from_utf8(&v) {
+            Ok(s) => Ok(Self::new(s)),
+            Err(error) => Err(FromUtf8Error { bytes: v, error }),
+        }
+    }
+
+    /// Converts bytes from an iterator into a [`ColdString`], validating them as they arrive.
+    ///
+    /// Bytes are collected into an inline buffer first, so strings of at most
+    /// `INLINE_CAPACITY` bytes don't allocate. Longer strings spill into a buffer reserved
+    /// from the iterator's size hint, and are copied once into their heap block at the end.
+    /// The buffer is validated each time its length doubles, so the iterator stops being
+    /// consumed soon after an invalid sequence.
+    ///
+    /// # Errors
+    /// Returns the same [`Utf8Error`] as [`str::from_utf8`] on all the bytes if they are not
+    /// valid UTF-8.
+    ///
+    /// # Examples
+    /// ```
+    /// use cold_string::ColdString;
+    ///
+    /// let record = [4u8, b'c', b'o', b'l', b'd', 0];
+    /// let len = record[0] as usize;
+    /// let s = ColdString::from_utf8_iter(record.iter().copied().skip(1).take(len)).unwrap();
+    /// assert_eq!(s, "cold");
+    ///
+    /// let err = ColdString::from_utf8_iter(vec![b'a', 0xff]).unwrap_err();
+    /// assert_eq!(err.valid_up_to(), 1);
+    /// ```
+    pub fn from_utf8_iter<I: IntoIterator<Item = u8>>(bytes: I) -> Result<Self, Utf8Error> {
+        let mut bytes = bytes.into_iter();
+        let mut inline = [0u8; WIDTH];
+        for (len, slot) in inline.iter_mut().enumerate() {
+            match bytes.next() {
+                Some(b) => *slot = b,
+                None => return Self::from_utf8(&inline[..len]),
+            }
+        }
+        let first = match bytes.next() {
+            Some(b) => b,
+            None => return Self::from_utf8(inline),
+        };
+        let reserve = bytes.size_hint().0.saturating_add(WIDTH + 1);
+        let mut buf = Vec::with_capacity(reserve.max(4 * WIDTH));
+        buf.extend_from_slice(&inline);
+        buf.push(first);
+        let (mut checked, mut next_check) = (0, 4 * WIDTH);
+        for b in bytes {
+            if buf.len() == next_check {
+                checked = check_prefix(&buf, checked)?;
+                next_check *= 2;
+            }
+            buf.push(b);
+        }
+        Self::from_utf8(&buf)
+    }
+}
+
+/// Validates `buf[checked..]`, allowing an incomplete char at the end, and returns the length
+/// of the valid prefix.
+fn check_prefix(buf: &[u8], checked: usize) -> Result<usize, Utf8Error> {
+    match str::from_utf8(&buf[checked..]) {
+        Ok(_) => Ok(buf.len()),
+        Err(e) if e.error_len().is_none() => Ok(checked + e.valid_up_to()),
+        // Validate again from the start, so the error is relative to all the bytes.
+        Err(_) => Err(str::from_utf8(buf).unwrap_err()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec;
+
+    #[test]
+    fn valid() {
+        for s in ["", "cold", "🦀💯", "a string long enough for the heap"] {
+            let cold = ColdString::from_utf8_owned(s.as_bytes().to_vec()).unwrap();
+            assert_eq!(cold, s);
+        }
+    }
+
+    #[test]
+    fn from_iter_stops_at_invalid_bytes() {
+        let mut consumed = 0;
+        let bytes = b"a long prefix before an invalid byte \xff"
+            .iter()
+            .copied()
+            .chain(core::iter::repeat(b'a').take(1 << 20))
+            .inspect(|_| consumed += 1);
+        let err = ColdString::from_utf8_iter(bytes).unwrap_err();
+        assert_eq!(err.valid_up_to(), 37);
+        assert_eq!(err.error_len(), Some(1));
+        assert!(consumed < 1024);
+    }
+
+    #[test]
+    fn recovers_bytes() {
+        for bytes in [
+            vec![0xff],
+            vec![b'a', b'b', 0xc3],
+            b"a long prefix before the invalid byte \xe2\x82".to_vec(),
+            b"\xf0\x9f\xa6\x80\x80".to_vec(),
+        ] {
+            let ptr = bytes.as_ptr();
+            let expected = str::from_utf8(&bytes).unwrap_err();
+            let err = ColdString::from_utf8_owned(bytes.clone()).unwrap_err();
+            assert_eq!(err.utf8_error(), expected);
+            assert_eq!(err.as_bytes(), &bytes[..]);
+            assert_eq!(err.into_bytes(), bytes);
+
+            // The original allocation is handed back.
+            let err = ColdString::from_utf8_owned(bytes).unwrap_err();
+            let recovered = err.into_bytes();
+            assert_eq!(recovered.as_ptr(), ptr);
+        }
+    }
+}
@@ -0,0 +1,294 @@
+use crate::ColdString;
+
+use core::fmt;
+
+/// The error returned by the validating constructors, such as [`ColdString::new_identifier`],
+/// when the string contains a character that isn't allowed.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct InvalidChar {
+    index: usize,
+    ch: Option<char>,
+}
+
+impl InvalidChar {
+    /// Returns the byte index of the offending character.
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    /// Returns the offending character, or `None` if the string was rejected for being empty.
+    pub fn invalid_char(&self) -> Option<char> {
+        self.ch
+    }
+}
+
+impl fmt::Display for InvalidChar {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.ch {
+            Some(ch) => write!(f, "invalid character {:?} at byte {}", ch, self.index),
+            None => f.write_str("empty string"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for InvalidChar {}
+
+/// The error returned by [`ColdString::new_xid_identifier`].
+#[cfg(feature = "unicode-ident")]
+pub type InvalidIdent = InvalidChar;
+
+impl ColdString {
+    /// Creates a new [`ColdString`] if every char of `s` satisfies `pred`.
+    ///
+    /// The string is checked in a single scan and then copied, with no intermediate
+    /// allocation. An empty string is always accepted.
+    ///
+    /// # Errors
+    /// Returns the byte index and value of the first char rejected by `pred`.
+    ///
+    /// # Examples
+    /// ```
+    /// use cold_string::ColdString;
+    ///
+    /// let s = ColdString::new_validated("hex0ff", |c| c.is_ascii_alphanumeric()).unwrap();
+    /// assert_eq!(s, "hex0ff");
+    ///
+    /// let err = ColdString::new_validated("a-b", |c| c.is_ascii_alphanumeric()).unwrap_err();
+    /// assert_eq!((err.index(), err.invalid_char()), (1, Some('-')));
+    /// ```
+    pub fn new_validated<F: Fn(char) -> bool>(s: &str, pred: F) -> Result<Self, InvalidChar> {
+        match s.char_indices().find(|&(_, c)| !pred(c)) {
+            Some((index, ch)) => Err(InvalidChar {
+                index,
+                ch: Some(ch),
+            }),
+            None => Ok(Self::new(s)),
+        }
+    }
+
+    /// Creates a new [`ColdString`] if `s` is an ASCII identifier: a letter or `_`, followed by
+    /// any number of letters, digits or `_`.
+    ///
+    /// # Errors
+    /// Returns the byte index and value of the first invalid char, or an error without a char
+    /// if `s` is empty.
+    ///
+    /// # Examples
+    /// ```
+    /// use cold_string::ColdString;
+    ///
+    /// assert_eq!(ColdString::new_identifier("_field_1").unwrap(), "_field_1");
+    /// assert_eq!(ColdString::new_identifier("1st").unwrap_err().index(), 0);
+    /// assert_eq!(ColdString::new_identifier("naïve").unwrap_err().invalid_char(), Some('ï'));
+    /// ```
+    pub fn new_identifier(s: &str) -> Result<Self, InvalidChar> {
+        match s.as_bytes().first() {
+            None => Err(InvalidChar { index: 0, ch: None }),
+            Some(b) if b.is_ascii_digit() => Err(InvalidChar {
+                index: 0,
+                ch: Some(*b as char),
+            }),
+            Some(_) => Self::new_validated(s, |c| c.is_ascii_alphanumeric() || c == '_'),
+        }
+    }
+
+    /// Creates a new [`ColdString`] if `s` is a non-empty string of ASCII digits.
+    ///
+    /// # Errors
+    /// Returns the byte index and value of the first non-digit char, or an error without a
+    /// char if `s` is empty.
+    ///
+    /// # Examples
+    /// ```
+    /// use cold_string::ColdString;
+    ///
+    /// assert_eq!(ColdString::new_digits("0042").unwrap(), "0042");
+    /// assert_eq!(ColdString::new_digits("-1").unwrap_err().invalid_char(), Some('-'));
+    /// assert!(ColdString::new_digits("").is_err());
+    /// ```
+    pub fn new_digits(s: &str) -> Result<Self, InvalidChar> {
+        if s.is_empty() {
+            return Err(InvalidChar { index: 0, ch: None });
+        }
+        Self::new_validated(s, |c| c.is_ascii_digit())
+    }
+
+    /// Creates a new [`ColdString`] if `s` is a Unicode identifier as defined by
+    /// [UAX #31](https://www.unicode.org/reports/tr31/): an `XID_Start` char, followed by any
+    /// number of `XID_Continue` chars.
+    ///
+    /// Unlike Rust identifiers, `_` is not allowed as the first char.
+    ///
+    /// # Errors
+    /// Returns the byte index and value of the first invalid char, or an error without a char
+    /// if `s` is empty.
+    ///
+    /// # Examples
+    /// ```
+    /// use cold_string::ColdString;
+    ///
+    /// assert_eq!(ColdString::new_xid_identifier("naïve").unwrap(), "naïve");
+    /// assert_eq!(ColdString::new_xid_identifier("λx").unwrap(), "λx");
+    /// assert_eq!(ColdString::new_xid_identifier("1st").unwrap_err().index(), 0);
+    /// ```
+    #[cfg(feature = "unicode-ident")]
+    pub fn new_xid_identifier(s: &str) -> Result<Self, InvalidIdent> {
+        match xid_error(s) {
+            Some(err) => Err(err),
+            None => Ok(Self::new(s)),
+        }
+    }
+
+    /// Returns `true` if `self` is a Unicode identifier, as accepted by
+    /// [`ColdString::new_xid_identifier`].
+    ///
+    /// # Examples
+    /// ```
+    /// use cold_string::ColdString;
+    ///
+    /// assert!(ColdString::new("变量").is_xid_identifier());
+    /// assert!(!ColdString::new("a-b").is_xid_identifier());
+    /// ```
+    #[cfg(feature = "unicode-ident")]
+    pub fn is_xid_identifier(&self) -> bool {
+        xid_error(self.as_str()).is_none()
+    }
+}
+
+#[cfg(feature = "unicode-ident")]
+fn xid_error(s: &str) -> Option<InvalidIdent> {
+    let mut chars = s.char_indices();
+    let ch = match chars.next() {
+        None => return Some(InvalidChar { index: 0, ch: None }),
+        Some((_, ch)) if !unicode_ident::is_xid_start(ch) => ch,
+        Some(_) => match chars.find(|&(_, c)| !unicode_ident::is_xid_continue(c)) {
+            None => return None,
+            Some((index, ch)) => {
+                return Some(InvalidChar {
+                    index,
+                    ch: Some(ch),
+                })
+            }
+        },
+    };
+    Some(InvalidChar {
+        index: 0,
+        ch: Some(ch),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::WIDTH;
+
+    fn err(index: usize, ch: char) -> Result<ColdString, InvalidChar> {
+        Err(InvalidChar {
+            index,
+            ch: Some(ch),
+        })
+    }
+
+    #[test]
+    fn identifiers() {
+        for s in [
+            "a",
+            "_",
+            "A1",
+            "snake_case",
+            "CamelCase",
+            "_0",
+            "a_long_identifier_on_heap",
+        ] {
+            assert_eq!(ColdString::new_identifier(s).unwrap(), s);
+        }
+        let empty = Err(InvalidChar { index: 0, ch: None });
+        assert_eq!(ColdString::new_identifier(""), empty);
+        assert_eq!(ColdString::new_identifier("0abc"), err(0, '0'));
+        assert_eq!(
+            ColdString::new_identifier("9_long_leading_digit"),
+            err(0, '9')
+        );
+        assert_eq!(ColdString::new_identifier("a-b"), err(1, '-'));
+        assert_eq!(ColdString::new_identifier("ab cd"), err(2, ' '));
+        assert_eq!(ColdString::new_identifier("é"), err(0, 'é'));
+        assert_eq!(ColdString::new_identifier("caf\u{e9}_au_lait"), err(3, 'é'));
+        assert_eq!(ColdString::new_identifier("x🦀"), err(1, '🦀'));
+    }
+
+    #[test]
+    fn digits() {
+        for s in ["0", "0123", "18446744073709551616000"] {
+            assert_eq!(ColdString::new_digits(s).unwrap(), s);
+        }
+        assert_eq!(
+            ColdString::new_digits(""),
+            Err(InvalidChar { index: 0, ch: None })
+        );
+        assert_eq!(ColdString::new_digits("12a"), err(2, 'a'));
+        assert_eq!(ColdString::new_digits("+1"), err(0, '+'));
+        assert_eq!(ColdString::new_digits("1\u{664}"), err(1, '\u{664}'));
+    }
+
+    #[test]
+    fn validated_boundary_lengths() {
+        for len in [WIDTH - 1, WIDTH, WIDTH + 1] {
+            let s = "7".repeat(len);
+            let cold = ColdString::new_digits(&s).unwrap();
+            assert_eq!(cold, s.as_str());
+            assert_eq!(cold.is_inline(), len <= WIDTH);
+            let ident = ColdString::new_identifier(&s.replacen('7', "_", 1)).unwrap();
+            assert_eq!(ident.len(), len);
+
+            let mut bad = s.clone();
+            bad.push('x');
+            assert_eq!(ColdString::new_digits(&bad), err(len, 'x'));
+        }
+        assert_eq!(ColdString::new_validated("", |_| false).unwrap(), "");
+        assert_eq!(ColdString::new_validated("abc", |c| c != 'c'), err(2, 'c'));
+    }
+
+    #[cfg(feature = "unicode-ident")]
+    #[test]
+    fn xid_identifiers() {
+        for s in [
+            "a",
+            "A1",
+            "snake_case",
+            "αβγ",
+            "Δx_1",
+            "变量名",
+            "日本語の識別子",
+            "e\u{301}",
+            "a\u{200c}b",
+            "nai\u{308}ve_combining",
+        ] {
+            assert_eq!(ColdString::new_xid_identifier(s).unwrap(), s);
+            assert!(ColdString::new(s).is_xid_identifier());
+        }
+        let empty = Err(InvalidChar { index: 0, ch: None });
+        assert_eq!(ColdString::new_xid_identifier(""), empty);
+        assert!(!ColdString::new("").is_xid_identifier());
+        assert_eq!(ColdString::new_xid_identifier("1st"), err(0, '1'));
+        assert_eq!(ColdString::new_xid_identifier("_x"), err(0, '_'));
+        // A combining mark can't start an identifier.
+        assert_eq!(
+            ColdString::new_xid_identifier("\u{301}e"),
+            err(0, '\u{301}')
+        );
+        // ZWJ is `XID_Continue` since Unicode 15.1, but not `XID_Start`.
+        assert_eq!(
+            ColdString::new_xid_identifier("\u{200d}b"),
+            err(0, '\u{200d}')
+        );
+        assert!(ColdString::new("a\u{200d}b").is_xid_identifier());
+        assert_eq!(ColdString::new_xid_identifier("🦀"), err(0, '🦀'));
+        assert_eq!(
+            ColdString::new_xid_identifier("long_name_then_🦀"),
+            err(15, '🦀')
+        );
+        assert_eq!(ColdString::new_xid_identifier("αβ-γ"), err(4, '-'));
+        assert!(!ColdString::new("a b").is_xid_identifier());
+    }
+}
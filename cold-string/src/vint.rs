@@ -1,6 +1,7 @@
 pub struct VarInt;
 
 impl VarInt {
+    #[cfg(test)]
     pub const fn write(mut value: u64) -> (usize, [u8; 10]) {
         let mut buf = [0u8; 10];
         let mut i = 0;
@@ -19,7 +20,34 @@ impl VarInt {
         (i, buf)
     }
 
+    /// Returns the number of bytes `value` is encoded in.
+    pub const fn encoded_len(value: u64) -> usize {
+        let bits = 64 - value.leading_zeros() as usize;
+        if bits == 0 {
+            1
+        } else {
+            (bits + 6) / 7
+        }
+    }
+
+    /// Writes `value` to `ptr`, returning the number of bytes written.
+    ///
+    /// SAFETY: `ptr` must be valid for writes of `encoded_len(value)` bytes.
+    #[allow(unsafe_op_in_unsafe_fn)]
+    #[inline]
+    pub unsafe fn write_to(ptr: *mut u8, mut value: u64) -> usize {
+        let mut i = 0;
+        while value >= 0x80 {
+            *ptr.add(i) = value as u8 | 0x80;
+            value >>= 7;
+            i += 1;
+        }
+        *ptr.add(i) = value as u8;
+        i + 1
+    }
+
     #[allow(unsafe_op_in_unsafe_fn)]
+    #[rustversion::attr(since(1.61), const)]
     pub unsafe fn read(ptr: *const u8) -> (usize, usize) {
         let mut result = 0usize;
         let mut shift = 0;
@@ -44,11 +72,15 @@ mod tests {
 
     fn assert_correct(x: u64) {
         let (wrote, b) = VarInt::write(x);
-        assert!(wrote >= 1 && wrote <= 10);
+        assert!((1..=10).contains(&wrote));
         let ptr = b.as_ptr();
         let (y, read) = unsafe { VarInt::read(ptr) };
         assert_eq!(wrote, read);
         assert_eq!(x, y as u64);
+        assert_eq!(VarInt::encoded_len(x), wrote);
+        let mut direct = [0xffu8; 10];
+        assert_eq!(unsafe { VarInt::write_to(direct.as_mut_ptr(), x) }, wrote);
+        assert_eq!(direct[..wrote], b[..wrote]);
     }
 
     #[test]
@@ -0,0 +1,703 @@
+use cold_string::*;
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::cell::Cell;
+
+#[global_allocator]
+static A: CountingAlloc = CountingAlloc;
+
+/// Counts allocations made by the current thread, so tests running in parallel don't interfere.
+pub struct CountingAlloc;
+
+thread_local! {
+    static ALLOCS: Cell<usize> = const { Cell::new(0) };
+    static DEALLOCS: Cell<usize> = const { Cell::new(0) };
+}
+
+unsafe impl GlobalAlloc for CountingAlloc {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let _ = ALLOCS.try_with(|c| c.set(c.get() + 1));
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        let _ = DEALLOCS.try_with(|c| c.set(c.get() + 1));
+        System.dealloc(ptr, layout)
+    }
+}
+
+#[derive(Debug, PartialEq)]
+struct Counts {
+    allocs: usize,
+    deallocs: usize,
+}
+
+fn count<T>(f: impl FnOnce() -> T) -> (T, Counts) {
+    let allocs = ALLOCS.with(|c| c.get());
+    let deallocs = DEALLOCS.with(|c| c.get());
+    let res = f();
+    let counts = Counts {
+        allocs: ALLOCS.with(|c| c.get()) - allocs,
+        deallocs: DEALLOCS.with(|c| c.get()) - deallocs,
+    };
+    (res, counts)
+}
+
+const WIDTH: usize = core::mem::size_of::<usize>();
+
+#[test]
+fn collect_lines_allocates_vec_once() {
+    let text = "short\nline two\r\na line long enough for the heap\n\n🦀\n";
+    let cold = ColdString::new(text);
+    let heap_lines = text.lines().filter(|l| l.len() > WIDTH).count();
+    let (lines, counts) = count(|| cold.collect_lines());
+    assert_eq!(counts.allocs, 1 + heap_lines);
+    assert_eq!(counts.deallocs, 0);
+    assert_eq!(lines.len(), text.lines().count());
+}
+
+#[test]
+fn collect_split_allocates_vec_once() {
+    let text = "a,bb,,a field long enough for the heap,ccc,another long heap field";
+    let cold = ColdString::new(text);
+    let heap_parts = text.split(',').filter(|p| p.len() > WIDTH).count();
+    let (parts, counts) = count(|| cold.collect_split(","));
+    assert_eq!(counts.allocs, 1 + heap_parts);
+    assert_eq!(counts.deallocs, 0);
+    assert_eq!(parts.len(), text.split(',').count());
+}
+
+/// Allocations for a `Vec` of `parts` whose capacity is exact: the `Vec` itself unless it is
+/// empty, and the heap parts.
+fn exact_collect_allocs(parts: &[&str]) -> usize {
+    !parts.is_empty() as usize + parts.iter().filter(|p| p.len() > WIDTH).count()
+}
+
+#[test]
+fn collect_lines_and_split_allocate_exactly() {
+    let texts = [
+        "",
+        "\n",
+        "\n\n\r\n",
+        "short",
+        "a\nb\r\nc\n",
+        "no trailing newline\nlast",
+        "a line long enough for the heap\r\n\r\n🦀🦀🦀\n",
+    ];
+    for text in texts {
+        let cold = ColdString::new(text);
+        let expected: Vec<&str> = text.lines().collect();
+        let (lines, counts) = count(|| cold.collect_lines());
+        assert_eq!(lines, expected, "{:?}", text);
+        assert_eq!(lines.capacity(), lines.len(), "{:?}", text);
+        assert_eq!(
+            counts,
+            Counts {
+                allocs: exact_collect_allocs(&expected),
+                deallocs: 0
+            },
+            "{:?}",
+            text
+        );
+    }
+
+    let splits = [
+        ("", ","),
+        ("a,b,c", ","),
+        ("trailing,", ","),
+        (",,", ","),
+        ("key::value::a field long enough for the heap::", "::"),
+        ("aaaaa", "aa"),
+        ("cold", ""),
+        ("no separator but long enough for the heap", ";"),
+        ("🦀=🦀==🦀", "=="),
+    ];
+    for (text, pat) in splits {
+        let cold = ColdString::new(text);
+        let expected: Vec<&str> = text.split(pat).collect();
+        let (parts, counts) = count(|| cold.collect_split(pat));
+        assert_eq!(parts, expected, "{:?} {:?}", text, pat);
+        assert_eq!(parts.capacity(), parts.len(), "{:?} {:?}", text, pat);
+        assert_eq!(
+            counts,
+            Counts {
+                allocs: exact_collect_allocs(&expected),
+                deallocs: 0
+            },
+            "{:?} {:?}",
+            text,
+            pat
+        );
+    }
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn as_ref_path_does_not_allocate() {
+    use std::{ffi::OsStr, path::Path};
+
+    for s in ["dir/a", "a/much/longer/path/on/the/heap.txt"] {
+        let cold = ColdString::new(s);
+        let (path, counts) = count(|| AsRef::<Path>::as_ref(&cold).extension().is_some());
+        assert_eq!(
+            counts,
+            Counts {
+                allocs: 0,
+                deallocs: 0
+            }
+        );
+        assert_eq!(path, Path::new(s).extension().is_some());
+        let (len, counts) = count(|| AsRef::<OsStr>::as_ref(&cold).len());
+        assert_eq!(
+            counts,
+            Counts {
+                allocs: 0,
+                deallocs: 0
+            }
+        );
+        assert_eq!(len, s.len());
+    }
+}
+
+#[test]
+fn into_string_copies_once_and_frees() {
+    let cold = ColdString::new("cold");
+    let (s, counts) = count(|| cold.into_string());
+    assert_eq!((counts.allocs, counts.deallocs), (1, 0));
+    assert_eq!(s.capacity(), s.len());
+
+    let cold = ColdString::new("a string long enough for the heap");
+    let (s, counts) = count(|| cold.into_string());
+    assert_eq!((counts.allocs, counts.deallocs), (1, 1));
+    assert_eq!(s.capacity(), s.len());
+
+    let cold = ColdString::new("another string long enough for the heap");
+    let (s, counts) = count(|| String::from(&cold));
+    assert_eq!((counts.allocs, counts.deallocs), (1, 0));
+    assert_eq!(s, cold);
+    let (_, counts) = count(|| drop(cold));
+    assert_eq!((counts.allocs, counts.deallocs), (0, 1));
+}
+
+#[test]
+fn into_boxed_str_copies_once_and_frees() {
+    for s in ["", "cold", "a string long enough for the heap"] {
+        let cold = ColdString::new(s);
+        let heap = !cold.is_inline();
+        let (b, counts) = count(|| Box::<str>::from(cold));
+        assert_eq!(counts.allocs, !s.is_empty() as usize);
+        assert_eq!(counts.deallocs, heap as usize);
+        assert_eq!(&*b, s);
+    }
+}
+
+#[test]
+fn collect_fragments_allocates_block_once() {
+    let parts = ["co", "ld"];
+    let (cold, counts) = count(|| parts.iter().copied().collect::<ColdString>());
+    assert_eq!((counts.allocs, counts.deallocs), (0, 0));
+    assert_eq!(cold, "cold");
+
+    // One allocation holds the remaining parts, and one is the final heap block.
+    let parts = ["a string ", "long enough ", "for the heap"];
+    let (cold, counts) = count(|| parts.iter().copied().collect::<ColdString>());
+    assert_eq!((counts.allocs, counts.deallocs), (2, 1));
+    assert_eq!(cold, parts.concat().as_str());
+}
+
+#[test]
+fn collect_chars_inline_does_not_allocate() {
+    let (cold, counts) = count(|| "cold".chars().collect::<ColdString>());
+    assert_eq!((counts.allocs, counts.deallocs), (0, 0));
+    assert_eq!(cold, "cold");
+
+    let s = "a string long enough for the heap";
+    let (cold, counts) = count(|| s.chars().collect::<ColdString>());
+    assert_eq!((counts.allocs, counts.deallocs), (2, 1));
+    assert_eq!(cold, s);
+}
+
+#[test]
+fn add_allocates_result_once() {
+    let cold = ColdString::new("cold");
+    let (sum, counts) = count(|| cold + "-ish");
+    assert_eq!((counts.allocs, counts.deallocs), (0, 0));
+    assert_eq!(sum, "cold-ish");
+
+    let cold = ColdString::new("a string long enough");
+    let (sum, counts) = count(|| cold + " for the heap");
+    assert_eq!((counts.allocs, counts.deallocs), (1, 1));
+    assert_eq!(sum, "a string long enough for the heap");
+}
+
+#[test]
+fn builder_inline_does_not_allocate() {
+    use std::fmt::Write;
+
+    let (cold, counts) = count(|| {
+        let mut b = ColdStringBuilder::new();
+        b.push_str("id");
+        write!(b, "={}", 42).unwrap();
+        b.finish()
+    });
+    assert_eq!((counts.allocs, counts.deallocs), (0, 0));
+    assert_eq!(cold, "id=42");
+
+    let (cold, counts) = count(|| {
+        let mut b = ColdStringBuilder::with_capacity(64);
+        let (what, n) = ("string", 1);
+        write!(b, "a {} long enough for the heap, {}", what, n).unwrap();
+        b.finish()
+    });
+    assert_eq!((counts.allocs, counts.deallocs), (2, 1));
+    assert_eq!(cold, "a string long enough for the heap, 1");
+}
+
+#[test]
+fn cold_format_allocates_at_most_once() {
+    let id = 42;
+    let (cold, counts) = count(|| cold_format!("user:{}", id));
+    assert_eq!((counts.allocs, counts.deallocs), (0, 0));
+    assert_eq!(cold, "user:42");
+
+    let (cold, counts) = count(|| cold_format!("{:>1$}", id, WIDTH));
+    assert_eq!((counts.allocs, counts.deallocs), (0, 0));
+    assert_eq!(cold.len(), WIDTH);
+
+    let (cold, counts) = count(|| cold_format!("user:{} is long enough for the heap", id));
+    assert_eq!((counts.allocs, counts.deallocs), (1, 0));
+    assert_eq!(cold, "user:42 is long enough for the heap");
+}
+
+#[test]
+fn from_display_allocates_at_most_once() {
+    let (cold, counts) = count(|| ColdString::from_display(&1234u32));
+    assert_eq!((counts.allocs, counts.deallocs), (0, 0));
+    assert_eq!(cold, "1234");
+
+    let addr = std::net::Ipv4Addr::LOCALHOST;
+    let (cold, counts) = count(|| ColdString::from_display(&addr));
+    assert_eq!(counts.allocs, (cold.len() > WIDTH) as usize);
+    assert_eq!(counts.deallocs, 0);
+    assert_eq!(cold, "127.0.0.1");
+}
+
+#[test]
+fn to_cold_string_allocates_at_most_once() {
+    let (cold, counts) = count(|| "short".to_cold_string());
+    assert_eq!((counts.allocs, counts.deallocs), (0, 0));
+    assert_eq!(cold, "short");
+
+    let s = String::from("a string long enough for the heap");
+    let (cold, counts) = count(|| s.to_cold_string());
+    assert_eq!((counts.allocs, counts.deallocs), (1, 0));
+    let (copy, counts) = count(|| cold.to_cold_string());
+    assert_eq!((counts.allocs, counts.deallocs), (1, 0));
+    assert_eq!(copy, s.as_str());
+}
+
+#[test]
+fn from_utf16_allocates_at_most_once() {
+    for s in ["cold", "a string long enough for the heap"] {
+        let utf16: Vec<u16> = s.encode_utf16().collect();
+        let (cold, counts) = count(|| ColdString::from_utf16(&utf16).unwrap());
+        assert_eq!(
+            (counts.allocs, counts.deallocs),
+            ((s.len() > WIDTH) as usize, 0)
+        );
+        assert_eq!(cold, s);
+    }
+}
+
+#[test]
+fn try_inline_never_allocates() {
+    let strs = ["", "a", "cold", &"x".repeat(WIDTH), "too long to be inline"];
+    let (inlined, counts) = count(|| {
+        let mut inlined = 0;
+        for i in 0..1_000_000 {
+            if let Some(s) = ColdString::try_inline(strs[i % strs.len()]) {
+                assert!(s.is_inline());
+                inlined += 1;
+            }
+        }
+        inlined
+    });
+    assert_eq!((counts.allocs, counts.deallocs), (0, 0));
+    assert_eq!(inlined, 800_000);
+}
+
+#[test]
+fn from_static_never_allocates() {
+    static LONG: StaticStr = StaticStr::new("a static string long enough for the heap");
+    let (_, counts) = count(|| {
+        let s = ColdString::from_static(&LONG);
+        let clone = s.clone();
+        assert_eq!(clone, s);
+        assert_eq!(s.len(), LONG.as_str().len());
+    });
+    assert_eq!((counts.allocs, counts.deallocs), (0, 0));
+
+    let (_, counts) = count(|| {
+        let short = cold!("short");
+        let long = cold!("a literal long enough for the heap");
+        assert_eq!((short.clone(), long.clone()), (short, long));
+    });
+    assert_eq!((counts.allocs, counts.deallocs), (0, 0));
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn from_reader_sized_allocates_once_with_exact_hint() {
+    let text = "a text asset loaded into a cold cache";
+    let (cold, counts) = count(|| ColdString::from_reader_sized(text.as_bytes(), text.len()));
+    assert_eq!(cold.unwrap(), text);
+    assert_eq!(
+        counts,
+        Counts {
+            allocs: 1,
+            deallocs: 0
+        }
+    );
+
+    let (cold, counts) = count(|| ColdString::from_reader("cold".as_bytes()));
+    assert_eq!(cold.unwrap(), "cold");
+    assert_eq!(
+        counts,
+        Counts {
+            allocs: 0,
+            deallocs: 0
+        }
+    );
+}
+
+#[test]
+fn short_slice_of_heap_string_does_not_allocate() {
+    let cold = ColdString::new("https://example.com/a/long/path");
+    let (host, counts) = count(|| cold.slice(8..15));
+    assert_eq!(host, "example");
+    assert_eq!(
+        counts,
+        Counts {
+            allocs: 0,
+            deallocs: 0
+        }
+    );
+    let (path, counts) = count(|| cold.try_slice(19..));
+    assert_eq!(path.unwrap(), "/a/long/path");
+    assert_eq!(
+        counts,
+        Counts {
+            allocs: 1,
+            deallocs: 0
+        }
+    );
+}
+
+#[test]
+fn split_at_cold_allocates_only_heap_halves() {
+    let cold = ColdString::new("key=a value long enough for the heap");
+    let ((key, value), counts) = count(|| cold.split_at_cold(3));
+    assert_eq!(key, "key");
+    assert!(key.is_inline());
+    assert_eq!(value, "=a value long enough for the heap");
+    assert_eq!(
+        counts,
+        Counts {
+            allocs: 1,
+            deallocs: 0
+        }
+    );
+}
+
+#[test]
+fn replace_allocates_result_once() {
+    let cold = ColdString::new("my-cold-identifier-long-enough-for-the-heap");
+    let (replaced, counts) = count(|| cold.replace('-', "_"));
+    assert_eq!(replaced, "my_cold_identifier_long_enough_for_the_heap");
+    assert_eq!(
+        counts,
+        Counts {
+            allocs: 1,
+            deallocs: 0
+        }
+    );
+    let (replaced, counts) = count(|| cold.replace("missing", "_"));
+    assert_eq!(replaced, cold);
+    assert_eq!(
+        counts,
+        Counts {
+            allocs: 1,
+            deallocs: 0
+        }
+    );
+    let (replaced, counts) = count(|| cold.replace("-long-enough-for-the-heap", ""));
+    assert_eq!(replaced, "my-cold-identifier");
+    assert_eq!(
+        counts,
+        Counts {
+            allocs: 1,
+            deallocs: 0
+        }
+    );
+    let (replaced, counts) = count(|| ColdString::new("a-b").replace('-', ""));
+    assert_eq!(replaced, "ab");
+    assert_eq!(
+        counts,
+        Counts {
+            allocs: 0,
+            deallocs: 0
+        }
+    );
+}
+
+#[test]
+fn ascii_case_mapping_inline_does_not_allocate() {
+    let cold = ColdString::new("Accept");
+    let (lower, counts) = count(|| cold.to_ascii_lowercase());
+    assert_eq!(lower, "accept");
+    assert_eq!(
+        counts,
+        Counts {
+            allocs: 0,
+            deallocs: 0
+        }
+    );
+    let long = ColdString::new("Content-Security-Policy");
+    let (upper, counts) = count(|| long.to_ascii_uppercase());
+    assert_eq!(upper, "CONTENT-SECURITY-POLICY");
+    assert_eq!(
+        counts,
+        Counts {
+            allocs: 1,
+            deallocs: 0
+        }
+    );
+}
+
+#[test]
+fn trim_inline_does_not_allocate() {
+    let cold = ColdString::new("  cold ");
+    let (trimmed, counts) = count(|| (cold.trimmed(), cold.trim_end_cold()));
+    assert_eq!(
+        trimmed,
+        (ColdString::new("cold"), ColdString::new("  cold"))
+    );
+    assert_eq!(
+        counts,
+        Counts {
+            allocs: 0,
+            deallocs: 0
+        }
+    );
+    let long = ColdString::new("        short  ");
+    let (trimmed, counts) = count(|| long.trimmed());
+    assert!(trimmed.is_inline());
+    assert_eq!(
+        counts,
+        Counts {
+            allocs: 0,
+            deallocs: 0
+        }
+    );
+}
+
+#[test]
+fn reversed_allocates_at_most_once() {
+    let (reversed, counts) = count(|| ColdString::new("cold🦀").reversed());
+    assert_eq!(reversed, "🦀dloc");
+    assert_eq!(
+        counts,
+        Counts {
+            allocs: 0,
+            deallocs: 0
+        }
+    );
+    let long = ColdString::new("a string long enough for the heap");
+    let (reversed, counts) = count(|| long.reversed());
+    assert_eq!(reversed, "paeh eht rof hguone gnol gnirts a");
+    assert_eq!(
+        counts,
+        Counts {
+            allocs: 1,
+            deallocs: 0
+        }
+    );
+}
+
+#[test]
+fn clone_from_reuses_same_length_block() {
+    let sources: Vec<ColdString> = (0..10)
+        .map(|i| ColdString::from(format!("a heap string with the same length {}", i)))
+        .collect();
+    let mut target = sources[0].clone();
+    let ((), counts) = count(|| {
+        for source in &sources {
+            target.clone_from(source);
+            assert_eq!(target, *source);
+        }
+    });
+    assert_eq!(
+        counts,
+        Counts {
+            allocs: 0,
+            deallocs: 0
+        }
+    );
+    let different = ColdString::new("a string of a different length");
+    let (_, counts) = count(|| target.clone_from(&different));
+    assert_eq!(
+        counts,
+        Counts {
+            allocs: 1,
+            deallocs: 1
+        }
+    );
+    let (_, counts) = count(|| target.clone_from(&ColdString::new("cold")));
+    assert_eq!(
+        counts,
+        Counts {
+            allocs: 0,
+            deallocs: 1
+        }
+    );
+}
+
+#[cfg(feature = "bumpalo")]
+#[test]
+fn new_in_uses_only_the_arena() {
+    let bump = bumpalo::Bump::with_capacity(1024);
+    let s = "a string long enough for the heap";
+    let (cold, counts) = count(|| ColdString::new_in(s, &bump));
+    assert_eq!(
+        counts,
+        Counts {
+            allocs: 0,
+            deallocs: 0
+        }
+    );
+    let (copy, counts) = count(|| cold.clone());
+    assert_eq!(
+        counts,
+        Counts {
+            allocs: 0,
+            deallocs: 0
+        }
+    );
+    assert_eq!(copy.as_ptr(), cold.as_ptr());
+}
+
+#[test]
+fn from_string_copies_once_and_frees_source() {
+    for (s, allocs) in [("cold", 0), ("a string long enough for the heap", 1)] {
+        let owned = String::from(s);
+        let (cold, counts) = count(|| ColdString::from(owned));
+        assert_eq!(
+            counts,
+            Counts {
+                allocs,
+                deallocs: 1
+            }
+        );
+        assert_eq!(cold, s);
+    }
+}
+
+#[test]
+fn into_bytes_copies_once_and_frees() {
+    for s in ["", "cold", "a string long enough for the heap"] {
+        let cold = ColdString::new(s);
+        let heap = !cold.is_inline();
+        let (v, counts) = count(|| cold.to_vec());
+        assert_eq!(
+            (counts.allocs, counts.deallocs),
+            (!s.is_empty() as usize, 0)
+        );
+        assert_eq!((v.as_slice(), v.capacity()), (s.as_bytes(), s.len()));
+
+        let (v, counts) = count(|| cold.into_bytes());
+        assert_eq!(counts.allocs, !s.is_empty() as usize);
+        assert_eq!(counts.deallocs, heap as usize);
+        assert_eq!((v.as_slice(), v.capacity()), (s.as_bytes(), s.len()));
+    }
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn deserialize_without_intermediate_string() {
+    let json = r#"["inline", "a string long enough for the heap", "🦀"]"#;
+    let (v, counts) = count(|| serde_json::from_str::<Vec<ColdString>>(json).unwrap());
+    // serde_json gives no length hint, so the `Vec` grows: 4 is the smallest capacity.
+    assert_eq!(counts.allocs, 1 + 1);
+    assert_eq!(v, ["inline", "a string long enough for the heap", "🦀"]);
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn vec_cold_allocates_vec_once() {
+    use cold_string::serde_helpers::vec_cold;
+
+    let n = 300_000;
+    let v: Vec<ColdString> = (0..n).map(|i| ColdString::new(i.to_string())).collect();
+    let buf = rmp_serde::to_vec(&v).unwrap();
+
+    let (naive, naive_counts) = count(|| rmp_serde::from_slice::<Vec<ColdString>>(&buf).unwrap());
+    let (de_v, counts) = count(|| {
+        let mut de = rmp_serde::Deserializer::from_read_ref(&buf);
+        vec_cold::deserialize(&mut de).unwrap()
+    });
+    assert_eq!(de_v, v);
+    assert_eq!(naive, v);
+    // All the strings are inline, so the only allocation is the `Vec`.
+    assert_eq!(
+        counts,
+        Counts {
+            allocs: 1,
+            deallocs: 0
+        }
+    );
+    assert_eq!(de_v.capacity(), n);
+    // serde caps its preallocation at 1 MiB, so the naive `Vec` has to grow.
+    assert!(naive_counts.allocs > 1);
+}
+
+#[test]
+fn with_len_allocates_final_block_once() {
+    let s = "a string long enough for the heap";
+    let (cold, counts) = count(|| {
+        let mut uninit = ColdString::with_len(s.len());
+        uninit.write_at(0, &s.as_bytes()[..10]);
+        uninit.write_at(10, &s.as_bytes()[10..]);
+        uninit.finish().unwrap()
+    });
+    assert_eq!((counts.allocs, counts.deallocs), (1, 0));
+    assert_eq!(cold, s);
+    let (_, counts) = count(|| drop(cold));
+    assert_eq!((counts.allocs, counts.deallocs), (0, 1));
+
+    let (res, counts) = count(|| {
+        let mut uninit = ColdString::with_len(s.len());
+        uninit.write_at(0, b"\xff");
+        uninit.finish()
+    });
+    assert!(res.is_err());
+    assert_eq!((counts.allocs, counts.deallocs), (1, 1));
+
+    let (_, counts) = count(|| drop(ColdString::with_len(s.len())));
+    assert_eq!((counts.allocs, counts.deallocs), (1, 1));
+    let (_, counts) = count(|| ColdString::with_len(4).finish().unwrap());
+    assert_eq!((counts.allocs, counts.deallocs), (0, 0));
+}
+
+#[test]
+fn from_utf8_owned_frees_vec() {
+    for s in ["cold", "a string long enough for the heap"] {
+        let heap = s.len() > WIDTH;
+        let v = s.as_bytes().to_vec();
+        let (cold, counts) = count(|| ColdString::from_utf8_owned(v).unwrap());
+        assert_eq!((counts.allocs, counts.deallocs), (heap as usize, 1));
+        assert_eq!(cold, s);
+    }
+    let v = b"invalid \xff".to_vec();
+    let (err, counts) = count(|| ColdString::from_utf8_owned(v).unwrap_err());
+    assert_eq!((counts.allocs, counts.deallocs), (0, 0));
+    assert_eq!(err.into_bytes(), b"invalid \xff");
+}
@@ -0,0 +1,131 @@
+//! Compile-time checks of the `const fn` API. Each group is gated on the first Rust version
+//! where those methods are `const`.
+
+use cold_string::ColdString;
+
+/// Evaluates `$body` with `$s` bound to an inline [`ColdString`], then forgets it, since
+/// `ColdString`'s destructor can't run in a const context.
+macro_rules! with_inline {
+    ($lit:literal, |$s:ident| $body:expr) => {{
+        let $s = ColdString::new_inline_const($lit);
+        let res = $body;
+        core::mem::forget($s);
+        res
+    }};
+}
+
+#[rustversion::since(1.61)]
+mod since_1_61 {
+    use super::*;
+
+    const _: () = assert!(with_inline!("", |s| s.is_inline()));
+    const _: () = assert!(with_inline!("ab", |s| s.is_inline()));
+    const _: () = assert!(with_inline!("\0\0\0\0", |s| s.is_inline()));
+
+    const _: () = assert!(with_inline!("ab", |s| s.len() == 2));
+    const _: () = assert!(with_inline!("🦀", |s| s.len() == 4));
+    const _: () = assert!(with_inline!("\0\0\0\0", |s| s.len() == 4));
+
+    const _: () = assert!(with_inline!("", |s| s.is_empty()));
+    const _: () = assert!(with_inline!("a", |s| !s.is_empty()));
+
+    #[cfg(target_pointer_width = "64")]
+    const _: () = assert!(with_inline!("12345678", |s| s.len() == 8 && s.is_inline()));
+    #[cfg(target_pointer_width = "64")]
+    const _: () = assert!(with_inline!("\0\0\0\0\0\0\0\0", |s| s.len() == 8));
+
+    const LEN: usize = with_inline!("abc", |s| s.len());
+
+    const COLD_CONST: ColdString = cold_string::cold!(const "cold");
+    static COLD_STATIC: ColdString = cold_string::cold!(const "static");
+
+    #[test]
+    fn cold_macro_const() {
+        assert_eq!(COLD_CONST, "cold");
+        assert_eq!(COLD_STATIC, "static");
+        assert!(COLD_STATIC.is_inline());
+    }
+
+    const TRY_SHORT: Option<ColdString> = ColdString::try_inline_const("abc");
+    const TRY_LONG: Option<ColdString> = ColdString::try_inline_const("too long to be inline");
+
+    #[test]
+    fn try_inline_const() {
+        assert_eq!(TRY_SHORT, Some(ColdString::new("abc")));
+        assert_eq!(TRY_LONG, None);
+        assert_eq!(
+            ColdString::try_inline_const("\0\0\0\0"),
+            Some(ColdString::new("\0\0\0\0"))
+        );
+    }
+
+    #[test]
+    fn const_len() {
+        assert_eq!(LEN, 3);
+        assert_eq!(ColdString::new("a string on the heap").len(), 20);
+    }
+}
+
+#[rustversion::since(1.83)]
+mod since_1_83 {
+    use super::*;
+
+    const fn bytes_eq(a: &[u8], b: &[u8]) -> bool {
+        if a.len() != b.len() {
+            return false;
+        }
+        let mut i = 0;
+        while i < a.len() {
+            if a[i] != b[i] {
+                return false;
+            }
+            i += 1;
+        }
+        true
+    }
+
+    const _: () = assert!(with_inline!("", |s| s.as_bytes().is_empty()));
+    const _: () = assert!(with_inline!("ab", |s| bytes_eq(s.as_bytes(), b"ab")));
+    const _: () = assert!(with_inline!("🦀", |s| bytes_eq(
+        s.as_str().as_bytes(),
+        "🦀".as_bytes()
+    )));
+    const _: () = assert!(with_inline!("\0\0\0\0", |s| bytes_eq(
+        s.as_bytes(),
+        &[0; 4]
+    )));
+    #[cfg(target_pointer_width = "64")]
+    const _: () = assert!(with_inline!("\0\0\0\0\0\0\0\0", |s| bytes_eq(
+        s.as_bytes(),
+        &[0; 8]
+    )));
+
+    // Two inline values are equal exactly when their bytes are.
+    const _: () = assert!(with_inline!("cold", |a| with_inline!(
+        "cold",
+        |b| bytes_eq(a.as_bytes(), b.as_bytes())
+    )));
+    const _: () = assert!(!with_inline!("cold", |a| with_inline!("cole", |b| {
+        bytes_eq(a.as_bytes(), b.as_bytes())
+    })));
+
+    const FIRST: u8 = with_inline!("xyz", |s| s.as_bytes()[0]);
+
+    #[test]
+    fn const_as_bytes() {
+        assert_eq!(FIRST, b'x');
+    }
+}
+
+#[rustversion::since(1.61)]
+mod from_char {
+    use super::*;
+
+    const CRAB: ColdString = ColdString::from_char('🦀');
+
+    #[test]
+    fn const_from_char() {
+        assert_eq!(CRAB, "🦀");
+        assert!(CRAB.is_inline());
+    }
+}
@@ -0,0 +1,58 @@
+use cold_string::*;
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::cell::Cell;
+
+#[global_allocator]
+static A: FailingAlloc = FailingAlloc;
+
+/// Fails allocations on the current thread while `FAIL` is set.
+pub struct FailingAlloc;
+
+thread_local! {
+    static FAIL: Cell<bool> = const { Cell::new(false) };
+}
+
+unsafe impl GlobalAlloc for FailingAlloc {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        if FAIL.try_with(|f| f.get()).unwrap_or(false) {
+            return std::ptr::null_mut();
+        }
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+}
+
+fn failing<T>(f: impl FnOnce() -> T) -> T {
+    FAIL.with(|fail| fail.set(true));
+    let res = f();
+    FAIL.with(|fail| fail.set(false));
+    res
+}
+
+#[test]
+fn heap_allocation_failure() {
+    let s = "a string long enough for the heap";
+    let err = failing(|| ColdString::try_new(s)).unwrap_err();
+    assert_eq!(err.requested_len(), s.len());
+    assert!(!err.is_utf8_error());
+    assert_eq!(
+        err.to_string(),
+        format!("failed to allocate a string of {} bytes", s.len())
+    );
+    let err = failing(|| ColdString::try_from_utf8(s.as_bytes())).unwrap_err();
+    assert_eq!(err.requested_len(), s.len());
+
+    // The same strings are created once the allocator recovers.
+    assert_eq!(ColdString::try_new(s).unwrap(), s);
+}
+
+#[test]
+fn inline_never_allocates() {
+    let cold = failing(|| ColdString::try_new("cold")).unwrap();
+    assert_eq!(cold, "cold");
+    let empty = failing(|| ColdString::try_from_utf8(b"")).unwrap();
+    assert_eq!(empty, "");
+}
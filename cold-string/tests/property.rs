@@ -15,6 +15,21 @@ fn proptest_config() -> ProptestConfig {
     ProptestConfig::with_cases(65536)
 }
 
+macro_rules! assert_fmt_eq {
+    ($cold:expr, $s:expr, $width:expr, [$($spec:literal),*]) => {
+        $(assert_eq!(
+            format!($spec, $cold, width = $width),
+            format!($spec, $s, width = $width)
+        );)*
+    };
+    ($cold:expr, $s:expr, $width:expr, $prec:expr, [$($spec:literal),*]) => {
+        $(assert_eq!(
+            format!($spec, $cold, width = $width, prec = $prec),
+            format!($spec, $s, width = $width, prec = $prec)
+        );)*
+    };
+}
+
 proptest! {
     #![proptest_config(proptest_config())]
 
@@ -27,6 +42,9 @@ proptest! {
         assert_eq!(right.as_str() == cold1, left == right);
         assert_eq!(cold2 == left.as_str(), left == right);
         assert_eq!(left.as_str() == cold2, left == right);
+        assert_eq!(cold1.partial_cmp(right.as_str()), left.as_str().partial_cmp(right.as_str()));
+        assert_eq!(left.as_str().partial_cmp(&cold2), left.as_str().partial_cmp(right.as_str()));
+        assert_eq!(cold1.partial_cmp(&right), left.partial_cmp(&right));
     }
 
     #[test]
@@ -48,4 +66,365 @@ proptest! {
         assert_eq!(opt_s.as_ref().map(|x| x.as_str()), Some(s.as_str()));
     }
 
+    #[test]
+    fn arb_debug(s in any::<String>()) {
+        let cold = ColdString::new(s.as_str());
+        assert_eq!(format!("{:?}", cold), format!("{:?}", s));
+        assert_eq!(format!("{:#?}", cold), format!("{:#?}", s));
+        assert_eq!(format!("{}", cold), s);
+    }
+
+    #[test]
+    fn arb_display_padding(s in "[a-c\u{e9}\u{1f980} ]{0,20}", width in 0usize..40, prec in 0usize..25) {
+        let cold = ColdString::new(s.as_str());
+        let s = s.as_str();
+        assert_fmt_eq!(cold, s, width, [
+            "{:width$}", "{:<width$}", "{:>width$}", "{:^width$}",
+            "{:*<width$}", "{:*>width$}", "{:*^width$}",
+            "{:\u{e9}<width$}", "{:\u{e9}>width$}", "{:\u{1f980}^width$}",
+            "{:#width$}", "{:+width$}", "{:0width$}", "{:-^width$}"
+        ]);
+        assert_fmt_eq!(cold, s, width, prec, [
+            "{:<width$.prec$}", "{:width$.prec$}", "{:>width$.prec$}", "{:*^width$.prec$}",
+            "{:\u{e9}<width$.prec$}", "{:^width$.prec$}", "{:\u{1f980}>width$.prec$}"
+        ]);
+    }
+
+    #[test]
+    fn arb_sorted_search(mut strings in proptest::collection::vec("[ab\u{e9}]{0,12}", 0..64), needle in "[ab\u{e9}]{0,12}") {
+        strings.sort();
+        let cold: Vec<ColdString> = strings.iter().map(ColdString::new).collect();
+        let needle = needle.as_str();
+        let expected = strings.binary_search_by(|s| s.as_str().cmp(needle));
+        match (binary_search(&cold, needle), expected) {
+            (Ok(i), Ok(_)) => assert_eq!(strings[i], needle),
+            (found, expected) => assert_eq!(found, expected),
+        }
+        let start = strings.partition_point(|s| s.as_str() < needle);
+        assert_eq!(partition_point_prefix(&cold, needle), start);
+        let matching: Vec<ColdString> = strings.iter().filter(|s| s.starts_with(needle)).map(ColdString::new).collect();
+        assert_eq!(equal_range_by_prefix(&cold, needle), matching.as_slice());
+    }
+
+    #[test]
+    fn arb_case_insensitive((left, right) in ("[aAbB\u{e9}\u{c9}_]{0,12}", "[aAbB\u{e9}\u{c9}_]{0,12}")) {
+        let cold = ColdString::new(left.as_str());
+        let (lower_left, lower_right) = (left.to_ascii_lowercase(), right.to_ascii_lowercase());
+        assert_eq!(cold.cmp_ignore_ascii_case(&right), lower_left.cmp(&lower_right));
+        assert!(cold.ascii_lowercase_bytes().eq(lower_left.bytes()));
+        let (a, b) = (CaseInsensitive(cold), CaseInsensitive(ColdString::new(right.as_str())));
+        assert_eq!(a.cmp(&b), lower_left.cmp(&lower_right));
+        assert_eq!(a == b, lower_left == lower_right);
+        if a == b {
+            use std::hash::BuildHasher;
+            let bh = std::collections::hash_map::RandomState::new();
+            assert_eq!(bh.hash_one(&a), bh.hash_one(&b));
+        }
+    }
+
+    #[test]
+    fn arb_construction_paths((s, other) in any::<(String, String)>()) {
+        use std::hash::BuildHasher;
+        let bh = std::collections::hash_map::RandomState::new();
+        let mut uninit = ColdString::with_len(s.len());
+        uninit.write_at(0, s.as_bytes());
+        let paths = [
+            ColdString::new(&s),
+            ColdString::from_utf8(s.as_bytes()).unwrap(),
+            ColdString::from_utf8_owned(s.clone().into_bytes()).unwrap(),
+            ColdString::from(s.clone()),
+            ColdString::from(s.clone().into_boxed_str()),
+            uninit.finish().unwrap(),
+        ];
+        // Every construction path is observably the same as `String`, and as each other.
+        for cold in &paths {
+            assert_eq!(cold.len(), s.len());
+            assert_eq!(cold.is_inline(), paths[0].is_inline());
+            assert_eq!(cold, &paths[0]);
+            assert_eq!(cold.clone(), s.as_str());
+            assert_eq!(bh.hash_one(cold), bh.hash_one(&s));
+            assert_eq!(bh.hash_one(cold), bh.hash_one(&paths[0]));
+            let other_cold = ColdString::new(&other);
+            assert_eq!(cold.cmp(&other_cold), s.cmp(&other));
+            assert_eq!(cold == &other_cold, s == other);
+        }
+    }
+
+    #[test]
+    fn arb_get(s in any::<String>(), start in 0usize..40, end in 0usize..40) {
+        use std::ops::Bound::*;
+        let cold = ColdString::new(s.as_str());
+        assert_eq!(cold.get(start..end), s.get(start..end));
+        assert_eq!(cold.get(start..), s.get(start..));
+        assert_eq!(cold.get(..end), s.get(..end));
+        assert_eq!(cold.get(..=end), s.get(..=end));
+        assert_eq!(cold.get(start..=end), s.get(start..=end));
+        assert_eq!(cold.get(..), s.get(..));
+        assert_eq!(cold.get((Excluded(start), Excluded(end))), s.get(start + 1..end));
+        if let Some(expected) = s.get(start..end) {
+            assert_eq!(unsafe { cold.get_unchecked(start..end) }, expected);
+        }
+    }
+
+    #[test]
+    fn arb_join(sep in ".{0,3}", parts in proptest::collection::vec(".{0,6}", 0..6)) {
+        let expected = parts.join(sep.as_str());
+        let cold = ColdString::join(&sep, &parts);
+        assert_eq!(cold.as_str(), expected.as_str());
+        assert_eq!(cold.is_inline(), expected.len() <= core::mem::size_of::<usize>());
+        assert_eq!(cold, ColdString::join(&sep, parts.iter().map(String::as_str)));
+    }
+
+    #[test]
+    fn arb_from_utf8_iter(s in any::<String>(), chunk in 1usize..16, junk in any::<Vec<u8>>()) {
+        let expected = ColdString::new(s.as_str());
+        let cold = ColdString::from_utf8_iter(s.bytes()).unwrap();
+        assert_eq!(cold, expected);
+        assert_eq!(cold.is_inline(), expected.is_inline());
+        // Chunks hide the exact size hint.
+        let chunked = s.as_bytes().chunks(chunk).flat_map(|c| c.iter().copied());
+        assert_eq!(ColdString::from_utf8_iter(chunked).unwrap(), expected);
+        let one_at_a_time = s.bytes().filter(|_| true);
+        assert_eq!(ColdString::from_utf8_iter(one_at_a_time).unwrap(), expected);
+        // Arbitrary bytes give the same result as `str::from_utf8`.
+        let mut bytes = s.into_bytes();
+        bytes.extend_from_slice(&junk);
+        let result = ColdString::from_utf8_iter(bytes.iter().copied());
+        match core::str::from_utf8(&bytes) {
+            Ok(expected) => assert_eq!(result.unwrap(), expected),
+            Err(expected) => assert_eq!(result.unwrap_err(), expected),
+        }
+    }
+
+    #[test]
+    fn arb_case_mapping(s in any::<String>(), greek in "[ΣσςΑαİıIiẞß a-zA-Z'.-]{0,24}") {
+        for s in [s, greek] {
+            let cold = ColdString::new(s.as_str());
+            assert_eq!(cold.to_lowercase().as_str(), s.to_lowercase());
+            assert_eq!(cold.to_uppercase().as_str(), s.to_uppercase());
+        }
+    }
+
+    #[test]
+    fn arb_trim(s in any::<String>(), pad in "[ \t\n\u{85}\u{a0}\u{2000}-\u{200b}\u{3000}x]{0,6}") {
+        let padded = format!("{pad}{s}{pad}");
+        let cold = ColdString::new(padded.as_str());
+        assert_eq!(cold.trimmed().as_str(), padded.trim());
+        assert_eq!(cold.trim_start_cold().as_str(), padded.trim_start());
+        assert_eq!(cold.trim_end_cold().as_str(), padded.trim_end());
+        assert_eq!(cold.trim_matches_cold('x').as_str(), padded.trim_matches('x'));
+        assert_eq!(cold.trimmed().is_inline(), padded.trim().len() <= core::mem::size_of::<usize>());
+    }
+
+    #[test]
+    fn arb_split_cold(s in "[a-c,🦀]{0,24}", delimiter in "[a-c,🦀]{0,2}", c in "[a-c,🦀]", n in 0usize..5) {
+        let cold = ColdString::new(s.as_str());
+        let c = c.chars().next().unwrap();
+        let pieces: Vec<ColdString> = cold.split_cold(delimiter.as_str()).collect();
+        assert_eq!(pieces, s.split(delimiter.as_str()).collect::<Vec<_>>());
+        assert!(cold.rsplit_cold(delimiter.as_str()).eq(s.rsplit(delimiter.as_str())));
+        assert!(cold.splitn_cold(n, delimiter.as_str()).eq(s.splitn(n, delimiter.as_str())));
+        assert!(cold.split_cold(c).eq(s.split(c)));
+        assert!(cold.rsplit_cold(c).eq(s.rsplit(c)));
+        assert!(cold.splitn_cold(n, c).eq(s.splitn(n, c)));
+    }
+
+    #[test]
+    fn arb_split_whitespace_cold(s in any::<String>(), words in "([a-z🦀]{0,12}[ \t\n\x0c\u{85}\u{a0}\u{2003}\u{3000}]{0,3}){0,6}") {
+        for s in [s, words] {
+            let cold = ColdString::new(s.as_str());
+            let pieces: Vec<ColdString> = cold.split_whitespace_cold().collect();
+            assert_eq!(pieces, s.split_whitespace().collect::<Vec<_>>());
+            assert!(pieces.iter().all(|p| p.is_inline() == (p.len() <= core::mem::size_of::<usize>())));
+            assert!(cold.split_ascii_whitespace_cold().eq(s.split_ascii_whitespace()));
+        }
+    }
+
+    #[test]
+    fn arb_char_transforms(s in any::<String>(), modulo in 1u32..5) {
+        let cold = ColdString::new(s.as_str());
+        let keep = |c: char| (c as u32) % modulo != 0;
+        let retained = cold.retain_chars(keep);
+        let expected: String = s.chars().filter(|&c| keep(c)).collect();
+        assert_eq!(retained.as_str(), expected);
+        assert_eq!(retained.is_inline(), expected.len() <= core::mem::size_of::<usize>());
+        let shift = |c: char| char::from_u32(c as u32 + modulo).unwrap_or(c);
+        let mapped = cold.map_chars(shift);
+        let expected: String = s.chars().map(shift).collect();
+        assert_eq!(mapped.as_str(), expected);
+        assert_eq!(mapped.is_inline(), expected.len() <= core::mem::size_of::<usize>());
+    }
+
+    #[test]
+    fn arb_escape(s in any::<String>(), special in "[\\\\\"'\\x00-\\x1f\\u{7f}\\u{300}-\\u{36f}\\u{e000}-\\u{e0fff}a]{0,12}") {
+        for s in [s, special] {
+            let cold = ColdString::new(s.as_str());
+            assert_eq!(cold.escape_debug().as_str(), format!("{}", s.escape_debug()));
+            assert_eq!(cold.escape_default().as_str(), format!("{}", s.escape_default()));
+        }
+    }
+
+    #[test]
+    fn arb_cmp_natural(strs in proptest::collection::vec("[ab0-9]{0,8}", 3)) {
+        let cold: Vec<ColdString> = strs.iter().map(ColdString::new).collect();
+        let (a, b, c) = (&cold[0], &cold[1], &cold[2]);
+        assert_eq!(a.cmp_natural(b), b.cmp_natural(a).reverse());
+        assert_eq!(a.cmp_natural(b) == core::cmp::Ordering::Equal, a == b);
+        if a.cmp_natural(b) != core::cmp::Ordering::Greater
+            && b.cmp_natural(c) != core::cmp::Ordering::Greater
+        {
+            assert_ne!(a.cmp_natural(c), core::cmp::Ordering::Greater);
+        }
+        // Numbers that fit a `u64` compare by value.
+        let parse = |s: &str| s.trim_start_matches(|c: char| !c.is_ascii_digit()).parse::<u64>().ok();
+        if let (Some(x), Some(y)) = (parse(&strs[0]), parse(&strs[1])) {
+            let prefix = |s: &str| s.find(|c: char| c.is_ascii_digit()).map(|i| s[..i].to_string());
+            if x != y && prefix(&strs[0]) == prefix(&strs[1]) {
+                assert_eq!(a.cmp_natural(b), x.cmp(&y));
+            }
+        }
+    }
+
+    #[test]
+    fn arb_eq_one_byte_changed(s in any::<String>(), pos in any::<usize>(), ascii in 0u8..0x80) {
+        let cold = ColdString::new(s.as_str());
+        let mut bytes = s.clone().into_bytes();
+        if !bytes.is_empty() {
+            let i = pos % bytes.len();
+            if bytes[i].is_ascii() {
+                bytes[i] = ascii;
+            }
+        }
+        let other = String::from_utf8(bytes).unwrap();
+        let expected = s == other;
+        assert_eq!(cold == ColdString::new(other.as_str()), expected);
+        assert_eq!(cold == *other.as_str(), expected);
+        assert!(cold == ColdString::new(s.as_str()));
+        assert!(cold == *s.as_str());
+    }
+
+    #[test]
+    fn arb_ord_matches_str(
+        (a, b) in ("[a\\x00\u{e9}]{0,10}", "[a\\x00\u{e9}]{0,10}"),
+        (c, d) in any::<(String, String)>(),
+    ) {
+        static LONG: StaticStr = StaticStr::new("a static string long enough for the heap");
+        for (a, b) in [(a.as_str(), b.as_str()), (&c, &d), (LONG.as_str(), &a), (&c, LONG.as_str())] {
+            let (ca, cb) = (ColdString::new(a), ColdString::new(b));
+            assert_eq!(ca.cmp(&cb), a.cmp(b), "{:?} {:?}", a, b);
+            assert_eq!(ca.partial_cmp(&cb), Some(a.cmp(b)));
+        }
+        let stat = ColdString::from_static(&LONG);
+        assert_eq!(stat.cmp(&ColdString::new(a.as_str())), LONG.as_str().cmp(a.as_str()));
+    }
+
+    #[test]
+    fn arb_prefix_key(strs in proptest::collection::vec("[a\\x00\u{e9}]{0,12}|.{0,12}", 0..12)) {
+        let mut cold: Vec<ColdString> = strs.iter().map(ColdString::new).collect();
+        for (a, s) in cold.iter().zip(&strs) {
+            let mut key = [0u8; 8];
+            let n = s.len().min(8);
+            key[..n].copy_from_slice(&s.as_bytes()[..n]);
+            assert_eq!(a.prefix_key(), u64::from_be_bytes(key));
+            for b in &cold {
+                if a.prefix_key() < b.prefix_key() {
+                    assert!(a < b);
+                }
+            }
+        }
+        let mut sorted = strs.clone();
+        sorted.sort_unstable();
+        sort_by_prefix(&mut cold);
+        assert!(cold.iter().map(ColdString::as_str).eq(sorted.iter().map(String::as_str)));
+    }
+
+    #[test]
+    fn arb_frozen_list(strs in proptest::collection::vec(".{0,20}", 0..16), probe in ".{0,4}") {
+        let list: FrozenColdStringList = strs.iter().collect();
+        assert_eq!(list.len(), strs.len());
+        assert!(list.iter().eq(strs.iter().map(String::as_str)));
+        assert!(list.iter().rev().eq(strs.iter().rev().map(String::as_str)));
+        for (i, s) in strs.iter().enumerate() {
+            assert_eq!(list.get(i), Some(s.as_str()));
+            assert_eq!(&list[i], s.as_str());
+            assert_eq!(list.to_cold(i), ColdString::new(s));
+        }
+        assert_eq!(list.get(strs.len()), None);
+        let mut sorted = strs.clone();
+        sorted.sort_unstable();
+        sorted.dedup();
+        let list: FrozenColdStringList = sorted.iter().collect();
+        for (i, s) in sorted.iter().enumerate() {
+            assert_eq!(list.binary_search(s), Ok(i));
+        }
+        assert_eq!(list.binary_search(&probe), sorted.binary_search(&probe));
+    }
+
+    #[test]
+    fn arb_collect_chars(s in any::<String>(), skip in 0usize..4) {
+        let expected = ColdString::new(s.chars().collect::<String>());
+        let cold: ColdString = s.chars().collect();
+        assert_eq!(cold, expected);
+        assert_eq!(cold.is_inline(), expected.is_inline());
+        // Iterators with an inexact size hint.
+        let filtered: ColdString = s.chars().filter(|_| true).collect();
+        assert_eq!(filtered, expected);
+        let skipped: ColdString = s.chars().enumerate().filter(|(i, _)| i % 4 != skip).map(|(_, c)| c).collect();
+        let expected: String = s.chars().enumerate().filter(|(i, _)| i % 4 != skip).map(|(_, c)| c).collect();
+        assert_eq!(skipped, expected.as_str());
+    }
+
+    #[test]
+    fn arb_static_variants((left, right) in ("[ab\u{e9}]{0,24}", "[ab\u{e9}]{0,24}")) {
+        use std::hash::BuildHasher;
+        fn leak(s: &str) -> &'static StaticStr {
+            Box::leak(Box::new(StaticStr::new(Box::leak(s.into()))))
+        }
+        let bh = std::collections::hash_map::RandomState::new();
+        // Owned, a clone of owned, static, and a clone of static.
+        let variants = |s: &str| {
+            let owned = ColdString::new(s);
+            let leaked = leak(s);
+            let static_cold = ColdString::from_static(leaked);
+            assert_eq!(static_cold.is_inline(), s.len() <= std::mem::size_of::<usize>());
+            let static_clone = static_cold.clone();
+            if !static_cold.is_inline() {
+                assert_eq!(static_cold.as_ptr(), leaked.as_str().as_ptr());
+                assert_eq!(static_clone.as_ptr(), static_cold.as_ptr());
+            }
+            [owned.clone(), owned, static_clone, static_cold]
+        };
+        let (lefts, rights) = (variants(&left), variants(&right));
+        for l in lefts.iter() {
+            assert_eq!(l.as_str(), left.as_str());
+            assert_eq!(l.len(), left.len());
+            assert_eq!(l.clone(), *l);
+            assert_eq!(bh.hash_one(l.clone()), bh.hash_one(l));
+            assert_eq!(bh.hash_one(l), bh.hash_one(left.as_str()));
+            for r in rights.iter() {
+                assert_eq!(l == r, left == right);
+                assert_eq!(r == l, left == right);
+                assert_eq!(l.cmp(r), left.cmp(&right));
+                assert_eq!(r.cmp(l), right.cmp(&left));
+                assert_eq!(l.partial_cmp(r), left.partial_cmp(&right));
+                if l == r {
+                    assert_eq!(bh.hash_one(l), bh.hash_one(r));
+                }
+            }
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "heck")]
+    fn arb_case_style_matches_heck(s in any::<String>(), ident in "[aAzZ09_ .ΣσςßẞİıﬄéÉ東-]{0,24}") {
+        use heck::{ToKebabCase, ToLowerCamelCase, ToShoutySnakeCase, ToSnakeCase, ToUpperCamelCase};
+        for s in [s.as_str(), ident.as_str()] {
+            let cold = ColdString::new(s);
+            assert_eq!(cold.to_snake_case(), s.to_snake_case().as_str());
+            assert_eq!(cold.to_kebab_case(), s.to_kebab_case().as_str());
+            assert_eq!(cold.to_shouty_snake_case(), s.to_shouty_snake_case().as_str());
+            assert_eq!(cold.to_camel_case(), s.to_lower_camel_case().as_str());
+            assert_eq!(cold.to_pascal_case(), s.to_upper_camel_case().as_str());
+        }
+    }
 }